@@ -1,100 +1,113 @@
 mod args;
 mod data;
+mod registry;
 mod tool;
 mod tools;
 
 use clap::CommandFactory;
 use clap::FromArgMatches;
 use clap::Parser;
+use clap_complete::engine::CompleteCommand;
 use clap_complete::generate;
 use clap_complete_nushell::Nushell;
 use std::io;
 
-use crate::tool::Tool;
+use crate::registry::{REGISTRY, ToolExecute};
 use anyhow::{Context, anyhow};
 
-// This way of building main is not ideal.
-macro_rules! toolbox {
-    ($cmd:ident, $(($tool:path, $name:literal, $($alias:literal),*)),+) => {
-        {
-            // Register the tools.
-            $(
-                $cmd = $cmd.subcommand(
-                    <$tool>::cli()
-                    .name($name)
-                    $(.visible_alias($alias))*
-                );
-            )*
-
-            // Parse args.
-            let matches = $cmd.clone().get_matches();
-            let (subcommand_name, subcommand_matches) = matches
-                .subcommand()
-                .context("Could not determine subcommand")?;
-
-            // Run the specific tool.
-            match subcommand_name {
-                $(
-                    $name => {
-                        let output = <$tool>::from_arg_matches(subcommand_matches)
-                            .context("Could not initialize the tool")?
-                            .execute()
-                            .context("Could not execute tool")?;
-
-                        Ok(output)
-                    }
-                )*
-                "completions" => {
-                    Completions::from_arg_matches(subcommand_matches)
-                        .context("Could not initialize the tool")?
-                        .execute(&mut $cmd);
-
-                    Ok(None)
-                }
-                _ => {
-                    Err(anyhow!("Unknown subcommand"))
-                }
-            }
+fn main() -> anyhow::Result<()> {
+    let mut cli = clap::builder::Command::new("ut")
+        .arg_required_else_help(true)
+        .subcommand(Completions::command().name("completions"))
+        .subcommand(clap::Command::new("tools").about("List every registered tool"))
+        .arg(
+            clap::Arg::new("list")
+                .long("list")
+                .action(clap::ArgAction::SetTrue)
+                .help("List every registered tool (alias for `ut tools`)"),
+        );
+    cli = CompleteCommand::augment_subcommands(cli);
+
+    for entry in REGISTRY {
+        cli = cli.subcommand(
+            (entry.cli)()
+                .name(entry.name)
+                .visible_aliases(entry.aliases.iter().copied()),
+        );
+    }
+
+    let matches = cli.clone().get_matches();
+
+    if matches.get_flag("list") {
+        print_tool_list(&cli);
+        return Ok(());
+    }
+
+    let (subcommand_name, subcommand_matches) = matches
+        .subcommand()
+        .context("Could not determine subcommand")?;
+
+    let output = match subcommand_name {
+        "completions" => {
+            Completions::from_arg_matches(subcommand_matches)
+                .context("Could not initialize the tool")?
+                .execute(&mut cli);
+
+            None
         }
+        "complete" => {
+            // Reuses the same assembled `cli` tree built above from
+            // `REGISTRY`, so new tools/flags/ValueEnum variants are picked
+            // up without regenerating a static script.
+            CompleteCommand::from_arg_matches(&matches)
+                .context("Could not initialize dynamic completion")?
+                .complete(&mut cli);
+
+            None
+        }
+        "tools" => {
+            print_tool_list(&cli);
+            None
+        }
+        name => match REGISTRY.iter().find(|entry| entry.name == name) {
+            Some(entry) => (entry.construct)(subcommand_matches)?
+                .run()
+                .context("Could not execute tool")?,
+            None => return Err(anyhow!("Unknown subcommand")),
+        },
     };
+
+    if let Some(output) = output {
+        let exit_code = output.exit_code();
+        output.flush()?;
+        if exit_code != 0 {
+            std::process::exit(exit_code);
+        }
+    }
+
+    Ok(())
 }
 
-fn main() -> anyhow::Result<()> {
-    let mut cli = clap::builder::Command::new("ut")
-        .subcommand_required(true)
-        .arg_required_else_help(true)
-        .subcommand(Completions::command().name("completions"));
-
-    let output = toolbox!(
-        cli,
-        (tools::base64::Base64Tool, "base64",),
-        (tools::bcrypt::BcryptTool, "bcrypt",),
-        (tools::calc::CalcTool, "calc", "cal"),
-        (tools::case::CaseTool, "case",),
-        (tools::color::ColorTool, "color",),
-        (tools::crontab::CrontabTool, "crontab", "cron"),
-        (tools::datetime::DateTimeTool, "datetime", "dt"),
-        (tools::diff::DiffTool, "diff",),
-        (tools::hash::HashTool, "hash",),
-        (tools::http::HttpTool, "http",),
-        (tools::json::JsonTool, "json",),
-        (tools::jwt::JwtTool, "jwt",),
-        (tools::lorem::LoremTool, "lorem",),
-        (tools::pp::PrettyPrintTool, "pretty-print", "pp"),
-        (tools::qr::QRTool, "qr",),
-        (tools::random::RandomTool, "random",),
-        (tools::regex::RegexTool, "regex",),
-        (tools::serve::ServeTool, "serve",),
-        (tools::token::TokenTool, "token", "secret", "password"),
-        (tools::url::UrlTool, "url",),
-        (tools::uuid::UUIDTool, "uuid",),
-        (tools::unicode::UnicodeTool, "unicode",)
-    )
-    .context("Could not run tool")?;
-
-    match output {
-        Some(output) => output.flush(),
-        None => Ok(()),
+// Enumerates every entry in `REGISTRY`, which is also what drives
+// subcommand registration above, so this can't fall out of sync with the
+// actual set of tools the way a hand-maintained list could.
+fn print_tool_list(cli: &clap::Command) {
+    for entry in REGISTRY {
+        let about = cli
+            .find_subcommand(entry.name)
+            .and_then(|c| c.get_about())
+            .map(|s| s.to_string())
+            .unwrap_or_default();
+
+        if entry.aliases.is_empty() {
+            println!("{:<14} {about}", entry.name);
+        } else {
+            println!(
+                "{:<14} {about} (aliases: {})",
+                entry.name,
+                entry.aliases.join(", ")
+            );
+        }
     }
 }
 
@@ -106,7 +119,14 @@ fn main() -> anyhow::Result<()> {
                   Examples:\n  \
                   ut completions zsh > ~/.zsh/completions/_ut\n  \
                   ut completions bash > ~/.local/share/bash-completion/completions/ut\n  \
-                  ut completions nushell > ~/.config/nushell/completions/ut.nu"
+                  ut completions nushell > ~/.config/nushell/completions/ut.nu\n\n\
+                  These scripts are static snapshots of the current subcommands/flags.\n\
+                  For completions that stay correct as tools and flags change without\n\
+                  regenerating anything, register the hidden `ut complete` subcommand\n\
+                  instead:\n  \
+                  echo 'source <(COMPLETE=bash ut)' >> ~/.bashrc\n  \
+                  echo 'source <(COMPLETE=zsh ut)' >> ~/.zshrc\n  \
+                  echo 'COMPLETE=fish ut | source' >> ~/.config/fish/config.fish"
 )]
 struct Completions {
     shell: Shell,
@@ -0,0 +1,116 @@
+// The single source of truth for which tools `ut` exposes. Each entry
+// pairs a concrete tool's CLI definition with how to construct it from
+// matched args, so registering a subcommand and dispatching to it can no
+// longer drift apart the way they could with two hand-written lists.
+use crate::tool::{Output, Tool};
+use anyhow::{Context, Result};
+use clap::{ArgMatches, Command, FromArgMatches};
+use enum_dispatch::enum_dispatch;
+
+// `Tool::cli()` is an associated function with no `self`, so it can't be
+// part of an enum_dispatch trait (there's no instance to match on). This
+// narrower trait only covers the instance-level half of `Tool`, which is
+// all `AnyTool` needs to dispatch.
+#[enum_dispatch]
+pub trait ToolExecute {
+    fn run(&self) -> Result<Option<Output>>;
+}
+
+impl<T: Tool> ToolExecute for T {
+    fn run(&self) -> Result<Option<Output>> {
+        Tool::execute(self)
+    }
+}
+
+#[enum_dispatch(ToolExecute)]
+pub enum AnyTool {
+    Base58(crate::tools::base58::Base58Tool),
+    Base64(crate::tools::base64::Base64Tool),
+    Bcrypt(crate::tools::bcrypt::BcryptTool),
+    Bucket(crate::tools::bucket::BucketTool),
+    Calc(crate::tools::calc::CalcTool),
+    Case(crate::tools::case::CaseTool),
+    Cipher(crate::tools::cipher::CipherTool),
+    Color(crate::tools::color::ColorTool),
+    Crontab(crate::tools::crontab::CrontabTool),
+    DateTime(crate::tools::datetime::DateTimeTool),
+    Diff(crate::tools::diff::DiffTool),
+    Hash(crate::tools::hash::HashTool),
+    Http(crate::tools::http::HttpTool),
+    Json(crate::tools::json::JsonTool),
+    Jwt(crate::tools::jwt::JwtTool),
+    Lorem(crate::tools::lorem::LoremTool),
+    PrettyPrint(crate::tools::pp::PrettyPrintTool),
+    Qr(crate::tools::qr::QRTool),
+    Random(crate::tools::random::RandomTool),
+    Regex(crate::tools::regex::RegexTool),
+    Schedule(crate::tools::cron::CronTool),
+    Serve(crate::tools::serve::ServeTool),
+    Token(crate::tools::token::TokenTool),
+    Url(crate::tools::url::UrlTool),
+    Uuid(crate::tools::uuid::UUIDTool),
+    Unicode(crate::tools::unicode::UnicodeTool),
+}
+
+pub struct ToolEntry {
+    pub name: &'static str,
+    pub aliases: &'static [&'static str],
+    pub cli: fn() -> Command,
+    pub construct: fn(&ArgMatches) -> Result<AnyTool>,
+}
+
+macro_rules! entry {
+    ($tool:ty, $variant:ident, $name:literal $(, $alias:literal)*) => {
+        ToolEntry {
+            name: $name,
+            aliases: &[$($alias),*],
+            cli: <$tool>::cli,
+            construct: |matches| {
+                Ok(AnyTool::$variant(
+                    <$tool>::from_arg_matches(matches)
+                        .context("Could not initialize the tool")?,
+                ))
+            },
+        }
+    };
+}
+
+pub static REGISTRY: &[ToolEntry] = &[
+    entry!(crate::tools::base58::Base58Tool, Base58, "base58"),
+    entry!(crate::tools::base64::Base64Tool, Base64, "base64"),
+    entry!(crate::tools::bcrypt::BcryptTool, Bcrypt, "bcrypt"),
+    entry!(crate::tools::bucket::BucketTool, Bucket, "bucket"),
+    entry!(crate::tools::calc::CalcTool, Calc, "calc", "cal"),
+    entry!(crate::tools::case::CaseTool, Case, "case"),
+    entry!(crate::tools::cipher::CipherTool, Cipher, "cipher"),
+    entry!(crate::tools::color::ColorTool, Color, "color"),
+    entry!(crate::tools::crontab::CrontabTool, Crontab, "crontab", "cron"),
+    entry!(crate::tools::datetime::DateTimeTool, DateTime, "datetime", "dt"),
+    entry!(crate::tools::diff::DiffTool, Diff, "diff"),
+    entry!(crate::tools::hash::HashTool, Hash, "hash"),
+    entry!(crate::tools::http::HttpTool, Http, "http"),
+    entry!(crate::tools::json::JsonTool, Json, "json"),
+    entry!(crate::tools::jwt::JwtTool, Jwt, "jwt"),
+    entry!(crate::tools::lorem::LoremTool, Lorem, "lorem"),
+    entry!(
+        crate::tools::pp::PrettyPrintTool,
+        PrettyPrint,
+        "pretty-print",
+        "pp"
+    ),
+    entry!(crate::tools::qr::QRTool, Qr, "qr"),
+    entry!(crate::tools::random::RandomTool, Random, "random"),
+    entry!(crate::tools::regex::RegexTool, Regex, "regex"),
+    entry!(crate::tools::cron::CronTool, Schedule, "schedule"),
+    entry!(crate::tools::serve::ServeTool, Serve, "serve"),
+    entry!(
+        crate::tools::token::TokenTool,
+        Token,
+        "token",
+        "secret",
+        "password"
+    ),
+    entry!(crate::tools::url::UrlTool, Url, "url"),
+    entry!(crate::tools::uuid::UUIDTool, Uuid, "uuid"),
+    entry!(crate::tools::unicode::UnicodeTool, Unicode, "unicode"),
+];
@@ -22,9 +22,26 @@ pub enum Output {
     Bytes(Vec<u8>),
     JsonValue(serde_json::Value),
     Text(String),
+    // Like `JsonValue`, but carries the process exit code the runner should
+    // use once the value has been flushed, so validate/verify subcommands
+    // can signal failure to shell callers (`ut bcrypt verify ... && ...`)
+    // without the caller having to parse stdout.
+    Status {
+        value: serde_json::Value,
+        exit_code: i32,
+    },
 }
 
 impl Output {
+    // The exit code the runner should use after flushing this output.
+    // Anything other than `Status` exits zero, matching prior behavior.
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            Output::Status { exit_code, .. } => *exit_code,
+            _ => 0,
+        }
+    }
+
     // Write out the output.
     pub fn flush(&self, human: bool) -> anyhow::Result<()> {
         match self {
@@ -43,6 +60,13 @@ impl Output {
             Output::Text(text) => {
                 println!("{}", text);
             }
+            Output::Status { value, .. } => {
+                if human {
+                    println!("{}", value_to_string(value));
+                } else {
+                    println!("{}", value.to_string());
+                }
+            }
         }
 
         Ok(())
@@ -0,0 +1,310 @@
+use crate::{
+    args::StringInput,
+    tool::{Output, Tool},
+};
+use anyhow::{Context, Result, bail};
+use clap::{Command, CommandFactory, Parser, Subcommand};
+use sha2::{Digest, Sha256};
+
+const ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "base58",
+    about = "Base58 / Base58Check encode and decode utilities"
+)]
+pub struct Base58Tool {
+    #[command(subcommand)]
+    command: Base58Command,
+}
+
+#[derive(Subcommand, Debug)]
+enum Base58Command {
+    /// Base58 encode contents
+    Encode {
+        /// Input to encode
+        text: StringInput,
+        /// Append a 4-byte double-SHA-256 checksum before encoding (Base58Check)
+        #[arg(long)]
+        check: bool,
+    },
+    /// Base58 decode contents
+    Decode {
+        /// Input to decode
+        text: StringInput,
+        /// Verify and strip a trailing 4-byte double-SHA-256 checksum (Base58Check)
+        #[arg(long)]
+        check: bool,
+    },
+}
+
+impl Tool for Base58Tool {
+    fn cli() -> Command {
+        Base58Tool::command()
+    }
+
+    fn execute(&self) -> anyhow::Result<Option<Output>> {
+        match &self.command {
+            Base58Command::Encode { text, check } => {
+                let payload = if *check {
+                    with_checksum(&text.0)
+                } else {
+                    text.0.clone()
+                };
+
+                Ok(Some(Output::JsonValue(serde_json::json!(encode(
+                    &payload
+                )))))
+            }
+            Base58Command::Decode { text, check } => {
+                let decoded = decode(text.as_ref()).context("Could not decode base58")?;
+
+                let payload = if *check {
+                    strip_checksum(decoded)?
+                } else {
+                    decoded
+                };
+
+                Ok(Some(Output::Bytes(payload)))
+            }
+        }
+    }
+}
+
+fn sha256d(data: &[u8]) -> [u8; 32] {
+    Sha256::digest(Sha256::digest(data)).into()
+}
+
+fn with_checksum(payload: &[u8]) -> Vec<u8> {
+    let checksum = sha256d(payload);
+    let mut out = payload.to_vec();
+    out.extend_from_slice(&checksum[..4]);
+    out
+}
+
+fn strip_checksum(mut payload: Vec<u8>) -> Result<Vec<u8>> {
+    if payload.len() < 4 {
+        bail!("Base58Check payload is too short to contain a checksum");
+    }
+
+    let checksum_start = payload.len() - 4;
+    let checksum = payload.split_off(checksum_start);
+    let expected = &sha256d(&payload)[..4];
+
+    if checksum != expected {
+        bail!("Base58Check checksum mismatch");
+    }
+
+    Ok(payload)
+}
+
+// Standard big-integer base conversion: repeatedly divmod the input bytes
+// (as a big-endian number) by 58 to produce digits least-significant-first,
+// then reverse. Leading zero bytes carry no numeric value, so they're
+// re-added afterwards as leading '1' characters (the zero digit in this
+// alphabet).
+fn encode(input: &[u8]) -> String {
+    let leading_zeros = input.iter().take_while(|&&b| b == 0).count();
+
+    let mut digits: Vec<u8> = vec![0];
+    for &byte in input {
+        let mut carry = byte as u32;
+        for digit in digits.iter_mut() {
+            carry += (*digit as u32) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+        while carry > 0 {
+            digits.push((carry % 58) as u8);
+            carry /= 58;
+        }
+    }
+
+    let mut out: Vec<u8> = std::iter::repeat(ALPHABET[0])
+        .take(leading_zeros)
+        .chain(digits.iter().rev().map(|&d| ALPHABET[d as usize]))
+        .collect();
+
+    // An all-zero input leaves `digits` as just the initial `[0]` on top of
+    // the leading-zero run already emitted above, which double-counts one
+    // zero digit; trim it back down to exactly `input.len()` characters.
+    if out.len() > input.len() && input.iter().all(|&b| b == 0) {
+        out.truncate(input.len());
+    }
+
+    String::from_utf8(out).expect("base58 alphabet is ASCII")
+}
+
+fn decode(input: &str) -> Result<Vec<u8>> {
+    let leading_zeros = input.chars().take_while(|&c| c == '1').count();
+
+    let mut bytes: Vec<u8> = vec![0];
+    for c in input.chars() {
+        let value = ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .with_context(|| format!("Invalid base58 character: {c:?}"))?;
+
+        let mut carry = value as u32;
+        for byte in bytes.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = (carry & 0xff) as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push((carry & 0xff) as u8);
+            carry >>= 8;
+        }
+    }
+
+    let body: Vec<u8> = bytes.into_iter().rev().skip_while(|&b| b == 0).collect();
+
+    Ok(std::iter::repeat(0u8)
+        .take(leading_zeros)
+        .chain(body)
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_known_vector() {
+        let tool = Base58Tool {
+            command: Base58Command::Encode {
+                text: StringInput("Hello, World!".to_string()),
+                check: false,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "72k1xXWG59fYdzSNoA");
+    }
+
+    #[test]
+    fn test_encode_preserves_leading_zero_bytes() {
+        let tool = Base58Tool {
+            command: Base58Command::Encode {
+                text: StringInput("\x00\x00hello".to_string()),
+                check: false,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert!(val.as_str().unwrap().starts_with("11"));
+    }
+
+    #[test]
+    fn test_decode_known_vector() {
+        let tool = Base58Tool {
+            command: Base58Command::Decode {
+                text: StringInput("72k1xXWG59fYdzSNoA".to_string()),
+                check: false,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::Bytes(bytes) = result else {
+            unreachable!()
+        };
+        assert_eq!(String::from_utf8(bytes).unwrap(), "Hello, World!");
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_character() {
+        let tool = Base58Tool {
+            command: Base58Command::Decode {
+                text: StringInput("0OIl".to_string()),
+                check: false,
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip() {
+        let original = "The quick brown fox jumps over the lazy dog";
+
+        let encode_tool = Base58Tool {
+            command: Base58Command::Encode {
+                text: StringInput(original.to_string()),
+                check: false,
+            },
+        };
+        let encoded = encode_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encoded else {
+            unreachable!()
+        };
+
+        let decode_tool = Base58Tool {
+            command: Base58Command::Decode {
+                text: StringInput(val.as_str().unwrap().to_string()),
+                check: false,
+            },
+        };
+        let decoded = decode_tool.execute().unwrap().unwrap();
+        let Output::Bytes(bytes) = decoded else {
+            unreachable!()
+        };
+        assert_eq!(String::from_utf8(bytes).unwrap(), original);
+    }
+
+    #[test]
+    fn test_check_roundtrip() {
+        let original = "wallet payload";
+
+        let encode_tool = Base58Tool {
+            command: Base58Command::Encode {
+                text: StringInput(original.to_string()),
+                check: true,
+            },
+        };
+        let encoded = encode_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encoded else {
+            unreachable!()
+        };
+
+        let decode_tool = Base58Tool {
+            command: Base58Command::Decode {
+                text: StringInput(val.as_str().unwrap().to_string()),
+                check: true,
+            },
+        };
+        let decoded = decode_tool.execute().unwrap().unwrap();
+        let Output::Bytes(bytes) = decoded else {
+            unreachable!()
+        };
+        assert_eq!(String::from_utf8(bytes).unwrap(), original);
+    }
+
+    #[test]
+    fn test_check_detects_corrupted_checksum() {
+        let encode_tool = Base58Tool {
+            command: Base58Command::Encode {
+                text: StringInput("wallet payload".to_string()),
+                check: true,
+            },
+        };
+        let encoded = encode_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encoded else {
+            unreachable!()
+        };
+        let mut corrupted = val.as_str().unwrap().to_string();
+        corrupted.push('1');
+
+        let decode_tool = Base58Tool {
+            command: Base58Command::Decode {
+                text: StringInput(corrupted),
+                check: true,
+            },
+        };
+        assert!(decode_tool.execute().is_err());
+    }
+}
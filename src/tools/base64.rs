@@ -3,8 +3,12 @@ use crate::{
     tool::{Output, Tool},
 };
 use anyhow::Context;
-use base64::{Engine as _, engine::general_purpose};
-use clap::{Command, CommandFactory, Parser, Subcommand};
+use base64::{
+    Engine as _,
+    alphabet::{self, Alphabet},
+    engine::{GeneralPurpose, GeneralPurposeConfig},
+};
+use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(name = "base64", about = "Base64 encode and decode utilities")]
@@ -19,20 +23,79 @@ enum Base64Command {
     Encode {
         /// Input to encode
         text: StringInput,
-        /// Encode with urlsafe character set
+        /// Encode with urlsafe character set (shorthand for --alphabet url-safe)
         #[arg(long)]
         urlsafe: bool,
+        /// Alphabet variant to encode with
+        #[arg(long, value_enum, default_value = "standard")]
+        alphabet: Base64Alphabet,
+        /// Omit the trailing `=` padding
+        #[arg(long)]
+        no_pad: bool,
     },
     /// Base64 decode contents
     Decode {
         /// Input to decode
         text: StringInput,
-        /// Decode with urlsafe character set
+        /// Decode with urlsafe character set (shorthand for --alphabet url-safe)
         #[arg(long)]
         urlsafe: bool,
+        /// Alphabet variant to decode with
+        #[arg(long, value_enum, default_value = "standard")]
+        alphabet: Base64Alphabet,
+        /// Require no trailing `=` padding (decoding is lenient about it by default)
+        #[arg(long)]
+        no_pad: bool,
     },
 }
 
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Base64Alphabet {
+    Standard,
+    #[value(name = "url-safe")]
+    UrlSafe,
+    Crypt,
+    Bcrypt,
+    #[value(name = "imap-mutf7")]
+    ImapMutf7,
+}
+
+impl Base64Alphabet {
+    fn alphabet(self) -> Alphabet {
+        match self {
+            Base64Alphabet::Standard => alphabet::STANDARD,
+            Base64Alphabet::UrlSafe => alphabet::URL_SAFE,
+            Base64Alphabet::Crypt => alphabet::CRYPT,
+            Base64Alphabet::Bcrypt => alphabet::BCRYPT,
+            Base64Alphabet::ImapMutf7 => alphabet::IMAP_MUTF7,
+        }
+    }
+}
+
+// Builds the engine for the selected alphabet, honoring --urlsafe as a
+// shorthand for --alphabet url-safe. Decoding is always lenient about
+// trailing padding so a no-pad-encoded token still decodes even without
+// passing --no-pad back in.
+fn build_engine(alphabet: Base64Alphabet, urlsafe: bool, no_pad: bool, for_decode: bool) -> GeneralPurpose {
+    let alphabet = if urlsafe {
+        Base64Alphabet::UrlSafe.alphabet()
+    } else {
+        alphabet.alphabet()
+    };
+
+    let config = GeneralPurposeConfig::new()
+        .with_encode_padding(!no_pad)
+        .with_decode_padding_mode(if !for_decode {
+            base64::engine::DecodePaddingMode::RequireCanonical
+        } else if no_pad {
+            base64::engine::DecodePaddingMode::RequireNone
+        } else {
+            base64::engine::DecodePaddingMode::Indifferent
+        });
+
+    GeneralPurpose::new(&alphabet, config)
+}
+
 impl Tool for Base64Tool {
     fn cli() -> Command {
         Base64Tool::command()
@@ -40,21 +103,24 @@ impl Tool for Base64Tool {
 
     fn execute(&self) -> anyhow::Result<Option<Output>> {
         match &self.command {
-            Base64Command::Encode { text, urlsafe } => {
-                let encoded = if *urlsafe {
-                    general_purpose::URL_SAFE.encode(&text.0)
-                } else {
-                    general_purpose::STANDARD.encode(&text.0)
-                };
+            Base64Command::Encode {
+                text,
+                urlsafe,
+                alphabet,
+                no_pad,
+            } => {
+                let engine = build_engine(*alphabet, *urlsafe, *no_pad, false);
+                let encoded = engine.encode(&text.0);
 
                 Ok(Some(Output::JsonValue(serde_json::json!(encoded))))
             }
-            Base64Command::Decode { text, urlsafe } => {
-                let engine = if *urlsafe {
-                    &general_purpose::URL_SAFE
-                } else {
-                    &general_purpose::STANDARD
-                };
+            Base64Command::Decode {
+                text,
+                urlsafe,
+                alphabet,
+                no_pad,
+            } => {
+                let engine = build_engine(*alphabet, *urlsafe, *no_pad, true);
 
                 Ok(Some(Output::Bytes(
                     engine.decode(&text.0).context("Could not decode base64")?,
@@ -75,6 +141,8 @@ mod tests {
             command: Base64Command::Encode {
                 text: StringInput("Hello, World!".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -91,6 +159,8 @@ mod tests {
             command: Base64Command::Encode {
                 text: StringInput("Hello>>World??".to_string()),
                 urlsafe: true,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -108,6 +178,8 @@ mod tests {
             command: Base64Command::Encode {
                 text: StringInput("".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -124,6 +196,8 @@ mod tests {
             command: Base64Command::Encode {
                 text: StringInput("\x00\x01\x02\x03".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -140,6 +214,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput("SGVsbG8sIFdvcmxkIQ==".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -156,6 +232,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput("SGVsbG8-PldvcmxkPz8=".to_string()),
                 urlsafe: true,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -172,6 +250,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput("".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -188,6 +268,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput("AAECAw==".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -204,6 +286,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput("Not valid base64!!!".to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let result = tool.execute();
@@ -225,6 +309,8 @@ mod tests {
             command: Base64Command::Encode {
                 text: StringInput(original.to_string()),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let encoded = encode_tool.execute().unwrap().unwrap();
@@ -238,6 +324,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput(encoded_str),
                 urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let decoded = decode_tool.execute().unwrap().unwrap();
@@ -256,6 +344,8 @@ mod tests {
             command: Base64Command::Encode {
                 text: StringInput(original.to_string()),
                 urlsafe: true,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let encoded = encode_tool.execute().unwrap().unwrap();
@@ -268,6 +358,8 @@ mod tests {
             command: Base64Command::Decode {
                 text: StringInput(encoded_str),
                 urlsafe: true,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
             },
         };
         let decoded = decode_tool.execute().unwrap().unwrap();
@@ -277,4 +369,87 @@ mod tests {
         };
         assert_eq!(String::from_utf8(bytes).unwrap(), original);
     }
+
+    #[test]
+    fn test_encode_no_pad() {
+        let tool = Base64Tool {
+            command: Base64Command::Encode {
+                text: StringInput("Hello, World!".to_string()),
+                urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: true,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "SGVsbG8sIFdvcmxkIQ");
+    }
+
+    #[test]
+    fn test_decode_no_pad_token_is_lenient_by_default() {
+        let tool = Base64Tool {
+            command: Base64Command::Decode {
+                text: StringInput("SGVsbG8sIFdvcmxkIQ".to_string()),
+                urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: false,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::Bytes(bytes) = result else {
+            unreachable!()
+        };
+        assert_eq!(String::from_utf8(bytes).unwrap(), "Hello, World!");
+    }
+
+    #[test]
+    fn test_decode_no_pad_rejects_padded_token() {
+        let tool = Base64Tool {
+            command: Base64Command::Decode {
+                text: StringInput("SGVsbG8sIFdvcmxkIQ==".to_string()),
+                urlsafe: false,
+                alphabet: Base64Alphabet::Standard,
+                no_pad: true,
+            },
+        };
+        let result = tool.execute();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_encode_decode_roundtrip_bcrypt_alphabet() {
+        let original = "bcrypt-alphabet-roundtrip";
+
+        let encode_tool = Base64Tool {
+            command: Base64Command::Encode {
+                text: StringInput(original.to_string()),
+                urlsafe: false,
+                alphabet: Base64Alphabet::Bcrypt,
+                no_pad: false,
+            },
+        };
+        let encoded = encode_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encoded else {
+            unreachable!()
+        };
+
+        let decode_tool = Base64Tool {
+            command: Base64Command::Decode {
+                text: StringInput(val.as_str().unwrap().to_string()),
+                urlsafe: false,
+                alphabet: Base64Alphabet::Bcrypt,
+                no_pad: false,
+            },
+        };
+        let decoded = decode_tool.execute().unwrap().unwrap();
+        let Output::Bytes(bytes) = decoded else {
+            unreachable!()
+        };
+        assert_eq!(String::from_utf8(bytes).unwrap(), original);
+    }
 }
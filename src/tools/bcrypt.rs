@@ -1,7 +1,15 @@
 use crate::args::StringInput;
 use crate::tool::{Output, Tool};
-use anyhow::{Context, Result};
-use clap::{Command, CommandFactory, Parser, Subcommand};
+use anyhow::{Context, Result, bail};
+use base64::{Engine as _, engine::general_purpose};
+use bcrypt::{Version, hash_with_result};
+use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
+use sha2::{Digest, Sha256};
+
+// bcrypt only examines the first 72 bytes of its input; anything past that
+// is silently ignored, so two passwords differing only after byte 72 hash
+// identically.
+const MAX_BCRYPT_BYTES: usize = 72;
 
 #[derive(Parser, Debug)]
 #[command(name = "bcrypt", about = "bcrypt hashing and verification utilities")]
@@ -20,6 +28,16 @@ enum BcryptCommand {
         /// Cost factor (4-31, default: 12). Higher values are more secure but slower
         #[arg(short, long, default_value = "12")]
         cost: u32,
+
+        /// Hash prefix to emit, for targeting legacy systems (e.g. old PHP
+        /// crypt output using $2y$)
+        #[arg(long, value_enum, default_value = "two-b")]
+        version: BcryptVersion,
+
+        /// SHA-256 + base64 the password before handing it to bcrypt, so
+        /// inputs longer than 72 bytes don't get silently truncated
+        #[arg(long)]
+        prehash: bool,
     },
     /// Verify a password against a bcrypt hash
     Verify {
@@ -29,6 +47,38 @@ enum BcryptCommand {
         /// Bcrypt hash to verify against
         hash: String,
     },
+    /// Parse a bcrypt hash into its version, cost, salt, and hash components
+    Inspect {
+        /// Bcrypt hash to inspect
+        hash: String,
+    },
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum BcryptVersion {
+    /// $2a$: the original specification
+    #[value(name = "2a", alias = "two-a")]
+    TwoA,
+    /// $2x$: crypt_blowfish's buggy-but-compatible variant
+    #[value(name = "2x", alias = "two-x")]
+    TwoX,
+    /// $2y$: crypt_blowfish's fixed variant, as emitted by older PHP crypt()
+    #[value(name = "2y", alias = "two-y")]
+    TwoY,
+    /// $2b$: the current specification (default)
+    #[value(name = "2b", alias = "two-b")]
+    TwoB,
+}
+
+impl From<BcryptVersion> for Version {
+    fn from(version: BcryptVersion) -> Self {
+        match version {
+            BcryptVersion::TwoA => Version::TwoA,
+            BcryptVersion::TwoX => Version::TwoX,
+            BcryptVersion::TwoY => Version::TwoY,
+            BcryptVersion::TwoB => Version::TwoB,
+        }
+    }
 }
 
 impl Tool for BcryptTool {
@@ -38,31 +88,102 @@ impl Tool for BcryptTool {
 
     fn execute(&self) -> Result<Option<Output>> {
         match &self.command {
-            BcryptCommand::Hash { password, cost } => {
+            BcryptCommand::Hash {
+                password,
+                cost,
+                version,
+                prehash,
+            } => {
                 // Validate cost
                 if *cost < 4 || *cost > 31 {
                     anyhow::bail!("Cost must be between 4 and 31");
                 }
 
+                if !*prehash && password.as_ref().len() > MAX_BCRYPT_BYTES {
+                    bail!(
+                        "Password is {} bytes, but bcrypt only examines the first {MAX_BCRYPT_BYTES}; \
+                         the rest would be silently ignored. Pass --prehash to SHA-256 the \
+                         password first instead",
+                        password.as_ref().len()
+                    );
+                }
+
+                let input = if *prehash {
+                    prehash_password(password.as_ref())
+                } else {
+                    password.as_ref().to_string()
+                };
+
+                let parts = hash_with_result(input, *cost).context("Failed to hash password")?;
+
                 Ok(Some(Output::JsonValue(serde_json::json!(
-                    bcrypt::hash(password.as_ref(), *cost).context("Failed to hash password")?
+                    parts.format_for_version((*version).into())
                 ))))
             }
             BcryptCommand::Verify { password, hash } => {
+                // A password this long can only have produced `hash` via
+                // --prehash, since Hash refuses to truncate it silently, so
+                // apply the same transform here to match.
+                let input = if password.as_ref().len() > MAX_BCRYPT_BYTES {
+                    prehash_password(password.as_ref())
+                } else {
+                    password.as_ref().to_string()
+                };
+
                 let is_valid =
-                    bcrypt::verify(password.as_ref(), hash).context("Failed to verify password")?;
+                    bcrypt::verify(input, hash).context("Failed to verify password")?;
 
-                // TODO: Also use proper exit code.
-                Ok(Some(Output::JsonValue(serde_json::json!(if is_valid {
-                    "valid"
-                } else {
-                    "invalid"
-                }))))
+                Ok(Some(Output::Status {
+                    value: serde_json::json!(if is_valid { "valid" } else { "invalid" }),
+                    exit_code: if is_valid { 0 } else { 1 },
+                }))
             }
+            BcryptCommand::Inspect { hash } => Ok(Some(Output::JsonValue(serde_json::json!(
+                inspect_hash(hash)?
+            )))),
         }
     }
 }
 
+// SHA-256 hashes the password and base64-encodes the digest, yielding a
+// fixed 44-byte string that is always within bcrypt's 72-byte limit.
+fn prehash_password(password: &str) -> String {
+    let digest = Sha256::digest(password.as_bytes());
+    general_purpose::STANDARD.encode(digest)
+}
+
+// Splits a bcrypt hash string ($<version>$<cost>$<22-char salt><31-char
+// hash>) into its components without verifying anything. Rejects anything
+// that doesn't match the shape bcrypt itself produces.
+fn inspect_hash(hash: &str) -> Result<serde_json::Value> {
+    let rest = hash.strip_prefix('$').context("Malformed bcrypt hash")?;
+    let mut segments = rest.splitn(3, '$');
+
+    let version = segments.next().context("Malformed bcrypt hash")?;
+    if !["2a", "2x", "2y", "2b"].contains(&version) {
+        bail!("Unrecognized bcrypt version prefix: {version}");
+    }
+
+    let cost: u32 = segments
+        .next()
+        .context("Malformed bcrypt hash")?
+        .parse()
+        .context("Malformed bcrypt hash: cost is not a number")?;
+
+    let salt_and_hash = segments.next().context("Malformed bcrypt hash")?;
+    if salt_and_hash.len() != 53 {
+        bail!("Malformed bcrypt hash: expected a 22-char salt and 31-char hash");
+    }
+    let (salt, digest) = salt_and_hash.split_at(22);
+
+    Ok(serde_json::json!({
+        "version": version,
+        "cost": cost,
+        "salt": salt,
+        "hash": digest,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -74,6 +195,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("test_password".to_string()),
                 cost: 12,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -94,6 +217,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("test_password".to_string()),
                 cost: 8,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -113,6 +238,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("test_password".to_string()),
                 cost: 3,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute();
@@ -131,6 +258,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("test_password".to_string()),
                 cost: 32,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute();
@@ -150,6 +279,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("correct_password".to_string()),
                 cost: 6, // Use lower cost for faster tests
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let hash_result = hash_tool.execute().unwrap().unwrap();
@@ -168,10 +299,15 @@ mod tests {
         };
         let verify_result = verify_tool.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val) = verify_result else {
-            panic!("Expected JsonValue output");
+        let Output::Status {
+            value: val,
+            exit_code,
+        } = verify_result
+        else {
+            panic!("Expected Status output");
         };
         assert_eq!(val.as_str().unwrap(), "valid");
+        assert_eq!(exit_code, 0);
     }
 
     #[test]
@@ -181,6 +317,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("correct_password".to_string()),
                 cost: 6, // Use lower cost for faster tests
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let hash_result = hash_tool.execute().unwrap().unwrap();
@@ -199,10 +337,15 @@ mod tests {
         };
         let verify_result = verify_tool.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val) = verify_result else {
-            panic!("Expected JsonValue output");
+        let Output::Status {
+            value: val,
+            exit_code,
+        } = verify_result
+        else {
+            panic!("Expected Status output");
         };
         assert_eq!(val.as_str().unwrap(), "invalid");
+        assert_eq!(exit_code, 1);
     }
 
     #[test]
@@ -223,6 +366,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("".to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -243,6 +388,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("".to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let hash_result = hash_tool.execute().unwrap().unwrap();
@@ -261,8 +408,8 @@ mod tests {
         };
         let verify_result = verify_tool.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val) = verify_result else {
-            panic!("Expected JsonValue output");
+        let Output::Status { value: val, .. } = verify_result else {
+            panic!("Expected Status output");
         };
         assert_eq!(val.as_str().unwrap(), "valid");
     }
@@ -273,6 +420,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("p@ssw0rd!#$%^&*()".to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -292,6 +441,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("ÂØÜÁ†Åüîí".to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -313,6 +464,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput(password.to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let hash_result = hash_tool.execute().unwrap().unwrap();
@@ -330,8 +483,8 @@ mod tests {
         };
         let verify_result = verify_tool.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val) = verify_result else {
-            panic!("Expected JsonValue output");
+        let Output::Status { value: val, .. } = verify_result else {
+            panic!("Expected Status output");
         };
         assert_eq!(val.as_str().unwrap(), "valid");
 
@@ -344,8 +497,8 @@ mod tests {
         };
         let verify_result2 = verify_tool2.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val2) = verify_result2 else {
-            panic!("Expected JsonValue output");
+        let Output::Status { value: val2, .. } = verify_result2 else {
+            panic!("Expected Status output");
         };
         assert_eq!(val2.as_str().unwrap(), "invalid");
     }
@@ -357,6 +510,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("same_password".to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result1 = tool1.execute().unwrap().unwrap();
@@ -365,6 +520,8 @@ mod tests {
             command: BcryptCommand::Hash {
                 password: StringInput("same_password".to_string()),
                 cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
             },
         };
         let result2 = tool2.execute().unwrap().unwrap();
@@ -388,9 +545,189 @@ mod tests {
             },
         };
         let verify_result = verify_tool.execute().unwrap().unwrap();
-        let Output::JsonValue(val) = verify_result else {
-            panic!("Expected JsonValue output");
+        let Output::Status { value: val, .. } = verify_result else {
+            panic!("Expected Status output");
         };
         assert_eq!(val.as_str().unwrap(), "valid");
     }
+
+    #[test]
+    fn test_hash_with_version_sets_prefix() {
+        let tool = BcryptTool {
+            command: BcryptCommand::Hash {
+                password: StringInput("legacy_password".to_string()),
+                cost: 6,
+                version: BcryptVersion::TwoY,
+                prehash: false,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+
+        let hash = val.as_str().unwrap();
+        assert!(hash.starts_with("$2y$06$"));
+        assert_eq!(hash.len(), 60);
+    }
+
+    #[test]
+    fn test_inspect_parses_known_hash() {
+        let tool = BcryptTool {
+            command: BcryptCommand::Inspect {
+                hash: "$2b$12$N9qo8uLOickgx2ZMRZoMyeIjZAgcfl7p92ldGxad68LJZdL17lhWy"
+                    .to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+
+        assert_eq!(val["version"], "2b");
+        assert_eq!(val["cost"], 12);
+        assert_eq!(val["salt"], "N9qo8uLOickgx2ZMRZoMye");
+        assert_eq!(val["hash"], "IjZAgcfl7p92ldGxad68LJZdL17lhWy");
+    }
+
+    #[test]
+    fn test_inspect_roundtrips_freshly_hashed_value() {
+        let hash_tool = BcryptTool {
+            command: BcryptCommand::Hash {
+                password: StringInput("roundtrip".to_string()),
+                cost: 6,
+                version: BcryptVersion::TwoA,
+                prehash: false,
+            },
+        };
+        let hash_result = hash_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = hash_result else {
+            panic!("Expected JsonValue output");
+        };
+        let hash = val.as_str().unwrap().to_string();
+
+        let inspect_tool = BcryptTool {
+            command: BcryptCommand::Inspect { hash },
+        };
+        let inspect_result = inspect_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(inspected) = inspect_result else {
+            panic!("Expected JsonValue output");
+        };
+
+        assert_eq!(inspected["version"], "2a");
+        assert_eq!(inspected["cost"], 6);
+        assert_eq!(inspected["salt"].as_str().unwrap().len(), 22);
+        assert_eq!(inspected["hash"].as_str().unwrap().len(), 31);
+    }
+
+    #[test]
+    fn test_inspect_rejects_malformed_hash() {
+        let tool = BcryptTool {
+            command: BcryptCommand::Inspect {
+                hash: "not-a-bcrypt-hash".to_string(),
+            },
+        };
+        let result = tool.execute();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hash_rejects_long_password_without_prehash() {
+        let tool = BcryptTool {
+            command: BcryptCommand::Hash {
+                password: StringInput("x".repeat(73)),
+                cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
+            },
+        };
+        let result = tool.execute();
+        assert!(result.is_err());
+        assert!(
+            result
+                .unwrap_err()
+                .to_string()
+                .contains("only examines the first 72")
+        );
+    }
+
+    #[test]
+    fn test_hash_with_prehash_accepts_long_password() {
+        let tool = BcryptTool {
+            command: BcryptCommand::Hash {
+                password: StringInput("x".repeat(200)),
+                cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: true,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert!(val.as_str().unwrap().starts_with("$2b$06$"));
+    }
+
+    #[test]
+    fn test_prehashed_password_verifies_via_auto_detect() {
+        let long_password = "x".repeat(200);
+
+        let hash_tool = BcryptTool {
+            command: BcryptCommand::Hash {
+                password: StringInput(long_password.clone()),
+                cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: true,
+            },
+        };
+        let hash_result = hash_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = hash_result else {
+            panic!("Expected JsonValue output");
+        };
+        let hash = val.as_str().unwrap().to_string();
+
+        let verify_tool = BcryptTool {
+            command: BcryptCommand::Verify {
+                password: StringInput(long_password),
+                hash,
+            },
+        };
+        let verify_result = verify_tool.execute().unwrap().unwrap();
+        let Output::Status { value: verified, .. } = verify_result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(verified.as_str().unwrap(), "valid");
+    }
+
+    #[test]
+    fn test_short_password_does_not_use_prehash_transform() {
+        let hash_tool = BcryptTool {
+            command: BcryptCommand::Hash {
+                password: StringInput("short_password".to_string()),
+                cost: 6,
+                version: BcryptVersion::TwoB,
+                prehash: false,
+            },
+        };
+        let hash_result = hash_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = hash_result else {
+            panic!("Expected JsonValue output");
+        };
+        let hash = val.as_str().unwrap().to_string();
+
+        let verify_tool = BcryptTool {
+            command: BcryptCommand::Verify {
+                password: StringInput("short_password".to_string()),
+                hash,
+            },
+        };
+        let verify_result = verify_tool.execute().unwrap().unwrap();
+        let Output::Status { value: verified, .. } = verify_result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(verified.as_str().unwrap(), "valid");
+    }
 }
@@ -0,0 +1,202 @@
+use crate::args::StringInput;
+use crate::tool::{Output, Tool};
+use anyhow::{Context, bail};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use clap::{Command, CommandFactory, Parser};
+use regex::Regex;
+use serde_json::{Map, Value, json};
+use std::collections::HashMap;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "bucket",
+    about = "Histogram log line timestamps into fixed-size time buckets"
+)]
+pub struct BucketTool {
+    /// Log lines to bucket (use "-" for stdin)
+    input: StringInput,
+
+    /// Regex matched against each line; the timestamp is taken from capture
+    /// group 1 unless --group names a capture instead
+    #[arg(short = 'p', long = "pattern")]
+    pattern: String,
+
+    /// strftime format string used to parse the captured timestamp
+    #[arg(short = 'f', long = "format")]
+    format: String,
+
+    /// Named capture group to read the timestamp from, instead of group 1
+    #[arg(short = 'g', long = "group")]
+    group: Option<String>,
+
+    /// Bucket width: an integer followed by s, m, h, or d (default: 1h)
+    #[arg(short = 'i', long = "interval", default_value = "1h")]
+    interval: String,
+}
+
+// Parses widths like "1m", "5m", "1h", "1d" into a bucket size in seconds.
+fn parse_interval_seconds(interval: &str) -> anyhow::Result<i64> {
+    let split_at = interval
+        .find(|c: char| !c.is_ascii_digit())
+        .filter(|&i| i > 0)
+        .with_context(|| format!("Invalid interval: {interval}"))?;
+    let (amount, unit) = interval.split_at(split_at);
+
+    let amount: i64 = amount
+        .parse()
+        .with_context(|| format!("Invalid interval: {interval}"))?;
+
+    let unit_seconds = match unit {
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        "d" => 86400,
+        _ => bail!("Invalid interval unit '{unit}'. Use one of: s, m, h, d"),
+    };
+
+    Ok(amount * unit_seconds)
+}
+
+fn floor_to_bucket(timestamp: DateTime<Utc>, interval_seconds: i64) -> DateTime<Utc> {
+    let floored_epoch = timestamp.timestamp().div_euclid(interval_seconds) * interval_seconds;
+    DateTime::from_timestamp(floored_epoch, 0).unwrap_or(timestamp)
+}
+
+fn extract_timestamp(captures: &regex::Captures, group: Option<&str>) -> Option<String> {
+    match group {
+        Some(name) => captures.name(name).map(|m| m.as_str().to_string()),
+        None => captures.get(1).map(|m| m.as_str().to_string()),
+    }
+}
+
+impl Tool for BucketTool {
+    fn cli() -> Command {
+        BucketTool::command()
+    }
+
+    fn execute(&self) -> anyhow::Result<Option<Output>> {
+        let pattern = Regex::new(&self.pattern).context("Invalid --pattern regex")?;
+        let interval_seconds = parse_interval_seconds(&self.interval)?;
+
+        let mut buckets: HashMap<DateTime<Utc>, u64> = HashMap::new();
+        let mut unmatched: u64 = 0;
+
+        for line in self.input.as_ref().lines() {
+            let timestamp = pattern
+                .captures(line)
+                .and_then(|captures| extract_timestamp(&captures, self.group.as_deref()))
+                .and_then(|text| NaiveDateTime::parse_from_str(&text, &self.format).ok());
+
+            match timestamp {
+                Some(naive) => {
+                    let bucket = floor_to_bucket(naive.and_utc(), interval_seconds);
+                    *buckets.entry(bucket).or_insert(0) += 1;
+                }
+                None => unmatched += 1,
+            }
+        }
+
+        let mut sorted_buckets: Vec<(DateTime<Utc>, u64)> = buckets.into_iter().collect();
+        sorted_buckets.sort_by_key(|(timestamp, _)| *timestamp);
+
+        let mut counts = Map::new();
+        for (timestamp, count) in sorted_buckets {
+            counts.insert(timestamp.to_rfc3339(), json!(count));
+        }
+        if unmatched > 0 {
+            counts.insert("unmatched".to_string(), json!(unmatched));
+        }
+
+        Ok(Some(Output::JsonValue(Value::Object(counts))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bucket_tool(input: &str, pattern: &str, format: &str, interval: &str) -> BucketTool {
+        BucketTool {
+            input: StringInput(input.to_string()),
+            pattern: pattern.to_string(),
+            format: format.to_string(),
+            group: None,
+            interval: interval.to_string(),
+        }
+    }
+
+    #[test]
+    fn test_counts_lines_per_bucket() {
+        let input = "\
+2024-01-01 10:00:01 started
+2024-01-01 10:00:45 still going
+2024-01-01 10:01:02 next minute";
+
+        let tool = bucket_tool(
+            input,
+            r"^(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})",
+            "%Y-%m-%d %H:%M:%S",
+            "1m",
+        );
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(value) = result else {
+            unreachable!()
+        };
+        let obj = value.as_object().unwrap();
+
+        assert_eq!(obj.get("2024-01-01T10:00:00+00:00").unwrap(), 2);
+        assert_eq!(obj.get("2024-01-01T10:01:00+00:00").unwrap(), 1);
+        assert!(obj.get("unmatched").is_none());
+    }
+
+    #[test]
+    fn test_unmatched_lines_are_tallied_separately() {
+        let input = "\
+2024-01-01 10:00:01 started
+not a log line at all";
+
+        let tool = bucket_tool(
+            input,
+            r"^(\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2})",
+            "%Y-%m-%d %H:%M:%S",
+            "1h",
+        );
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(value) = result else {
+            unreachable!()
+        };
+        let obj = value.as_object().unwrap();
+
+        assert_eq!(obj.get("2024-01-01T10:00:00+00:00").unwrap(), 1);
+        assert_eq!(obj.get("unmatched").unwrap(), 1);
+    }
+
+    #[test]
+    fn test_named_group_is_used_when_given() {
+        let input = "level=info time=2024-06-01T08:15:30Z msg=ready";
+
+        let mut tool = bucket_tool(
+            input,
+            r"time=(?P<ts>\S+)",
+            "%Y-%m-%dT%H:%M:%SZ",
+            "1d",
+        );
+        tool.group = Some("ts".to_string());
+
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(value) = result else {
+            unreachable!()
+        };
+        let obj = value.as_object().unwrap();
+
+        assert_eq!(obj.get("2024-06-01T00:00:00+00:00").unwrap(), 1);
+    }
+
+    #[test]
+    fn test_invalid_interval_unit_is_rejected() {
+        let tool = bucket_tool("", r"(\d+)", "%s", "1x");
+        assert!(tool.execute().is_err());
+    }
+}
@@ -1,18 +1,183 @@
 use crate::tool::{Output, Tool};
 use anyhow::{Result, anyhow};
-use clap::{Command, CommandFactory, Parser};
+use clap::{Command, CommandFactory, Parser, ValueEnum};
 use nom::{
     IResult,
     branch::alt,
     bytes::complete::{tag, tag_no_case, take_while1},
     character::complete::{char, multispace0},
     combinator::{map, map_res, opt, recognize},
-    multi::separated_list0,
+    multi::{separated_list0, separated_list1},
     sequence::{delimited, pair, preceded, tuple},
 };
 use rust_decimal::MathematicalOps;
+use rust_decimal::RoundingStrategy;
 use rust_decimal::prelude::*;
 use serde_json::json;
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+
+/// A computed value: either an arithmetic result or a boolean, mirroring
+/// the typed-value model `expressive` uses for its own `eval` (which
+/// yields `Int`/`Float`/`Boolean`). Keeping this as a thin enum rather than
+/// forcing everything into `Decimal` is what lets comparison/logical
+/// expressions like `5 > 3 && 2 < 1` flow through the same grammar as
+/// arithmetic ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Value {
+    Decimal(Decimal),
+    Boolean(bool),
+}
+
+/// Unwraps a `Value` expected to be numeric, e.g. an operand to `+` or
+/// `<<`. Honors short-circuit suppression (see `fallback_or_fail`): inside
+/// a `&&`/`||` branch that was never supposed to run, a type mismatch is
+/// swallowed instead of failing the whole parse.
+fn require_decimal(value: Value, input: &str) -> IResult<&str, Decimal> {
+    match value {
+        Value::Decimal(d) => Ok((input, d)),
+        Value::Boolean(_) => fallback_or_fail(input, Decimal::ZERO),
+    }
+}
+
+/// Unwraps a `Value` expected to be boolean, e.g. an operand to `&&` or `!`.
+/// See `require_decimal` for the short-circuit suppression behavior.
+fn require_boolean(value: Value, input: &str) -> IResult<&str, bool> {
+    match value {
+        Value::Boolean(b) => Ok((input, b)),
+        Value::Decimal(_) => fallback_or_fail(input, false),
+    }
+}
+
+// Functions like `base()` render their result in a radix the output's
+// always-on decimal/hex/binary/octal fields can't express (letters, radix
+// other than 2/8/10/16). Since `apply_function` can only hand back a single
+// `Decimal` to stay composable with the rest of the expression grammar, the
+// last rendered custom-base string is stashed here and picked up once
+// evaluation finishes. Thread-local because each `ut calc` invocation (and
+// each test) evaluates exactly one expression on its own thread.
+thread_local! {
+    static LAST_CUSTOM_BASE: RefCell<Option<(u32, String)>> = const { RefCell::new(None) };
+    // Set once from `--rounding` before evaluation starts, and read by
+    // `round()` calls that don't specify their own mode. Same thread-local
+    // smuggling trick as `LAST_CUSTOM_BASE`, used here in the other
+    // direction (top-down instead of bottom-up).
+    static DEFAULT_ROUNDING_STRATEGY: RefCell<RoundingStrategy> =
+        RefCell::new(RoundingStrategy::MidpointNearestEven);
+    // The evaluation environment: the predefined constants plus whatever
+    // `--var` flags and `name = expr` assignment statements add on top.
+    // Reset to `default_environment()` before each evaluation, then mutated
+    // in place as assignment statements run left to right.
+    static ENVIRONMENT: RefCell<HashMap<String, Value>> = RefCell::new(default_environment());
+    // How many nested short-circuited `&&`/`||` branches are currently being
+    // evaluated. While positive, operand errors that would otherwise fail
+    // the whole parse (an out-of-range bitwise operand, division by zero,
+    // an unknown variable, a function's own validation, a type mismatch)
+    // are swallowed instead, so `false && (1 / 0 > 2)` returns `false`
+    // rather than erroring on a branch that was never supposed to run.
+    static SHORT_CIRCUIT_DEPTH: Cell<u32> = const { Cell::new(0) };
+}
+
+/// True while evaluating the right-hand side of a short-circuited
+/// `&&`/`||`. See `SHORT_CIRCUIT_DEPTH`.
+fn is_short_circuited() -> bool {
+    SHORT_CIRCUIT_DEPTH.with(|cell| cell.get() > 0)
+}
+
+/// Runs `parser` with short-circuit suppression enabled when `suppress` is
+/// set, used by `&&`/`||` to evaluate the operand that won't affect the
+/// result without letting its errors escape.
+fn with_short_circuit<'a, T>(
+    suppress: bool,
+    parser: impl FnOnce(&'a str) -> IResult<&'a str, T>,
+    input: &'a str,
+) -> IResult<&'a str, T> {
+    if !suppress {
+        return parser(input);
+    }
+
+    SHORT_CIRCUIT_DEPTH.with(|cell| cell.set(cell.get() + 1));
+    let result = parser(input);
+    SHORT_CIRCUIT_DEPTH.with(|cell| cell.set(cell.get() - 1));
+    result
+}
+
+/// Returns `fallback` if we're inside a short-circuited `&&`/`||` branch,
+/// otherwise fails the parse the same way the rest of this module's domain
+/// checks do.
+fn fallback_or_fail<T>(input: &str, fallback: T) -> IResult<&str, T> {
+    if is_short_circuited() {
+        Ok((input, fallback))
+    } else {
+        Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        )))
+    }
+}
+
+/// The identifiers available to an expression before any `--var` flags or
+/// `name = expr` assignments are applied.
+fn default_environment() -> HashMap<String, Value> {
+    let mut env = HashMap::new();
+    env.insert("pi".to_string(), Value::Decimal(pi()));
+    env.insert("e".to_string(), Value::Decimal(e()));
+    env.insert("tau".to_string(), Value::Decimal(pi() * Decimal::TWO));
+    env
+}
+
+/// Parses repeated `--var name=value` flags into variable bindings that
+/// seed the evaluation environment, the same `name=value` convention the
+/// `jwt` tool uses for `--payload-item`/`--header`.
+fn parse_var_flags(vars: &[String]) -> Result<HashMap<String, Value>> {
+    let mut env = HashMap::new();
+    for var in vars {
+        let (name, raw_value) = var
+            .split_once('=')
+            .ok_or_else(|| anyhow!("Invalid --var {var:?}, expected name=value"))?;
+        let value = Decimal::from_str(raw_value)
+            .map_err(|_| anyhow!("Invalid --var {var:?}: {raw_value:?} is not a number"))?;
+        env.insert(name.to_string(), Value::Decimal(value));
+    }
+    Ok(env)
+}
+
+/// The rounding strategy to apply at a midpoint (e.g. 2.5 -> 2 or 3),
+/// named the way users commonly describe them rather than after
+/// `rust_decimal`'s `RoundingStrategy` variants
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum RoundingMode {
+    HalfUp,
+    HalfEven,
+    HalfDown,
+    TowardZero,
+}
+
+impl RoundingMode {
+    fn to_strategy(self) -> RoundingStrategy {
+        match self {
+            RoundingMode::HalfUp => RoundingStrategy::MidpointAwayFromZero,
+            RoundingMode::HalfEven => RoundingStrategy::MidpointNearestEven,
+            RoundingMode::HalfDown => RoundingStrategy::MidpointTowardZero,
+            RoundingMode::TowardZero => RoundingStrategy::ToZero,
+        }
+    }
+}
+
+/// Parses a round() mode string ("half_up", "half_even", "half_down",
+/// "toward_zero") as used inside an expression, distinct from the
+/// kebab-case `--rounding` CLI flag
+fn parse_rounding_mode_name(name: &str) -> Result<RoundingStrategy> {
+    match name {
+        "half_up" => Ok(RoundingStrategy::MidpointAwayFromZero),
+        "half_even" => Ok(RoundingStrategy::MidpointNearestEven),
+        "half_down" => Ok(RoundingStrategy::MidpointTowardZero),
+        "toward_zero" => Ok(RoundingStrategy::ToZero),
+        _ => Err(anyhow!(
+            "round() mode must be one of half_up, half_even, half_down, toward_zero"
+        )),
+    }
+}
 
 /// Calculator and number base converter.
 #[derive(Parser, Debug)]
@@ -21,6 +186,30 @@ pub struct CalcTool {
     /// Expression to evaluate
     /// Supports arithmetic, functions, constants, and multiple number formats
     expression: String,
+
+    /// Default rounding strategy used by round() when it isn't given an
+    /// explicit mode argument
+    #[arg(long, value_enum, default_value = "half-even")]
+    rounding: RoundingMode,
+
+    /// Bind a variable for the expression to reference, as name=value
+    /// (repeatable). Predefined constants (pi, e, tau) can be overridden
+    /// the same way
+    #[arg(short = 'V', long = "var")]
+    var: Vec<String>,
+
+    /// Round the final result to this many digits after the decimal point
+    /// (using the `--rounding` strategy), for financial-style calculations
+    /// where the full ~28-digit precision of the underlying fixed-point
+    /// decimal is more noise than signal
+    #[arg(long)]
+    precision: Option<u32>,
+
+    /// Also render the final result in this radix (2-36), independent of
+    /// any base() call inside the expression itself, under the "custom"
+    /// field
+    #[arg(long)]
+    to_base: Option<u32>,
 }
 
 impl Tool for CalcTool {
@@ -30,25 +219,66 @@ impl Tool for CalcTool {
     }
 
     /// Executes the calculator tool with the provided expression
-    /// Returns the result formatted in decimal, binary, and hexadecimal
+    /// Returns the result formatted in decimal, binary, and hexadecimal,
+    /// or as a "boolean" field when the expression is a predicate
     fn execute(&self) -> Result<Option<Output>> {
         // Parse and evaluate the mathematical expression
+        LAST_CUSTOM_BASE.with(|cell| *cell.borrow_mut() = None);
+        DEFAULT_ROUNDING_STRATEGY.with(|cell| *cell.borrow_mut() = self.rounding.to_strategy());
+        let mut environment = default_environment();
+        environment.extend(parse_var_flags(&self.var)?);
+        ENVIRONMENT.with(|cell| *cell.borrow_mut() = environment);
         let result = evaluate_expression(&self.expression)?;
 
-        // Format the result in multiple number bases
-        let output = json!({
-            "decimal": result.to_string(),
-            "hex": format_hex(result),
-            "binary": format_binary(result),
-        });
+        let boolean = match result {
+            Value::Boolean(boolean) => boolean,
+            Value::Decimal(result) => {
+                let result = match self.precision {
+                    Some(digits) => {
+                        result.round_dp_with_strategy(digits, self.rounding.to_strategy())
+                    }
+                    None => result,
+                };
+
+                // Format the result in multiple number bases
+                let mut output = json!({
+                    "decimal": result.to_string(),
+                    "hex": format_hex(result),
+                    "binary": format_binary(result),
+                    "octal": format_octal(result),
+                });
+
+                if let Some((radix, value)) = LAST_CUSTOM_BASE.with(|cell| cell.borrow().clone()) {
+                    output["base"] = json!({ "radix": radix, "value": value });
+                }
 
-        Ok(Some(Output::JsonValue(output)))
+                if let Some(radix) = self.to_base {
+                    output["custom"] = json!(render_custom_base(result, radix)?);
+                }
+
+                return Ok(Some(Output::JsonValue(output)));
+            }
+        };
+
+        if self.precision.is_some() || self.to_base.is_some() {
+            return Err(anyhow!(
+                "--precision and --to-base require a numeric result, but the expression evaluated to a boolean"
+            ));
+        }
+
+        Ok(Some(Output::JsonValue(json!({
+            "decimal": null,
+            "hex": null,
+            "binary": null,
+            "octal": null,
+            "boolean": boolean,
+        }))))
     }
 }
 
 /// Main entry point for expression evaluation using nom parser
-fn evaluate_expression(input: &str) -> Result<Decimal> {
-    match parse_expression(input.trim()) {
+fn evaluate_expression(input: &str) -> Result<Value> {
+    match parse_program(input.trim()) {
         Ok((remaining, result)) => {
             if remaining.is_empty() {
                 Ok(result)
@@ -63,71 +293,575 @@ fn evaluate_expression(input: &str) -> Result<Decimal> {
     }
 }
 
-/// Parses a complete mathematical expression with proper precedence
-fn parse_expression(input: &str) -> IResult<&str, Decimal> {
-    delimited(multispace0, parse_bitwise_or, multispace0)(input)
+/// Parses `;`-separated statements (assignments and/or expressions),
+/// evaluating them in order and returning the last one's value. This is
+/// what lets `x = 5; y = x * 2; y + 1` bind and reuse intermediate results.
+fn parse_program(input: &str) -> IResult<&str, Value> {
+    map(
+        separated_list1(delimited(multispace0, char(';'), multispace0), parse_statement),
+        |results| *results.last().expect("separated_list1 returns at least one item"),
+    )(input)
+}
+
+/// A single statement: either `name = expr`, which binds `name` in the
+/// evaluation environment, or a plain expression.
+fn parse_statement(input: &str) -> IResult<&str, Value> {
+    alt((parse_assignment, parse_expression))(input)
+}
+
+/// Parses `name = expr` and binds the result into `ENVIRONMENT`, so later
+/// statements (and the bare-identifier lookups in `parse_variable`) can
+/// reference it by name. A single `=` only, so it backs off (via the
+/// ordinary recoverable nom error) in favor of `parse_expression` when what
+/// follows is actually `==`.
+fn parse_assignment(input: &str) -> IResult<&str, Value> {
+    let (input, name) = delimited(multispace0, parse_identifier, multispace0)(input)?;
+    let (input, _) = char('=')(input)?;
+    let (input, value) = parse_expression(input)?;
+
+    ENVIRONMENT.with(|cell| cell.borrow_mut().insert(name, value));
+    Ok((input, value))
+}
+
+/// Resolves a bare identifier against the evaluation environment
+/// (predefined constants, `--var` flags, and prior assignments). Fails
+/// (like the "IdentifierNotFound" case of `expressive`'s `Configuration`
+/// lookup) if the name isn't bound, unless a short-circuited `&&`/`||`
+/// branch is swallowing the error (see `fallback_or_fail`).
+fn parse_variable(input: &str) -> IResult<&str, Value> {
+    let (input, name) = parse_identifier(input)?;
+
+    match ENVIRONMENT.with(|cell| cell.borrow().get(&name).copied()) {
+        Some(value) => Ok((input, value)),
+        None => fallback_or_fail(input, Value::Decimal(Decimal::ZERO)),
+    }
+}
+
+/// Parses a complete expression with proper precedence: logical OR/AND
+/// bind loosest, then equality, then comparison, then the numeric grammar
+/// (bitwise, shift, arithmetic) below it.
+fn parse_expression(input: &str) -> IResult<&str, Value> {
+    delimited(multispace0, parse_logical_or, multispace0)(input)
+}
+
+/// Parses a full expression and requires it evaluate to a number, for call
+/// sites (function arguments, a parenthesized operand inside arithmetic)
+/// that have no use for a boolean result.
+fn parse_numeric_expression(input: &str) -> IResult<&str, Decimal> {
+    let (input, value) = parse_expression(input)?;
+    require_decimal(value, input)
+}
+
+/// Matches the `||` operator (with surrounding whitespace), recoverably.
+fn or_operator(input: &str) -> IResult<&str, &str> {
+    delimited(multispace0, tag("||"), multispace0)(input)
+}
+
+/// Matches the `&&` operator (with surrounding whitespace), recoverably.
+fn and_operator(input: &str) -> IResult<&str, &str> {
+    delimited(multispace0, tag("&&"), multispace0)(input)
+}
+
+/// Handles logical OR (lowest precedence), short-circuiting so
+/// `true || (1 / 0 > 2)` returns `true` without evaluating the right side.
+fn parse_logical_or(input: &str) -> IResult<&str, Value> {
+    let (mut input, mut result) = parse_logical_and(input)?;
+
+    while let Ok((rest, _)) = or_operator(input) {
+        let short_circuit = matches!(result, Value::Boolean(true));
+        let (rest, rhs) = with_short_circuit(short_circuit, parse_logical_and, rest)?;
+        input = rest;
+
+        if !short_circuit {
+            let (_, lhs) = require_boolean(result, input)?;
+            let (_, rhs) = require_boolean(rhs, input)?;
+            result = Value::Boolean(lhs || rhs);
+        }
+    }
+
+    Ok((input, result))
+}
+
+/// Handles logical AND (higher precedence than OR), short-circuiting so
+/// `false && (1 / 0 > 2)` returns `false` without evaluating the right side.
+fn parse_logical_and(input: &str) -> IResult<&str, Value> {
+    let (mut input, mut result) = parse_equality(input)?;
+
+    while let Ok((rest, _)) = and_operator(input) {
+        let short_circuit = matches!(result, Value::Boolean(false));
+        let (rest, rhs) = with_short_circuit(short_circuit, parse_equality, rest)?;
+        input = rest;
+
+        if !short_circuit {
+            let (_, lhs) = require_boolean(result, input)?;
+            let (_, rhs) = require_boolean(rhs, input)?;
+            result = Value::Boolean(lhs && rhs);
+        }
+    }
+
+    Ok((input, result))
+}
+
+/// Handles `==`/`!=` (non-chaining: `a == b == c` isn't supported).
+/// Requires both sides to be the same kind of value (two decimals or two
+/// booleans) rather than silently coercing.
+fn parse_equality(input: &str) -> IResult<&str, Value> {
+    let (input, left) = parse_comparison(input)?;
+
+    let (input, op) = opt(delimited(
+        multispace0,
+        alt((tag("=="), tag("!="))),
+        multispace0,
+    ))(input)?;
+
+    match op {
+        None => Ok((input, left)),
+        Some(op) => {
+            let (input, right) = parse_comparison(input)?;
+            let (input, equal) = values_equal(left, right, input)?;
+            Ok((input, Value::Boolean(if op == "==" { equal } else { !equal })))
+        }
+    }
+}
+
+/// Compares two values for equality, honoring short-circuit suppression
+/// the same way `require_decimal`/`require_boolean` do for a type mismatch.
+fn values_equal(left: Value, right: Value, input: &str) -> IResult<&str, bool> {
+    match (left, right) {
+        (Value::Decimal(a), Value::Decimal(b)) => Ok((input, a == b)),
+        (Value::Boolean(a), Value::Boolean(b)) => Ok((input, a == b)),
+        _ => fallback_or_fail(input, false),
+    }
+}
+
+/// Handles `<`/`<=`/`>`/`>=` (non-chaining, numeric operands only, higher
+/// precedence than equality)
+fn parse_comparison(input: &str) -> IResult<&str, Value> {
+    let (input, left) = parse_bitwise_or(input)?;
+
+    let (input, op) = opt(delimited(
+        multispace0,
+        alt((tag("<="), tag(">="), tag("<"), tag(">"))),
+        multispace0,
+    ))(input)?;
+
+    match op {
+        None => Ok((input, left)),
+        Some(op) => {
+            let (input, right) = parse_bitwise_or(input)?;
+            let (input, a) = require_decimal(left, input)?;
+            let (input, b) = require_decimal(right, input)?;
+            let result = match op {
+                "<" => a < b,
+                "<=" => a <= b,
+                ">" => a > b,
+                ">=" => a >= b,
+                _ => unreachable!(),
+            };
+            Ok((input, Value::Boolean(result)))
+        }
+    }
+}
+
+/// Validates a single operand for integer-only operations (bitwise ops,
+/// base conversion) and converts it to u64
+/// Returns an error if the operand is not a non-negative integer within u64 range
+fn validate_integer_operand(a: Decimal) -> Result<u64> {
+    if a.fract() != Decimal::ZERO {
+        Err(anyhow!("Expected an integer operand"))
+    } else if a.is_sign_negative() {
+        Err(anyhow!("Expected a non-negative integer operand"))
+    } else if a > Decimal::from(u64::MAX) {
+        Err(anyhow!("Operand must be within u64 range"))
+    } else {
+        Ok(a.to_u64().unwrap())
+    }
+}
+
+/// Reinterprets a negative integer as its 64-bit two's-complement bit
+/// pattern (e.g. -1 becomes 0xFFFFFFFFFFFFFFFF), the same representation
+/// the CPU itself would use, so bitwise operators can treat negative
+/// operands as ordinary bit patterns instead of rejecting them outright.
+/// Returns an error if the magnitude doesn't fit in 64 bits.
+fn twos_complement_u64(value: Decimal) -> Result<u64> {
+    let magnitude = -value;
+    if magnitude > Decimal::from(1u64 << 63) {
+        return Err(anyhow!(
+            "Operand must be within 64-bit two's-complement range"
+        ));
+    }
+
+    let magnitude = magnitude.to_u64().unwrap();
+    Ok((!magnitude).wrapping_add(1))
+}
+
+/// Validates a single bitwise operand and converts it to its 64-bit bit
+/// pattern. Non-negative integers up to `u64::MAX` pass through as-is;
+/// negative integers are reinterpreted via `twos_complement_u64` rather
+/// than rejected, so expressions like `~(-1)` or `-1 >> 4` work.
+fn validate_bitwise_operand(a: Decimal) -> Result<u64> {
+    if a.fract() != Decimal::ZERO {
+        Err(anyhow!("Expected an integer operand"))
+    } else if a.is_sign_negative() {
+        twos_complement_u64(a)
+    } else if a > Decimal::from(u64::MAX) {
+        Err(anyhow!("Operand must be within u64 range"))
+    } else {
+        Ok(a.to_u64().unwrap())
+    }
 }
 
-/// Validates operands for bitwise operations and converts them to u64
-/// Returns an error if operands are not non-negative integers within u64 range
+/// Validates operands for bitwise operations and converts them to their
+/// 64-bit bit patterns (see `validate_bitwise_operand`)
 fn validate_bitwise_operands(a: Decimal, b: Decimal) -> Result<(u64, u64)> {
-    if a.fract() != Decimal::ZERO || b.fract() != Decimal::ZERO {
-        Err(anyhow!("Bitwise operations require integer operands"))
-    } else if a.is_sign_negative() || b.is_sign_negative() {
-        Err(anyhow!("Bitwise operations require non-negative integers"))
-    } else if a > Decimal::from(u64::MAX) || b > Decimal::from(u64::MAX) {
-        Err(anyhow!(
-            "Bitwise operations require values within u64 range"
-        ))
+    Ok((validate_bitwise_operand(a)?, validate_bitwise_operand(b)?))
+}
+
+/// Validates a non-negative integer operand wider than `u64`, up to
+/// whatever `Decimal` itself can still represent (~2^96). Used by the
+/// bitwise/shift operators once an operand no longer fits in `u64`, so
+/// `0x1FFFFFFFFFFFFFFFF & 0xFF` works instead of hard-erroring.
+fn validate_wide_integer_operand(a: Decimal) -> Result<Decimal> {
+    if a.fract() != Decimal::ZERO {
+        Err(anyhow!("Expected an integer operand"))
+    } else if a.is_sign_negative() {
+        Err(anyhow!("Expected a non-negative integer operand"))
     } else {
-        Ok((a.to_u64().unwrap(), b.to_u64().unwrap()))
+        Ok(a)
     }
 }
 
-/// Handles bitwise OR (lower precedence than AND)
-fn parse_bitwise_or(input: &str) -> IResult<&str, Decimal> {
-    let (input, init) = parse_bitwise_and(input)?;
+/// A little-endian array of 64-bit limbs, used so the bitwise/shift
+/// operators keep working correctly past `u64::MAX` instead of hard
+/// erroring, up to whatever `Decimal`'s own ~96-bit range can still
+/// represent on the way back out. Modeled on the limb-array bigint
+/// representation `amplify_num` uses for its `u256`/`u512` types, but sized
+/// dynamically since this only ever needs to bridge the gap between `u64`
+/// and `Decimal`, not support truly arbitrary widths.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct BigBits(Vec<u64>);
+
+impl BigBits {
+    /// Decomposes a validated non-negative integer `Decimal` into
+    /// little-endian 64-bit limbs via its exact coefficient. `Decimal`
+    /// stores whole numbers as an exact (up to ~96-bit) integer, so unlike
+    /// a division-based decomposition, there's no precision to lose here.
+    fn from_decimal(value: Decimal) -> Result<Self> {
+        let normalized = value.normalize();
+        let coefficient = u128::try_from(normalized.mantissa())
+            .map_err(|_| anyhow!("Expected a non-negative integer operand"))?;
+
+        let mut limbs = vec![coefficient as u64, (coefficient >> 64) as u64];
+        trim_leading_zero_limbs(&mut limbs);
+        Ok(BigBits(limbs))
+    }
+
+    /// Wraps an already-computed 64-bit pattern (e.g. the two's-complement
+    /// encoding of a negative value from `validate_bitwise_operand`) as a
+    /// single-limb `BigBits`, for widening without re-deriving from a
+    /// signed `Decimal` that `from_decimal` would reject.
+    fn from_u64(value: u64) -> Self {
+        BigBits(vec![value])
+    }
+
+    /// Recomposes the limbs into a `Decimal`, erroring if the value no
+    /// longer fits in `Decimal`'s ~96-bit range (e.g. after a wide `<<`).
+    fn to_decimal(&self) -> Result<Decimal> {
+        let mut coefficient: u128 = 0;
+        for (index, limb) in self.0.iter().enumerate() {
+            let shift = 64u32
+                .checked_mul(index as u32)
+                .ok_or_else(|| anyhow!("Result is too large to represent"))?;
+            if shift >= 128 && *limb != 0 {
+                return Err(anyhow!("Result is too large to represent"));
+            }
+            if shift < 128 {
+                coefficient |= (*limb as u128)
+                    .checked_shl(shift)
+                    .ok_or_else(|| anyhow!("Result is too large to represent"))?;
+            }
+        }
+
+        Decimal::try_from(coefficient).map_err(|_| anyhow!("Result is too large to represent"))
+    }
+
+    /// Pads both operands to the same limb count, then combines limb by
+    /// limb with `op`. Used for `&`/`|`/`^`, which all operate bitwise
+    /// across the full width of the wider operand.
+    fn zip_with(&self, other: &Self, op: impl Fn(u64, u64) -> u64) -> Self {
+        let len = self.0.len().max(other.0.len());
+        let mut limbs = Vec::with_capacity(len);
+        for i in 0..len {
+            let a = self.0.get(i).copied().unwrap_or(0);
+            let b = other.0.get(i).copied().unwrap_or(0);
+            limbs.push(op(a, b));
+        }
+        trim_leading_zero_limbs(&mut limbs);
+        BigBits(limbs)
+    }
+
+    fn bitand(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    fn bitor(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    fn bitxor(&self, other: &Self) -> Self {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Inverts every bit across the operand's own limb width, the same
+    /// "invert over the value's own width" convention the scalar `~`
+    /// already uses for 64-bit values.
+    fn not(&self) -> Self {
+        let mut limbs: Vec<u64> = self.0.iter().map(|limb| !limb).collect();
+        trim_leading_zero_limbs(&mut limbs);
+        BigBits(limbs)
+    }
+
+    /// Shifts left by `shift` bits, growing the limb vector as needed.
+    fn shl(&self, shift: u32) -> Self {
+        let limb_shift = (shift / 64) as usize;
+        let bit_shift = shift % 64;
+
+        let mut limbs = vec![0u64; limb_shift];
+        let mut carry = 0u64;
+        for &limb in &self.0 {
+            let shifted = if bit_shift == 0 {
+                limb
+            } else {
+                (limb << bit_shift) | carry
+            };
+            carry = if bit_shift == 0 { 0 } else { limb >> (64 - bit_shift) };
+            limbs.push(shifted);
+        }
+        if carry != 0 {
+            limbs.push(carry);
+        }
+
+        trim_leading_zero_limbs(&mut limbs);
+        BigBits(limbs)
+    }
+
+    /// Shifts right by `shift` bits, shrinking the limb vector as needed.
+    fn shr(&self, shift: u32) -> Self {
+        let limb_shift = (shift / 64) as usize;
+        let bit_shift = shift % 64;
+
+        if limb_shift >= self.0.len() {
+            return BigBits(vec![0]);
+        }
+
+        let mut limbs: Vec<u64> = self.0[limb_shift..].to_vec();
+        if bit_shift > 0 {
+            for i in 0..limbs.len() {
+                let hi_bits = limbs
+                    .get(i + 1)
+                    .map(|next| next << (64 - bit_shift))
+                    .unwrap_or(0);
+                limbs[i] = (limbs[i] >> bit_shift) | hi_bits;
+            }
+        }
+
+        trim_leading_zero_limbs(&mut limbs);
+        BigBits(limbs)
+    }
+
+    /// Renders the value in `radix` (2-36) via the standard big-integer
+    /// divide-by-small-radix algorithm, carrying the remainder of each
+    /// limb's division into the next (most-significant first).
+    fn to_radix_string(&self, radix: u32) -> String {
+        let mut limbs = self.0.clone();
+        trim_leading_zero_limbs(&mut limbs);
+        if limbs.iter().all(|&limb| limb == 0) {
+            return "0".to_string();
+        }
+
+        let mut digits = Vec::new();
+        while !limbs.iter().all(|&limb| limb == 0) {
+            let mut remainder: u128 = 0;
+            for limb in limbs.iter_mut().rev() {
+                let acc = (remainder << 64) | (*limb as u128);
+                *limb = (acc / radix as u128) as u64;
+                remainder = acc % radix as u128;
+            }
+            digits.push(RADIX_DIGITS[remainder as usize]);
+        }
+
+        digits.reverse();
+        String::from_utf8(digits).unwrap()
+    }
+}
+
+/// Drops trailing (most-significant) all-zero limbs, keeping at least one
+/// limb so a zero value is still `[0]` rather than an empty vector.
+fn trim_leading_zero_limbs(limbs: &mut Vec<u64>) {
+    while limbs.len() > 1 && *limbs.last().unwrap() == 0 {
+        limbs.pop();
+    }
+}
+
+/// Evaluates a bitwise binary operator (`&`/`|`/`^`), taking the fast
+/// native-`u64` path when both operands fit (the common case) and falling
+/// back to `BigBits` once either operand is wider, up to `Decimal`'s own
+/// ~96-bit ceiling.
+fn apply_wide_bitwise(
+    a: Decimal,
+    b: Decimal,
+    narrow: impl Fn(u64, u64) -> u64,
+    wide: impl Fn(&BigBits, &BigBits) -> BigBits,
+) -> Result<Decimal> {
+    match validate_bitwise_operands(a, b) {
+        Ok((a, b)) => Ok(Decimal::from(narrow(a, b))),
+        Err(e) => {
+            let a = validate_wide_integer_operand(a).map_err(|_| anyhow!("{}", e))?;
+            let b = validate_wide_integer_operand(b).map_err(|_| anyhow!("{}", e))?;
+            wide(&BigBits::from_decimal(a)?, &BigBits::from_decimal(b)?).to_decimal()
+        }
+    }
+}
+
+/// Evaluates the unary bitwise-NOT operator, inverting over the operand's
+/// own width rather than a fixed 64 bits, taking the fast native-`u64` path
+/// when the operand fits and falling back to `BigBits` otherwise.
+fn apply_wide_bitwise_not(a: Decimal) -> Result<Decimal> {
+    match validate_bitwise_operand(a) {
+        Ok(a) => Ok(Decimal::from(!a)),
+        Err(e) => {
+            let a = validate_wide_integer_operand(a).map_err(|_| anyhow!("{}", e))?;
+            BigBits::from_decimal(a)?.not().to_decimal()
+        }
+    }
+}
+
+/// Evaluates `<<`/`>>`. The shift amount is always validated as a plain
+/// non-negative integer; the left-hand operand takes the fast native-`u64`
+/// path when it (and the shifted result, for `<<`) fits, falling back to
+/// `BigBits` once it doesn't.
+fn apply_shift(value: Decimal, shift_amount: Decimal, op: &str) -> Result<Decimal> {
+    let shift_amount = validate_integer_operand(shift_amount)?;
+    let shift: u32 = shift_amount
+        .try_into()
+        .map_err(|_| anyhow!("Shift amount is too large"))?;
+
+    if let Ok(value_u64) = validate_bitwise_operand(value) {
+        if op == ">>" {
+            if shift < 64 {
+                return Ok(Decimal::from(value_u64 >> shift));
+            }
+            return Ok(Decimal::ZERO);
+        }
+
+        if shift < 64 {
+            if let Some(shifted) = value_u64
+                .checked_shl(shift)
+                .filter(|_| value_u64.leading_zeros() >= shift)
+            {
+                return Ok(Decimal::from(shifted));
+            }
+        }
+
+        if value.is_sign_negative() {
+            // `value` is negative, so `validate_wide_integer_operand` below
+            // would reject it outright; widen from the two's-complement
+            // u64 pattern already computed above instead of re-deriving
+            // from the signed `Decimal`.
+            return BigBits::from_u64(value_u64).shl(shift).to_decimal();
+        }
+    }
+
+    let big = BigBits::from_decimal(validate_wide_integer_operand(value)?)?;
+    match op {
+        "<<" => big.shl(shift).to_decimal(),
+        ">>" => big.shr(shift).to_decimal(),
+        _ => unreachable!(),
+    }
+}
+
+/// Handles bitwise OR (lowest precedence of the bitwise operators)
+fn parse_bitwise_or(input: &str) -> IResult<&str, Value> {
+    let (input, init) = parse_bitwise_xor(input)?;
 
     let (input, ops) = nom::multi::many0(pair(
         delimited(multispace0, char('|'), multispace0),
+        parse_bitwise_xor,
+    ))(input)?;
+
+    let mut result = init;
+    for (_, val) in ops {
+        let (_, a) = require_decimal(result, input)?;
+        let (_, b) = require_decimal(val, input)?;
+        match apply_wide_bitwise(a, b, |a, b| a | b, BigBits::bitor) {
+            Ok(val) => result = Value::Decimal(val),
+            Err(_) => return fallback_or_fail(input, Value::Decimal(a)),
+        }
+    }
+
+    Ok((input, result))
+}
+
+/// Handles bitwise XOR (higher precedence than OR, lower than AND)
+fn parse_bitwise_xor(input: &str) -> IResult<&str, Value> {
+    let (input, init) = parse_bitwise_and(input)?;
+
+    let (input, ops) = nom::multi::many0(pair(
+        delimited(multispace0, char('^'), multispace0),
         parse_bitwise_and,
     ))(input)?;
 
     let mut result = init;
     for (_, val) in ops {
-        match validate_bitwise_operands(result, val) {
-            Ok((a, b)) => result = Decimal::from(a | b),
-            Err(_) => {
-                return Err(nom::Err::Failure(nom::error::Error::new(
-                    input,
-                    nom::error::ErrorKind::Verify,
-                )));
-            }
+        let (_, a) = require_decimal(result, input)?;
+        let (_, b) = require_decimal(val, input)?;
+        match apply_wide_bitwise(a, b, |a, b| a ^ b, BigBits::bitxor) {
+            Ok(val) => result = Value::Decimal(val),
+            Err(_) => return fallback_or_fail(input, Value::Decimal(a)),
         }
     }
 
     Ok((input, result))
 }
 
-/// Handles bitwise AND (higher precedence than OR, lower than addition)
-fn parse_bitwise_and(input: &str) -> IResult<&str, Decimal> {
-    let (input, init) = parse_additive(input)?;
+/// Handles bitwise AND (higher precedence than XOR, lower than shifts)
+fn parse_bitwise_and(input: &str) -> IResult<&str, Value> {
+    let (input, init) = parse_shift(input)?;
 
     let (input, ops) = nom::multi::many0(pair(
         delimited(multispace0, char('&'), multispace0),
-        parse_additive,
+        parse_shift,
     ))(input)?;
 
     let mut result = init;
     for (_, val) in ops {
-        match validate_bitwise_operands(result, val) {
-            Ok((a, b)) => result = Decimal::from(a & b),
-            Err(_) => {
-                return Err(nom::Err::Failure(nom::error::Error::new(
-                    input,
-                    nom::error::ErrorKind::Verify,
-                )));
-            }
+        let (_, a) = require_decimal(result, input)?;
+        let (_, b) = require_decimal(val, input)?;
+        match apply_wide_bitwise(a, b, |a, b| a & b, BigBits::bitand) {
+            Ok(val) => result = Value::Decimal(val),
+            Err(_) => return fallback_or_fail(input, Value::Decimal(a)),
+        }
+    }
+
+    Ok((input, result))
+}
+
+/// Handles left/right bit-shift (higher precedence than AND, lower than addition)
+fn parse_shift(input: &str) -> IResult<&str, Value> {
+    let (input, init) = parse_additive(input)?;
+
+    let (input, ops) = nom::multi::many0(pair(
+        delimited(multispace0, alt((tag("<<"), tag(">>"))), multispace0),
+        parse_additive,
+    ))(input)?;
+
+    let mut result = init;
+    for (op, val) in ops {
+        let (_, a) = require_decimal(result, input)?;
+        let (_, b) = require_decimal(val, input)?;
+        match apply_shift(a, b, op) {
+            Ok(val) => result = Value::Decimal(val),
+            Err(_) => return fallback_or_fail(input, Value::Decimal(a)),
         }
     }
 
@@ -135,7 +869,7 @@ fn parse_bitwise_and(input: &str) -> IResult<&str, Decimal> {
 }
 
 /// Handles addition and subtraction (medium precedence)
-fn parse_additive(input: &str) -> IResult<&str, Decimal> {
+fn parse_additive(input: &str) -> IResult<&str, Value> {
     let (input, init) = parse_multiplicative(input)?;
 
     let (input, ops) = nom::multi::many0(pair(
@@ -143,17 +877,22 @@ fn parse_additive(input: &str) -> IResult<&str, Decimal> {
         parse_multiplicative,
     ))(input)?;
 
-    let result = ops.into_iter().fold(init, |acc, (op, val)| match op {
-        '+' => acc + val,
-        '-' => acc - val,
-        _ => unreachable!(),
-    });
+    let mut result = init;
+    for (op, val) in ops {
+        let (_, a) = require_decimal(result, input)?;
+        let (_, b) = require_decimal(val, input)?;
+        result = Value::Decimal(match op {
+            '+' => a + b,
+            '-' => a - b,
+            _ => unreachable!(),
+        });
+    }
 
     Ok((input, result))
 }
 
 /// Handles multiplication, division, and modulo (medium precedence)
-fn parse_multiplicative(input: &str) -> IResult<&str, Decimal> {
+fn parse_multiplicative(input: &str) -> IResult<&str, Value> {
     let (input, init) = parse_power(input)?;
 
     let (input, ops) = nom::multi::many0(pair(
@@ -167,27 +906,26 @@ fn parse_multiplicative(input: &str) -> IResult<&str, Decimal> {
 
     let mut result = init;
     for (op, val) in ops {
-        match op {
-            '*' => result = result * val,
+        let (_, a) = require_decimal(result, input)?;
+        let (_, b) = require_decimal(val, input)?;
+        result = match op {
+            '*' => Value::Decimal(a * b),
             '/' => {
-                if val.is_zero() {
-                    return Err(nom::Err::Failure(nom::error::Error::new(
-                        input,
-                        nom::error::ErrorKind::Verify,
-                    )));
+                if b.is_zero() {
+                    return fallback_or_fail(input, Value::Decimal(a));
                 }
-                result = result / val;
+                Value::Decimal(a / b)
             }
-            '%' => result = result % val,
+            '%' => Value::Decimal(a % b),
             _ => unreachable!(),
-        }
+        };
     }
 
     Ok((input, result))
 }
 
 /// Handles exponentiation (high precedence, right-associative)
-fn parse_power(input: &str) -> IResult<&str, Decimal> {
+fn parse_power(input: &str) -> IResult<&str, Value> {
     let (input, base) = parse_unary(input)?;
 
     let (input, exponent) = opt(preceded(
@@ -196,78 +934,325 @@ fn parse_power(input: &str) -> IResult<&str, Decimal> {
     ))(input)?;
 
     match exponent {
-        Some(exp) => Ok((input, base.powd(exp))),
+        Some(exp) => {
+            let (input, base) = require_decimal(base, input)?;
+            let (input, exp) = require_decimal(exp, input)?;
+            Ok((input, Value::Decimal(base.powd(exp))))
+        }
         None => Ok((input, base)),
     }
 }
 
-/// Handles unary operators (+ and -)
-fn parse_unary(input: &str) -> IResult<&str, Decimal> {
+/// Handles unary operators (+, -, bitwise NOT, and logical NOT)
+fn parse_unary(input: &str) -> IResult<&str, Value> {
     alt((
-        map(preceded(char('-'), parse_unary), |val| -val),
-        map(preceded(char('+'), parse_unary), |val| val),
+        parse_negate,
+        parse_unary_plus,
+        parse_bitwise_not,
+        parse_logical_not,
         parse_primary,
     ))(input)
 }
 
-/// Handles primary expressions (numbers, functions, parentheses)
-fn parse_primary(input: &str) -> IResult<&str, Decimal> {
+/// Handles unary `-`
+fn parse_negate(input: &str) -> IResult<&str, Value> {
+    let (input, value) = preceded(char('-'), parse_unary)(input)?;
+    let (input, value) = require_decimal(value, input)?;
+    Ok((input, Value::Decimal(-value)))
+}
+
+/// Handles unary `+` (a no-op beyond requiring a numeric operand)
+fn parse_unary_plus(input: &str) -> IResult<&str, Value> {
+    let (input, value) = preceded(char('+'), parse_unary)(input)?;
+    let (input, value) = require_decimal(value, input)?;
+    Ok((input, Value::Decimal(value)))
+}
+
+/// Handles the unary bitwise-NOT operator (~), operating over the operand's
+/// own width (64-bit for values that fit, wider otherwise)
+fn parse_bitwise_not(input: &str) -> IResult<&str, Value> {
+    let (input, value) = preceded(char('~'), parse_unary)(input)?;
+    let (input, value) = require_decimal(value, input)?;
+
+    match apply_wide_bitwise_not(value) {
+        Ok(result) => Ok((input, Value::Decimal(result))),
+        Err(_) => fallback_or_fail(input, Value::Decimal(value)),
+    }
+}
+
+/// Handles the unary logical-NOT operator (!)
+fn parse_logical_not(input: &str) -> IResult<&str, Value> {
+    let (input, value) = preceded(char('!'), parse_unary)(input)?;
+    let (input, value) = require_boolean(value, input)?;
+    Ok((input, Value::Boolean(!value)))
+}
+
+/// Handles primary expressions (numbers, booleans, functions, variables,
+/// parentheses)
+fn parse_primary(input: &str) -> IResult<&str, Value> {
     delimited(
         multispace0,
         alt((
             parse_function,
-            parse_constant,
-            parse_number,
+            map(parse_boolean_literal, Value::Boolean),
+            parse_variable,
+            map(parse_number, Value::Decimal),
             delimited(char('('), parse_expression, char(')')),
         )),
         multispace0,
     )(input)
 }
 
+/// Parses the `true`/`false` literals, backing off (recoverably) for any
+/// other identifier so `alt` can fall through to `parse_variable`.
+fn parse_boolean_literal(input: &str) -> IResult<&str, bool> {
+    let (rest, name) = parse_identifier(input)?;
+    match name.as_str() {
+        "true" => Ok((rest, true)),
+        "false" => Ok((rest, false)),
+        _ => Err(nom::Err::Error(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Tag,
+        ))),
+    }
+}
+
 /// Parses mathematical functions with arguments
-fn parse_function(input: &str) -> IResult<&str, Decimal> {
+fn parse_function(input: &str) -> IResult<&str, Value> {
     let (input, name) = parse_identifier(input)?;
 
     let (input, _) = char('(')(input)?;
+
+    // round()'s optional third argument is a bare rounding-mode keyword
+    // rather than a numeric expression, so it can't share the generic
+    // argument list below.
+    if name == "round" {
+        let (input, result) = parse_round_args(input)?;
+        let (input, _) = char(')')(input)?;
+        return Ok((input, Value::Decimal(result)));
+    }
+
     let (input, args) = separated_list0(
         delimited(multispace0, char(','), multispace0),
-        parse_expression,
+        parse_numeric_expression,
     )(input)?;
     let (input, _) = char(')')(input)?;
 
     match apply_function(&name, args) {
-        Ok(result) => Ok((input, result)),
-        Err(_) => Err(nom::Err::Failure(nom::error::Error::new(
-            input,
-            nom::error::ErrorKind::Verify,
-        ))),
+        Ok(result) => Ok((input, Value::Decimal(result))),
+        Err(_) => fallback_or_fail(input, Value::Decimal(Decimal::ZERO)),
     }
 }
 
-/// Parses mathematical constants (pi, e)
-fn parse_constant(input: &str) -> IResult<&str, Decimal> {
-    alt((
-        map(tag_no_case("pi"), |_| {
-            Decimal::from_str("3.1415926535897932384626433832795").unwrap()
-        }),
-        map(tag_no_case("e"), |_| {
-            Decimal::from_str("2.7182818284590452353602874713527").unwrap()
-        }),
-    ))(input)
-}
-
-/// Parses numbers in various formats (decimal, hex, binary)
-fn parse_number(input: &str) -> IResult<&str, Decimal> {
-    alt((parse_hex_number, parse_binary_number, parse_decimal_number))(input)
-}
+/// Parses `round()`'s argument list: `round(x)`, `round(x, dp)`, or
+/// `round(x, dp, mode)` where `mode` is one of half_up/half_even/
+/// half_down/toward_zero rather than an expression.
+fn parse_round_args(input: &str) -> IResult<&str, Decimal> {
+    let (input, value) = parse_numeric_expression(input)?;
+
+    let (input, decimal_places) = opt(preceded(
+        delimited(multispace0, char(','), multispace0),
+        parse_numeric_expression,
+    ))(input)?;
+
+    let (input, mode) = if decimal_places.is_some() {
+        opt(preceded(
+            delimited(multispace0, char(','), multispace0),
+            parse_identifier,
+        ))(input)?
+    } else {
+        (input, None)
+    };
+
+    match apply_round(value, decimal_places, mode.as_deref()) {
+        Ok(result) => Ok((input, result)),
+        Err(_) => fallback_or_fail(input, value),
+    }
+}
+
+/// Rounds `value` to `decimal_places` (0 if unspecified) using `mode` if
+/// given, otherwise the `--rounding` default set for this evaluation.
+fn apply_round(
+    value: Decimal,
+    decimal_places: Option<Decimal>,
+    mode: Option<&str>,
+) -> Result<Decimal> {
+    let strategy = match mode {
+        Some(name) => parse_rounding_mode_name(name)?,
+        None => DEFAULT_ROUNDING_STRATEGY.with(|cell| *cell.borrow()),
+    };
+
+    let decimal_places = decimal_places
+        .map(|dp| dp.to_u32().unwrap_or(0))
+        .unwrap_or(0);
+
+    Ok(value.round_dp_with_strategy(decimal_places, strategy))
+}
+
+/// The constant pi, to the precision used throughout this module's series
+fn pi() -> Decimal {
+    Decimal::from_str("3.1415926535897932384626433832795").unwrap()
+}
+
+/// The constant e, to the precision used throughout this module's series
+fn e() -> Decimal {
+    Decimal::from_str("2.7182818284590452353602874713527").unwrap()
+}
+
+/// Stop a series once the next term's magnitude drops below this, matching
+/// the precision of the `pi`/`e` constants above
+fn series_tolerance() -> Decimal {
+    Decimal::new(1, 28)
+}
+
+/// 1/ln(10), precomputed so base-10 logs are a single multiply instead of a
+/// second series evaluation (mirrors rust_decimal's own LN10_INVERSE)
+fn ln10_inverse() -> Decimal {
+    Decimal::from_str("0.4342944819032518276511289189").unwrap()
+}
+
+/// Computes atan(x) via the Taylor series x - x^3/3 + x^5/5 - ..., which only
+/// converges for |x| <= 1, and converges far too slowly near |x| = 1 to hit
+/// `series_tolerance` in a sane number of terms. Halve the angle with
+/// atan(x) = 2*atan(x / (1 + sqrt(1+x^2))) until it's comfortably small,
+/// then undo the halving on the result, so the series itself only ever has
+/// to converge near zero.
+fn atan_series(x: Decimal) -> Decimal {
+    let tolerance = series_tolerance();
+
+    let mut reduced = x;
+    let mut halvings: u32 = 0;
+    while reduced.abs() > Decimal::new(25, 2) && halvings < 64 {
+        let denom = Decimal::ONE + (Decimal::ONE + reduced * reduced).sqrt().unwrap();
+        reduced /= denom;
+        halvings += 1;
+    }
+
+    let x2 = reduced * reduced;
+    let mut power = reduced;
+    let mut sum = Decimal::ZERO;
+    let mut k: u64 = 0;
+
+    loop {
+        let denom = Decimal::from(2 * k + 1);
+        let term = power / denom;
+        sum += if k % 2 == 0 { term } else { -term };
+
+        if term.abs() < tolerance || k > 200 {
+            break;
+        }
+
+        power *= x2;
+        k += 1;
+    }
+
+    sum * Decimal::from(2u64.pow(halvings))
+}
+
+/// Computes atan(x) for any real x, using the reciprocal identity to keep
+/// the underlying series convergent when |x| > 1
+fn atan_decimal(x: Decimal) -> Decimal {
+    if x.abs() <= Decimal::ONE {
+        atan_series(x)
+    } else {
+        let sign = if x.is_sign_negative() {
+            -Decimal::ONE
+        } else {
+            Decimal::ONE
+        };
+        sign * (pi() / Decimal::TWO) - atan_series(Decimal::ONE / x)
+    }
+}
+
+/// Computes atan2(y, x), the four-quadrant arctangent
+fn atan2_decimal(y: Decimal, x: Decimal) -> Decimal {
+    if x > Decimal::ZERO {
+        atan_decimal(y / x)
+    } else if x < Decimal::ZERO {
+        if y >= Decimal::ZERO {
+            atan_decimal(y / x) + pi()
+        } else {
+            atan_decimal(y / x) - pi()
+        }
+    } else if y > Decimal::ZERO {
+        pi() / Decimal::TWO
+    } else if y < Decimal::ZERO {
+        -(pi() / Decimal::TWO)
+    } else {
+        Decimal::ZERO
+    }
+}
+
+/// Computes asin(x) as atan(x / sqrt(1 - x^2)), valid for |x| <= 1
+fn asin_decimal(x: Decimal) -> Result<Decimal> {
+    if x.abs() > Decimal::ONE {
+        return Err(anyhow!("asin() argument must be between -1 and 1"));
+    }
+    if x == Decimal::ONE {
+        return Ok(pi() / Decimal::TWO);
+    }
+    if x == -Decimal::ONE {
+        return Ok(-(pi() / Decimal::TWO));
+    }
+
+    let denom = (Decimal::ONE - x * x)
+        .sqrt()
+        .ok_or_else(|| anyhow!("Invalid asin operation"))?;
+    Ok(atan_decimal(x / denom))
+}
+
+/// Computes acos(x) as pi/2 - asin(x), valid for |x| <= 1
+fn acos_decimal(x: Decimal) -> Result<Decimal> {
+    Ok(pi() / Decimal::TWO - asin_decimal(x)?)
+}
+
+/// Parses numbers in various formats (decimal, hex, octal, binary)
+fn parse_number(input: &str) -> IResult<&str, Decimal> {
+    alt((
+        parse_hex_number,
+        parse_octal_number,
+        parse_binary_number,
+        parse_decimal_number,
+    ))(input)
+}
+
+/// Parses a string of digits in the given radix into a `Decimal`, accepting
+/// values beyond `u64::MAX` (up to whatever `Decimal`'s own ~96-bit range
+/// can still hold) by accumulating digit-by-digit instead of going through
+/// `u64::from_str_radix`.
+fn decimal_from_radix_str(digits: &str, radix: u32) -> Result<Decimal> {
+    let radix_decimal = Decimal::from(radix);
+    let mut value = Decimal::ZERO;
+    for c in digits.chars() {
+        let digit = c
+            .to_digit(radix)
+            .ok_or_else(|| anyhow!("'{}' is not a valid base-{} digit", c, radix))?;
+        value = value * radix_decimal + Decimal::from(digit);
+    }
+    Ok(value)
+}
 
 /// Parses hexadecimal numbers (0x prefix)
 fn parse_hex_number(input: &str) -> IResult<&str, Decimal> {
     let (input, _) = tag_no_case("0x")(input)?;
     let (input, hex_str) = take_while1(|c: char| c.is_ascii_hexdigit())(input)?;
 
-    match u64::from_str_radix(hex_str, 16) {
-        Ok(value) => Ok((input, Decimal::from(value))),
+    match decimal_from_radix_str(hex_str, 16) {
+        Ok(value) => Ok((input, value)),
+        Err(_) => Err(nom::Err::Failure(nom::error::Error::new(
+            input,
+            nom::error::ErrorKind::Verify,
+        ))),
+    }
+}
+
+/// Parses octal numbers (0o prefix)
+fn parse_octal_number(input: &str) -> IResult<&str, Decimal> {
+    let (input, _) = tag_no_case("0o")(input)?;
+    let (input, oct_str) = take_while1(|c: char| ('0'..='7').contains(&c))(input)?;
+
+    match decimal_from_radix_str(oct_str, 8) {
+        Ok(value) => Ok((input, value)),
         Err(_) => Err(nom::Err::Failure(nom::error::Error::new(
             input,
             nom::error::ErrorKind::Verify,
@@ -280,8 +1265,8 @@ fn parse_binary_number(input: &str) -> IResult<&str, Decimal> {
     let (input, _) = tag_no_case("0b")(input)?;
     let (input, bin_str) = take_while1(|c: char| c == '0' || c == '1')(input)?;
 
-    match u64::from_str_radix(bin_str, 2) {
-        Ok(value) => Ok((input, Decimal::from(value))),
+    match decimal_from_radix_str(bin_str, 2) {
+        Ok(value) => Ok((input, value)),
         Err(_) => Err(nom::Err::Failure(nom::error::Error::new(
             input,
             nom::error::ErrorKind::Verify,
@@ -342,16 +1327,140 @@ fn apply_function(name: &str, args: Vec<Decimal>) -> Result<Decimal> {
             // Calculate tangent (input in radians)
             Ok(args[0].tan())
         }
-        "log" => {
+        "asin" => {
+            if args.len() != 1 {
+                return Err(anyhow!("asin() expects 1 argument"));
+            }
+            asin_decimal(args[0])
+        }
+        "acos" => {
+            if args.len() != 1 {
+                return Err(anyhow!("acos() expects 1 argument"));
+            }
+            acos_decimal(args[0])
+        }
+        "atan" => {
+            if args.len() != 1 {
+                return Err(anyhow!("atan() expects 1 argument"));
+            }
+            Ok(atan_decimal(args[0]))
+        }
+        "atan2" => {
+            if args.len() != 2 {
+                return Err(anyhow!("atan2() expects 2 arguments"));
+            }
+            Ok(atan2_decimal(args[0], args[1]))
+        }
+        "sinh" => {
+            if args.len() != 1 {
+                return Err(anyhow!("sinh() expects 1 argument"));
+            }
+            Ok((args[0].exp() - (-args[0]).exp()) / Decimal::TWO)
+        }
+        "cosh" => {
+            if args.len() != 1 {
+                return Err(anyhow!("cosh() expects 1 argument"));
+            }
+            Ok((args[0].exp() + (-args[0]).exp()) / Decimal::TWO)
+        }
+        "tanh" => {
+            if args.len() != 1 {
+                return Err(anyhow!("tanh() expects 1 argument"));
+            }
+            let x = args[0];
+            let positive = x.exp();
+            let negative = (-x).exp();
+            Ok((positive - negative) / (positive + negative))
+        }
+        "asinh" => {
+            if args.len() != 1 {
+                return Err(anyhow!("asinh() expects 1 argument"));
+            }
+            let x = args[0];
+            let inner = (x * x + Decimal::ONE)
+                .sqrt()
+                .ok_or_else(|| anyhow!("Invalid asinh operation"))?;
+            Ok((x + inner).ln())
+        }
+        "acosh" => {
+            if args.len() != 1 {
+                return Err(anyhow!("acosh() expects 1 argument"));
+            }
+            let x = args[0];
+            if x < Decimal::ONE {
+                return Err(anyhow!("acosh() argument must be >= 1"));
+            }
+            let inner = (x * x - Decimal::ONE)
+                .sqrt()
+                .ok_or_else(|| anyhow!("Invalid acosh operation"))?;
+            Ok((x + inner).ln())
+        }
+        "atanh" => {
+            if args.len() != 1 {
+                return Err(anyhow!("atanh() expects 1 argument"));
+            }
+            let x = args[0];
+            if x.abs() >= Decimal::ONE {
+                return Err(anyhow!("atanh() argument must be between -1 and 1"));
+            }
+            Ok(((Decimal::ONE + x) / (Decimal::ONE - x)).ln() / Decimal::TWO)
+        }
+        "deg_to_rad" => {
             if args.len() != 1 {
-                return Err(anyhow!("log() expects 1 argument"));
+                return Err(anyhow!("deg_to_rad() expects 1 argument"));
+            }
+            Ok(args[0] * pi() / Decimal::from(180))
+        }
+        "rad_to_deg" => {
+            if args.len() != 1 {
+                return Err(anyhow!("rad_to_deg() expects 1 argument"));
+            }
+            Ok(args[0] * Decimal::from(180) / pi())
+        }
+        "ln" => {
+            if args.len() != 1 {
+                return Err(anyhow!("ln() expects 1 argument"));
             }
             if args[0] <= Decimal::ZERO {
-                return Err(anyhow!("log() argument must be positive"));
+                return Err(anyhow!("ln() argument must be positive"));
             }
             // Calculate natural logarithm (base e)
             Ok(args[0].ln())
         }
+        "log2" => {
+            if args.len() != 1 {
+                return Err(anyhow!("log2() expects 1 argument"));
+            }
+            if args[0] <= Decimal::ZERO {
+                return Err(anyhow!("log2() argument must be positive"));
+            }
+            Ok(args[0].ln() / Decimal::TWO.ln())
+        }
+        "log" => match args.len() {
+            1 => {
+                if args[0] <= Decimal::ZERO {
+                    return Err(anyhow!("log() argument must be positive"));
+                }
+                // Base-10 logarithm, the conventional meaning of a bare `log()`.
+                // Multiply by the precomputed 1/ln(10) instead of dividing by a
+                // second series evaluation of ln(10), to avoid extra rounding drift.
+                Ok(args[0].ln() * ln10_inverse())
+            }
+            2 => {
+                let (value, base) = (args[0], args[1]);
+                if value <= Decimal::ZERO {
+                    return Err(anyhow!("log() argument must be positive"));
+                }
+                if base <= Decimal::ZERO {
+                    return Err(anyhow!("log() base must be positive"));
+                }
+                if base == Decimal::ONE {
+                    return Err(anyhow!("log() base cannot be 1"));
+                }
+                Ok(value.ln() / base.ln())
+            }
+            _ => Err(anyhow!("log() expects 1 or 2 arguments")),
+        },
         "exp" => {
             if args.len() != 1 {
                 return Err(anyhow!("exp() expects 1 argument"));
@@ -392,48 +1501,101 @@ fn apply_function(name: &str, args: Vec<Decimal>) -> Result<Decimal> {
             // Round up to the nearest integer
             Ok(args[0].ceil())
         }
-        "round" => match args.len() {
-            1 => {
-                // Round to nearest integer
-                Ok(args[0].round())
-            }
-            2 => {
-                // Round to specified number of decimal places
-                let decimal_places = args[1].to_u32().unwrap_or(0);
-                Ok(args[0].round_dp(decimal_places))
+        "base" => {
+            if args.len() != 2 {
+                return Err(anyhow!("base() expects 2 arguments"));
             }
-            _ => Err(anyhow!("round() expects 1 or 2 arguments")),
-        },
+            let value = validate_integer_operand(args[0])?;
+            let radix = args[1]
+                .to_u32()
+                .filter(|radix| (2..=36).contains(radix))
+                .ok_or_else(|| anyhow!("base() radix must be between 2 and 36"))?;
+
+            let rendered = to_radix_string(value, radix);
+            LAST_CUSTOM_BASE.with(|cell| *cell.borrow_mut() = Some((radix, rendered)));
+
+            // Numerically a no-op so `base(0xFF, 7) + 1` keeps composing.
+            Ok(args[0])
+        }
         _ => Err(anyhow!("Unknown function: {}", name)),
     }
 }
 
-/// Formats a decimal value as a binary string
-/// Returns None for non-integer, negative, or values too large for u64
-fn format_binary(value: Decimal) -> Option<String> {
-    // Only format integers that fit in u64 range
-    if value.fract() != Decimal::ZERO || value.is_sign_negative() || value > Decimal::from(u64::MAX)
-    {
-        None
-    } else {
+/// The usual 0-9 then a-z digit alphabet, shared by every radix formatter
+/// (native `u64` and the `BigBits` wide path alike).
+const RADIX_DIGITS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+/// Formats a non-negative integer in the given radix (2-36) using the usual
+/// 0-9 then a-z digit alphabet
+fn to_radix_string(mut value: u64, radix: u32) -> String {
+    if value == 0 {
+        return "0".to_string();
+    }
+
+    let mut digits = Vec::new();
+    while value > 0 {
+        digits.push(RADIX_DIGITS[(value % radix as u64) as usize]);
+        value /= radix as u64;
+    }
+    digits.reverse();
+
+    String::from_utf8(digits).unwrap()
+}
+
+/// Formats a non-negative integer `Decimal` in the given radix, prefixed
+/// with `prefix`. Takes the fast u64 path when the value fits, falling back
+/// to `BigBits` for the wider values the bitwise operators can now produce.
+/// Returns None for non-integer or negative values.
+fn format_radix(value: Decimal, radix: u32, prefix: &str) -> Option<String> {
+    if value.fract() != Decimal::ZERO || value.is_sign_negative() {
+        return None;
+    }
+
+    if value <= Decimal::from(u64::MAX) {
         let int_val = value.to_u64().unwrap_or(0);
-        Some(format!("0b{:b}", int_val))
+        Some(format!("{}{}", prefix, to_radix_string(int_val, radix)))
+    } else {
+        BigBits::from_decimal(value)
+            .ok()
+            .map(|big| format!("{}{}", prefix, big.to_radix_string(radix)))
     }
 }
 
-/// Formats a decimal value as a hexadecimal string
-/// Returns None for non-integer, negative, or values too large for u64
-fn format_hex(value: Decimal) -> Option<String> {
-    // Only format integers that fit in u64 range
-    if value.fract() != Decimal::ZERO || value.is_sign_negative() || value > Decimal::from(u64::MAX)
-    {
-        None
+/// Renders the final result in an arbitrary radix (2-36) for `--to-base`,
+/// taking the fast u64 path when the value fits and falling back to
+/// `BigBits` for the wider values the bitwise operators can produce
+/// (same strategy as `format_radix`, but without a fixed 2/8/16 prefix).
+fn render_custom_base(value: Decimal, radix: u32) -> Result<String> {
+    if !(2..=36).contains(&radix) {
+        return Err(anyhow!("--to-base radix must be between 2 and 36"));
+    }
+
+    let value = validate_wide_integer_operand(value)?;
+    if value <= Decimal::from(u64::MAX) {
+        Ok(to_radix_string(value.to_u64().unwrap(), radix))
     } else {
-        let int_val = value.to_u64().unwrap_or(0);
-        Some(format!("0x{:x}", int_val))
+        Ok(BigBits::from_decimal(value)?.to_radix_string(radix))
     }
 }
 
+/// Formats a decimal value as a binary string
+/// Returns None for non-integer, negative, or values too large to represent
+fn format_binary(value: Decimal) -> Option<String> {
+    format_radix(value, 2, "0b")
+}
+
+/// Formats a decimal value as a hexadecimal string
+/// Returns None for non-integer, negative, or values too large to represent
+fn format_hex(value: Decimal) -> Option<String> {
+    format_radix(value, 16, "0x")
+}
+
+/// Formats a decimal value as an octal string
+/// Returns None for non-integer, negative, or values too large to represent
+fn format_octal(value: Decimal) -> Option<String> {
+    format_radix(value, 8, "0o")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -442,6 +1604,10 @@ mod tests {
     fn test_addition() {
         let tool = CalcTool {
             expression: "2 + 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -457,6 +1623,10 @@ mod tests {
     fn test_subtraction() {
         let tool = CalcTool {
             expression: "10 - 7".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -472,6 +1642,10 @@ mod tests {
     fn test_multiplication() {
         let tool = CalcTool {
             expression: "4 * 5".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -487,6 +1661,10 @@ mod tests {
     fn test_division() {
         let tool = CalcTool {
             expression: "20 / 4".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -502,6 +1680,10 @@ mod tests {
     fn test_float_division() {
         let tool = CalcTool {
             expression: "7 / 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -517,6 +1699,10 @@ mod tests {
     fn test_modulo() {
         let tool = CalcTool {
             expression: "10 % 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -532,6 +1718,10 @@ mod tests {
     fn test_exponentiation() {
         let tool = CalcTool {
             expression: "2 ^ 8".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -546,567 +1736,1883 @@ mod tests {
     #[test]
     fn test_complex_expression() {
         let tool = CalcTool {
-            expression: "(2 + 3) * 4 - 6 / 2".to_string(),
+            expression: "(2 + 3) * 4 - 6 / 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "17");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x11");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b10001");
+    }
+
+    #[test]
+    fn test_nested_parentheses() {
+        let tool = CalcTool {
+            expression: "((10 + 5) * 2) / 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "10");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xa");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1010");
+    }
+
+    #[test]
+    fn test_negative_numbers() {
+        let tool = CalcTool {
+            expression: "-5 + 10".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "5");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x5");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b101");
+    }
+
+    #[test]
+    fn test_decimal_numbers() {
+        let tool = CalcTool {
+            expression: "3.14 * 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "6.28");
+        assert!(val["hex"].is_null());
+        assert!(val["binary"].is_null());
+    }
+
+    #[test]
+    fn test_hex_input() {
+        let tool = CalcTool {
+            expression: "0xFF + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "256");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x100");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b100000000");
+    }
+
+    #[test]
+    fn test_binary_input() {
+        let tool = CalcTool {
+            expression: "0b1010 + 0b0101".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "15");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+    }
+
+    #[test]
+    fn test_hex_output() {
+        let tool = CalcTool {
+            expression: "255".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "255");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xff");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b11111111");
+    }
+
+    #[test]
+    fn test_binary_output() {
+        let tool = CalcTool {
+            expression: "15".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "15");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+    }
+
+    #[test]
+    fn test_sqrt_function() {
+        let tool = CalcTool {
+            expression: "sqrt(16)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert!(val["decimal"].as_str().unwrap().starts_with("4"));
+        assert_eq!(val["hex"].as_str().unwrap(), "0x4");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b100");
+    }
+
+    #[test]
+    fn test_abs_function() {
+        let tool = CalcTool {
+            expression: "abs(-42)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "42");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x2a");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b101010");
+    }
+
+    #[test]
+    fn test_floor_function() {
+        let tool = CalcTool {
+            expression: "floor(3.7)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "3");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x3");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b11");
+    }
+
+    #[test]
+    fn test_ceil_function() {
+        let tool = CalcTool {
+            expression: "ceil(3.2)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "4");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x4");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b100");
+    }
+
+    #[test]
+    fn test_round_function() {
+        let tool = CalcTool {
+            expression: "round(3.6)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "4");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x4");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b100");
+    }
+
+    #[test]
+    fn test_round_explicit_half_up_mode() {
+        let tool = CalcTool {
+            expression: "round(2.5, 0, half_up)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "3");
+    }
+
+    #[test]
+    fn test_round_uses_default_rounding_flag() {
+        let tool = CalcTool {
+            expression: "round(2.5)".to_string(),
+            rounding: RoundingMode::HalfUp,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "3");
+    }
+
+    #[test]
+    fn test_round_rejects_unknown_mode() {
+        let tool = CalcTool {
+            expression: "round(2.5, 0, sideways)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_precision_flag_rounds_final_result() {
+        let tool = CalcTool {
+            expression: "1 / 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: Some(4),
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "0.3333");
+    }
+
+    #[test]
+    fn test_precision_flag_uses_rounding_strategy() {
+        let tool = CalcTool {
+            expression: "2.5".to_string(),
+            rounding: RoundingMode::HalfUp,
+            var: vec![],
+            precision: Some(0),
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "3");
+    }
+
+    #[test]
+    fn test_pi_constant() {
+        let tool = CalcTool {
+            expression: "pi * 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let decimal_val = val["decimal"].as_str().unwrap();
+        assert!(decimal_val.starts_with("6.28318"));
+        assert!(val["hex"].is_null());
+        assert!(val["binary"].is_null());
+    }
+
+    #[test]
+    fn test_e_constant() {
+        let tool = CalcTool {
+            expression: "e".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let decimal_val = val["decimal"].as_str().unwrap();
+        assert!(decimal_val.starts_with("2.71828"));
+        assert!(val["hex"].is_null());
+        assert!(val["binary"].is_null());
+    }
+
+    #[test]
+    fn test_tau_constant() {
+        let tool = CalcTool {
+            expression: "tau".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert!(val["decimal"].as_str().unwrap().starts_with("6.28318"));
+    }
+
+    #[test]
+    fn test_variable_assignment_and_reuse() {
+        let tool = CalcTool {
+            expression: "x = 5; y = x * 2; y + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "11");
+    }
+
+    #[test]
+    fn test_var_flag_seeds_environment() {
+        let tool = CalcTool {
+            expression: "x + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec!["x=41".to_string()],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "42");
+    }
+
+    #[test]
+    fn test_assignment_can_override_predefined_constant() {
+        let tool = CalcTool {
+            expression: "pi = 3; pi".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "3");
+    }
+
+    #[test]
+    fn test_unknown_identifier_is_an_error() {
+        let tool = CalcTool {
+            expression: "not_bound + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_invalid_expression() {
+        let tool = CalcTool {
+            expression: "2 + * 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        let tool = CalcTool {
+            expression: "5 / 0".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_sqrt_negative() {
+        let tool = CalcTool {
+            expression: "sqrt(-1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bitwise_and() {
+        let tool = CalcTool {
+            expression: "12 & 10".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 12 = 0b1100, 10 = 0b1010, 12 & 10 = 0b1000 = 8
+        assert_eq!(val["decimal"].as_str().unwrap(), "8");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x8");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1000");
+    }
+
+    #[test]
+    fn test_bitwise_or() {
+        let tool = CalcTool {
+            expression: "12 | 10".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 12 = 0b1100, 10 = 0b1010, 12 | 10 = 0b1110 = 14
+        assert_eq!(val["decimal"].as_str().unwrap(), "14");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xe");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1110");
+    }
+
+    #[test]
+    fn test_bitwise_with_hex() {
+        let tool = CalcTool {
+            expression: "0xFF & 0x0F".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "15");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+    }
+
+    #[test]
+    fn test_bitwise_with_binary() {
+        let tool = CalcTool {
+            expression: "0b1111 | 0b1000".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "15");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+    }
+
+    #[test]
+    fn test_bitwise_precedence() {
+        let tool = CalcTool {
+            expression: "8 | 4 & 12".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // & has higher precedence than |
+        // 4 & 12 = 4, then 8 | 4 = 12
+        assert_eq!(val["decimal"].as_str().unwrap(), "12");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xc");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1100");
+    }
+
+    #[test]
+    fn test_bitwise_with_parentheses() {
+        let tool = CalcTool {
+            expression: "(8 | 4) & 12".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // (8 | 4) = 12, then 12 & 12 = 12
+        assert_eq!(val["decimal"].as_str().unwrap(), "12");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xc");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1100");
+    }
+
+    #[test]
+    fn test_bitwise_and_float_error() {
+        let tool = CalcTool {
+            expression: "3.5 & 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bitwise_or_float_error() {
+        let tool = CalcTool {
+            expression: "4 | 2.5".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute();
+
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_bitwise_and_negative_uses_twos_complement() {
+        // -5 as a 64-bit two's-complement pattern is 0xFFFFFFFFFFFFFFFB
+        let tool = CalcTool {
+            expression: "-5 & 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "3");
+    }
+
+    #[test]
+    fn test_bitwise_or_negative_uses_twos_complement() {
+        let tool = CalcTool {
+            expression: "5 | -3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "18446744073709551613");
+    }
+
+    #[test]
+    fn test_bitwise_not_negative_uses_twos_complement() {
+        // ~(-1) = 0 since -1's bit pattern is all ones
+        let tool = CalcTool {
+            expression: "~(-1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "0");
+    }
+
+    #[test]
+    fn test_right_shift_of_negative_value() {
+        let tool = CalcTool {
+            expression: "-1 >> 4".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "1152921504606846975");
+    }
+
+    #[test]
+    fn test_left_shift_of_negative_value() {
+        let tool = CalcTool {
+            expression: "-1 << 4".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // -1's 64-bit two's complement pattern is all ones, shifted left 4
+        // bits rather than rejected as a negative wide-integer operand.
+        assert_eq!(val["decimal"].as_str().unwrap(), "295147905179352825840");
+    }
+
+    #[test]
+    fn test_bitwise_not_rejects_magnitude_beyond_64_bit_range() {
+        let tool = CalcTool {
+            expression: "~(-9223372036854775809)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_bitwise_complex_expression() {
+        let tool = CalcTool {
+            expression: "(0xFF & 0x0F) | (0x10 & 0x10)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // (255 & 15) | (16 & 16) = 15 | 16 = 31
+        assert_eq!(val["decimal"].as_str().unwrap(), "31");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x1f");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b11111");
+    }
+
+    #[test]
+    fn test_arithmetic_with_bitwise_and() {
+        let tool = CalcTool {
+            expression: "10 + 5 & 12".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 10 + 5 = 15, then 15 & 12 = 12
+        assert_eq!(val["decimal"].as_str().unwrap(), "12");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xc");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1100");
+    }
+
+    #[test]
+    fn test_arithmetic_with_bitwise_or() {
+        let tool = CalcTool {
+            expression: "8 - 4 | 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 8 - 4 = 4, then 4 | 2 = 6
+        assert_eq!(val["decimal"].as_str().unwrap(), "6");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x6");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b110");
+    }
+
+    #[test]
+    fn test_multiplication_with_bitwise() {
+        let tool = CalcTool {
+            expression: "2 * 4 & 7".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 2 * 4 = 8, then 8 & 7 = 0
+        assert_eq!(val["decimal"].as_str().unwrap(), "0");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x0");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b0");
+    }
+
+    #[test]
+    fn test_division_with_bitwise() {
+        let tool = CalcTool {
+            expression: "16 / 2 | 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 16 / 2 = 8, then 8 | 3 = 11
+        assert_eq!(val["decimal"].as_str().unwrap(), "11");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xb");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1011");
+    }
+
+    #[test]
+    fn test_bitwise_with_parentheses_arithmetic() {
+        let tool = CalcTool {
+            expression: "(10 | 5) + 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // (10 | 5) = 15, then 15 + 2 = 17
+        assert_eq!(val["decimal"].as_str().unwrap(), "17");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x11");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b10001");
+    }
+
+    #[test]
+    fn test_mixed_bitwise_arithmetic() {
+        let tool = CalcTool {
+            expression: "3 + 4 & 5 | 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 3 + 4 = 7, 7 & 5 = 5, 5 | 2 = 7
+        assert_eq!(val["decimal"].as_str().unwrap(), "7");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x7");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b111");
+    }
+
+    #[test]
+    fn test_bitwise_or_chain() {
+        let tool = CalcTool {
+            expression: "1 | 2 | 4 | 8".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 1 | 2 | 4 | 8 = 15
+        assert_eq!(val["decimal"].as_str().unwrap(), "15");
+        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+    }
+
+    #[test]
+    fn test_bitwise_and_chain() {
+        let tool = CalcTool {
+            expression: "255 & 127 & 63".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 255 & 127 & 63 = 63
+        assert_eq!(val["decimal"].as_str().unwrap(), "63");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x3f");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b111111");
+    }
+
+    #[test]
+    fn test_bitwise_zero_operands() {
+        let tool = CalcTool {
+            expression: "0 & 255".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "0");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x0");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b0");
+    }
+
+    #[test]
+    fn test_bitwise_with_modulo() {
+        let tool = CalcTool {
+            expression: "17 % 5 & 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 17 % 5 = 2, 2 & 3 = 2
+        assert_eq!(val["decimal"].as_str().unwrap(), "2");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x2");
+        assert_eq!(val["binary"].as_str().unwrap(), "0b10");
+    }
+
+    #[test]
+    fn test_octal_output() {
+        let tool = CalcTool {
+            expression: "8".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["octal"].as_str().unwrap(), "0o10");
+    }
+
+    #[test]
+    fn test_octal_input() {
+        let tool = CalcTool {
+            expression: "0o17 + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 0o17 = 15, + 1 = 16
+        assert_eq!(val["decimal"].as_str().unwrap(), "16");
+        assert_eq!(val["octal"].as_str().unwrap(), "0o20");
+    }
+
+    #[test]
+    fn test_base_function_converts_to_arbitrary_radix() {
+        let tool = CalcTool {
+            expression: "base(0xFF, 7)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "255");
+        assert_eq!(val["base"]["radix"], 7);
+        assert_eq!(val["base"]["value"].as_str().unwrap(), "513");
+    }
+
+    #[test]
+    fn test_base_function_uses_letters_above_base_10() {
+        let tool = CalcTool {
+            expression: "base(255, 16)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["base"]["value"].as_str().unwrap(), "ff");
+    }
+
+    #[test]
+    fn test_base_function_is_numerically_composable() {
+        let tool = CalcTool {
+            expression: "base(0xFF, 7) + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "256");
+    }
+
+    #[test]
+    fn test_base_function_rejects_out_of_range_radix() {
+        let tool = CalcTool {
+            expression: "base(10, 1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_base_function_rejects_non_integer_value() {
+        let tool = CalcTool {
+            expression: "base(10.5, 8)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_to_base_flag_renders_custom_field() {
+        let tool = CalcTool {
+            expression: "255".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: Some(16),
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["custom"].as_str().unwrap(), "ff");
+    }
+
+    #[test]
+    fn test_to_base_flag_handles_values_wider_than_u64() {
+        let tool = CalcTool {
+            expression: "2 ^ 100".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: Some(16),
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(
+            val["custom"].as_str().unwrap(),
+            BigBits::from_decimal(Decimal::from(2u64).powd(Decimal::from(100u64)))
+                .unwrap()
+                .to_radix_string(16)
+        );
+    }
+
+    #[test]
+    fn test_to_base_flag_rejects_out_of_range_radix() {
+        let tool = CalcTool {
+            expression: "255".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: Some(1),
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_expression_without_base_has_no_base_field() {
+        let tool = CalcTool {
+            expression: "2 + 2".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert!(val.get("base").is_none());
+    }
+
+    #[test]
+    fn test_atan_matches_known_value() {
+        let tool = CalcTool {
+            expression: "atan(1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // atan(1) = pi/4 = 0.785398...
+        assert!(val["decimal"].as_str().unwrap().starts_with("0.785398"));
+    }
+
+    #[test]
+    fn test_atan_beyond_unit_circle_uses_reciprocal_identity() {
+        let tool = CalcTool {
+            expression: "atan(10)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "17");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x11");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b10001");
+        // atan(10) = 1.4711276743...
+        assert!(val["decimal"].as_str().unwrap().starts_with("1.47112"));
     }
 
     #[test]
-    fn test_nested_parentheses() {
+    fn test_atan2_quadrants() {
         let tool = CalcTool {
-            expression: "((10 + 5) * 2) / 3".to_string(),
+            expression: "atan2(1, 1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "10");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xa");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1010");
+        assert!(val["decimal"].as_str().unwrap().starts_with("0.785398"));
     }
 
     #[test]
-    fn test_negative_numbers() {
+    fn test_asin_of_one_is_half_pi() {
         let tool = CalcTool {
-            expression: "-5 + 10".to_string(),
+            expression: "asin(1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "5");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x5");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b101");
+        assert!(val["decimal"].as_str().unwrap().starts_with("1.5707963"));
     }
 
     #[test]
-    fn test_decimal_numbers() {
+    fn test_asin_out_of_domain_errors() {
         let tool = CalcTool {
-            expression: "3.14 * 2".to_string(),
+            expression: "asin(2)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_acos_of_zero_is_half_pi() {
+        let tool = CalcTool {
+            expression: "acos(0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "6.28");
-        assert!(val["hex"].is_null());
-        assert!(val["binary"].is_null());
+        assert!(val["decimal"].as_str().unwrap().starts_with("1.5707963"));
     }
 
     #[test]
-    fn test_hex_input() {
+    fn test_sinh_of_zero_is_zero() {
         let tool = CalcTool {
-            expression: "0xFF + 1".to_string(),
+            expression: "sinh(0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "256");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x100");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b100000000");
+        assert_eq!(val["decimal"].as_str().unwrap(), "0");
     }
 
     #[test]
-    fn test_binary_input() {
+    fn test_cosh_of_zero_is_one() {
         let tool = CalcTool {
-            expression: "0b1010 + 0b0101".to_string(),
+            expression: "cosh(0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "15");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+        assert_eq!(val["decimal"].as_str().unwrap(), "1");
     }
 
     #[test]
-    fn test_hex_output() {
+    fn test_tanh_of_zero_is_zero() {
         let tool = CalcTool {
-            expression: "255".to_string(),
+            expression: "tanh(0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "255");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xff");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b11111111");
+        assert_eq!(val["decimal"].as_str().unwrap(), "0");
     }
 
     #[test]
-    fn test_binary_output() {
+    fn test_asinh_cosh_atanh_round_trip() {
         let tool = CalcTool {
-            expression: "15".to_string(),
+            expression: "atanh(0.5)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "15");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+        // atanh(0.5) = 0.5493061443...
+        assert!(val["decimal"].as_str().unwrap().starts_with("0.549306"));
     }
 
     #[test]
-    fn test_sqrt_function() {
+    fn test_atanh_out_of_domain_errors() {
         let tool = CalcTool {
-            expression: "sqrt(16)".to_string(),
+            expression: "atanh(1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute().unwrap().unwrap();
+        assert!(tool.execute().is_err());
+    }
 
-        let Output::JsonValue(val) = result else {
-            unreachable!()
+    #[test]
+    fn test_acosh_out_of_domain_errors() {
+        let tool = CalcTool {
+            expression: "acosh(0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        assert!(val["decimal"].as_str().unwrap().starts_with("4"));
-        assert_eq!(val["hex"].as_str().unwrap(), "0x4");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b100");
+        assert!(tool.execute().is_err());
     }
 
     #[test]
-    fn test_abs_function() {
+    fn test_deg_to_rad_and_back() {
         let tool = CalcTool {
-            expression: "abs(-42)".to_string(),
+            expression: "deg_to_rad(180)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "42");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x2a");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b101010");
+        assert!(val["decimal"].as_str().unwrap().starts_with("3.14159"));
     }
 
     #[test]
-    fn test_floor_function() {
+    fn test_rad_to_deg() {
         let tool = CalcTool {
-            expression: "floor(3.7)".to_string(),
+            expression: "rad_to_deg(pi)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "3");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x3");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b11");
+        assert_eq!(val["decimal"].as_str().unwrap(), "180");
     }
 
     #[test]
-    fn test_ceil_function() {
+    fn test_ln_of_e_is_one() {
         let tool = CalcTool {
-            expression: "ceil(3.2)".to_string(),
+            expression: "ln(e)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "4");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x4");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b100");
+        assert!(val["decimal"].as_str().unwrap().starts_with("1"));
     }
 
     #[test]
-    fn test_round_function() {
+    fn test_log_is_base_10() {
         let tool = CalcTool {
-            expression: "round(3.6)".to_string(),
+            expression: "log(100)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "4");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x4");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b100");
+        assert!(val["decimal"].as_str().unwrap().starts_with("2"));
     }
 
     #[test]
-    fn test_pi_constant() {
+    fn test_log2_of_eight_is_three() {
         let tool = CalcTool {
-            expression: "pi * 2".to_string(),
+            expression: "log2(8)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        let decimal_val = val["decimal"].as_str().unwrap();
-        assert!(decimal_val.starts_with("6.28318"));
-        assert!(val["hex"].is_null());
-        assert!(val["binary"].is_null());
+        assert!(val["decimal"].as_str().unwrap().starts_with("3"));
     }
 
     #[test]
-    fn test_e_constant() {
+    fn test_log_with_explicit_base() {
         let tool = CalcTool {
-            expression: "e".to_string(),
+            expression: "log(8, 2)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        let decimal_val = val["decimal"].as_str().unwrap();
-        assert!(decimal_val.starts_with("2.71828"));
-        assert!(val["hex"].is_null());
-        assert!(val["binary"].is_null());
+        assert!(val["decimal"].as_str().unwrap().starts_with("3"));
     }
 
     #[test]
-    fn test_invalid_expression() {
+    fn test_log_rejects_non_positive_argument() {
         let tool = CalcTool {
-            expression: "2 + * 3".to_string(),
+            expression: "log(0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
-
-        assert!(result.is_err());
+        assert!(tool.execute().is_err());
     }
 
     #[test]
-    fn test_division_by_zero() {
+    fn test_log_rejects_base_of_one() {
         let tool = CalcTool {
-            expression: "5 / 0".to_string(),
+            expression: "log(10, 1)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
-
-        assert!(result.is_err());
+        assert!(tool.execute().is_err());
     }
 
     #[test]
-    fn test_sqrt_negative() {
+    fn test_left_shift() {
         let tool = CalcTool {
-            expression: "sqrt(-1)".to_string(),
+            expression: "1 << 8".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
+        let result = tool.execute().unwrap().unwrap();
 
-        assert!(result.is_err());
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "256");
     }
 
     #[test]
-    fn test_bitwise_and() {
+    fn test_right_shift() {
         let tool = CalcTool {
-            expression: "12 & 10".to_string(),
+            expression: "256 >> 4".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 12 = 0b1100, 10 = 0b1010, 12 & 10 = 0b1000 = 8
-        assert_eq!(val["decimal"].as_str().unwrap(), "8");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x8");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1000");
+        assert_eq!(val["decimal"].as_str().unwrap(), "16");
     }
 
     #[test]
-    fn test_bitwise_or() {
+    fn test_left_shift_beyond_64_bits() {
         let tool = CalcTool {
-            expression: "12 | 10".to_string(),
+            expression: "1 << 64".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 12 = 0b1100, 10 = 0b1010, 12 | 10 = 0b1110 = 14
-        assert_eq!(val["decimal"].as_str().unwrap(), "14");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xe");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1110");
+        assert_eq!(val["decimal"].as_str().unwrap(), "18446744073709551616");
     }
 
     #[test]
-    fn test_bitwise_with_hex() {
+    fn test_left_shift_overflowing_64_bits_falls_back_to_big_bits() {
         let tool = CalcTool {
-            expression: "0xFF & 0x0F".to_string(),
+            expression: "1152921504606846976 << 10".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "15");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+        // 2^60 << 10 == 2^70, which doesn't fit in a u64.
+        assert_eq!(val["decimal"].as_str().unwrap(), "1180591620717411303424");
     }
 
     #[test]
-    fn test_bitwise_with_binary() {
+    fn test_right_shift_of_wide_value() {
         let tool = CalcTool {
-            expression: "0b1111 | 0b1000".to_string(),
+            expression: "(1 << 70) >> 66".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "15");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+        assert_eq!(val["decimal"].as_str().unwrap(), "16");
     }
 
     #[test]
-    fn test_bitwise_precedence() {
+    fn test_shift_errors_once_result_exceeds_decimal_range() {
         let tool = CalcTool {
-            expression: "8 | 4 & 12".to_string(),
+            expression: "1 << 200".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_bitwise_and_beyond_64_bits() {
+        let tool = CalcTool {
+            expression: "(1 << 64) & (1 << 64)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // & has higher precedence than |
-        // 4 & 12 = 4, then 8 | 4 = 12
-        assert_eq!(val["decimal"].as_str().unwrap(), "12");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xc");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1100");
+        assert_eq!(val["decimal"].as_str().unwrap(), "18446744073709551616");
     }
 
     #[test]
-    fn test_bitwise_with_parentheses() {
+    fn test_bitwise_or_beyond_64_bits() {
         let tool = CalcTool {
-            expression: "(8 | 4) & 12".to_string(),
+            expression: "(1 << 64) | 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // (8 | 4) = 12, then 12 & 12 = 12
-        assert_eq!(val["decimal"].as_str().unwrap(), "12");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xc");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1100");
+        assert_eq!(val["decimal"].as_str().unwrap(), "18446744073709551617");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x10000000000000001");
     }
 
     #[test]
-    fn test_bitwise_and_float_error() {
+    fn test_bitwise_not_of_wide_value_beyond_decimal_range_errors() {
+        // ~ inverts over the operand's own width; once that width is wider
+        // than 64 bits the inverted value can exceed what Decimal can still
+        // represent, which is a real (if unfortunate) error rather than
+        // silent truncation
         let tool = CalcTool {
-            expression: "3.5 & 2".to_string(),
+            expression: "~(1 << 64)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
-
-        assert!(result.is_err());
+        assert!(tool.execute().is_err());
     }
 
     #[test]
-    fn test_bitwise_or_float_error() {
+    fn test_shift_has_lower_precedence_than_addition() {
         let tool = CalcTool {
-            expression: "4 | 2.5".to_string(),
+            expression: "1 << 4 + 4".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
+        let result = tool.execute().unwrap().unwrap();
 
-        assert!(result.is_err());
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 4 + 4 = 8, then 1 << 8 = 256
+        assert_eq!(val["decimal"].as_str().unwrap(), "256");
     }
 
     #[test]
-    fn test_bitwise_and_negative_error() {
+    fn test_bitwise_xor() {
         let tool = CalcTool {
-            expression: "-5 & 3".to_string(),
+            expression: "12 ^ 10".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
+        let result = tool.execute().unwrap().unwrap();
 
-        assert!(result.is_err());
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        // 12 = 0b1100, 10 = 0b1010, 12 ^ 10 = 0b0110 = 6
+        assert_eq!(val["decimal"].as_str().unwrap(), "6");
     }
 
     #[test]
-    fn test_bitwise_or_negative_error() {
+    fn test_shift_xor_combined_expression() {
         let tool = CalcTool {
-            expression: "5 | -3".to_string(),
+            expression: "(1 << 8) ^ 0xFF".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute();
+        let result = tool.execute().unwrap().unwrap();
 
-        assert!(result.is_err());
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["decimal"].as_str().unwrap(), "511");
+        assert_eq!(val["hex"].as_str().unwrap(), "0x1ff");
     }
 
     #[test]
-    fn test_bitwise_complex_expression() {
+    fn test_bitwise_not() {
         let tool = CalcTool {
-            expression: "(0xFF & 0x0F) | (0x10 & 0x10)".to_string(),
+            expression: "~0".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // (255 & 15) | (16 & 16) = 15 | 16 = 31
-        assert_eq!(val["decimal"].as_str().unwrap(), "31");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x1f");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b11111");
+        assert_eq!(val["decimal"].as_str().unwrap(), u64::MAX.to_string());
     }
 
     #[test]
-    fn test_arithmetic_with_bitwise_and() {
+    fn test_comparison_yields_boolean_field() {
         let tool = CalcTool {
-            expression: "10 + 5 & 12".to_string(),
+            expression: "5 > 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 10 + 5 = 15, then 15 & 12 = 12
-        assert_eq!(val["decimal"].as_str().unwrap(), "12");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xc");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1100");
+        assert_eq!(val["boolean"].as_bool().unwrap(), true);
+        assert!(val["decimal"].is_null());
+        assert!(val["hex"].is_null());
+        assert!(val["binary"].is_null());
+        assert!(val["octal"].is_null());
     }
 
     #[test]
-    fn test_arithmetic_with_bitwise_or() {
+    fn test_comparison_operators() {
+        for (expr, expected) in [
+            ("5 > 3", true),
+            ("5 < 3", false),
+            ("3 <= 3", true),
+            ("3 >= 4", false),
+            ("5 == 5", true),
+            ("5 != 5", false),
+        ] {
+            let tool = CalcTool {
+                expression: expr.to_string(),
+                rounding: RoundingMode::HalfEven,
+                var: vec![],
+                precision: None,
+                to_base: None,
+            };
+            let result = tool.execute().unwrap().unwrap();
+
+            let Output::JsonValue(val) = result else {
+                unreachable!()
+            };
+            assert_eq!(val["boolean"].as_bool().unwrap(), expected, "{expr}");
+        }
+    }
+
+    #[test]
+    fn test_bitmask_predicate_expression() {
         let tool = CalcTool {
-            expression: "8 - 4 | 2".to_string(),
+            expression: "(6 & 0x4) != 0".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 8 - 4 = 4, then 4 | 2 = 6
-        assert_eq!(val["decimal"].as_str().unwrap(), "6");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x6");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b110");
+        assert_eq!(val["boolean"].as_bool().unwrap(), true);
     }
 
     #[test]
-    fn test_multiplication_with_bitwise() {
+    fn test_logical_and_or_precedence_and_short_circuit() {
         let tool = CalcTool {
-            expression: "2 * 4 & 7".to_string(),
+            expression: "5 > 3 && 2 < 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 2 * 4 = 8, then 8 & 7 = 0
-        assert_eq!(val["decimal"].as_str().unwrap(), "0");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x0");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b0");
+        assert_eq!(val["boolean"].as_bool().unwrap(), false);
     }
 
     #[test]
-    fn test_division_with_bitwise() {
+    fn test_logical_and_short_circuits_past_division_by_zero() {
         let tool = CalcTool {
-            expression: "16 / 2 | 3".to_string(),
+            expression: "false && (1 / 0 > 0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 16 / 2 = 8, then 8 | 3 = 11
-        assert_eq!(val["decimal"].as_str().unwrap(), "11");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xb");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1011");
+        assert_eq!(val["boolean"].as_bool().unwrap(), false);
     }
 
     #[test]
-    fn test_bitwise_with_parentheses_arithmetic() {
+    fn test_logical_or_short_circuits_past_division_by_zero() {
         let tool = CalcTool {
-            expression: "(10 | 5) + 2".to_string(),
+            expression: "true || (1 / 0 > 0)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // (10 | 5) = 15, then 15 + 2 = 17
-        assert_eq!(val["decimal"].as_str().unwrap(), "17");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x11");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b10001");
+        assert_eq!(val["boolean"].as_bool().unwrap(), true);
     }
 
     #[test]
-    fn test_mixed_bitwise_arithmetic() {
+    fn test_logical_not() {
         let tool = CalcTool {
-            expression: "3 + 4 & 5 | 2".to_string(),
+            expression: "!(5 > 3)".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 3 + 4 = 7, 7 & 5 = 5, 5 | 2 = 7
-        assert_eq!(val["decimal"].as_str().unwrap(), "7");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x7");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b111");
+        assert_eq!(val["boolean"].as_bool().unwrap(), false);
     }
 
     #[test]
-    fn test_bitwise_or_chain() {
+    fn test_boolean_literals() {
         let tool = CalcTool {
-            expression: "1 | 2 | 4 | 8".to_string(),
+            expression: "true && !false".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 1 | 2 | 4 | 8 = 15
-        assert_eq!(val["decimal"].as_str().unwrap(), "15");
-        assert_eq!(val["hex"].as_str().unwrap(), "0xf");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b1111");
+        assert_eq!(val["boolean"].as_bool().unwrap(), true);
     }
 
     #[test]
-    fn test_bitwise_and_chain() {
+    fn test_boolean_variable_assignment_and_reuse() {
         let tool = CalcTool {
-            expression: "255 & 127 & 63".to_string(),
+            expression: "flag = 5 > 3; flag && true".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 255 & 127 & 63 = 63
-        assert_eq!(val["decimal"].as_str().unwrap(), "63");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x3f");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b111111");
+        assert_eq!(val["boolean"].as_bool().unwrap(), true);
     }
 
     #[test]
-    fn test_bitwise_zero_operands() {
+    fn test_comparing_decimal_to_boolean_is_an_error() {
         let tool = CalcTool {
-            expression: "0 & 255".to_string(),
+            expression: "5 == true".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
-        let result = tool.execute().unwrap().unwrap();
+        assert!(tool.execute().is_err());
+    }
 
-        let Output::JsonValue(val) = result else {
-            unreachable!()
+    #[test]
+    fn test_precision_requires_numeric_result() {
+        let tool = CalcTool {
+            expression: "5 > 3".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: Some(2),
+            to_base: None,
         };
-        assert_eq!(val["decimal"].as_str().unwrap(), "0");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x0");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b0");
+        assert!(tool.execute().is_err());
     }
 
     #[test]
-    fn test_bitwise_with_modulo() {
+    fn test_numeric_expression_still_works_after_boolean_support() {
         let tool = CalcTool {
-            expression: "17 % 5 & 3".to_string(),
+            expression: "x = 5; x + 1".to_string(),
+            rounding: RoundingMode::HalfEven,
+            var: vec![],
+            precision: None,
+            to_base: None,
         };
         let result = tool.execute().unwrap().unwrap();
 
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        // 17 % 5 = 2, 2 & 3 = 2
-        assert_eq!(val["decimal"].as_str().unwrap(), "2");
-        assert_eq!(val["hex"].as_str().unwrap(), "0x2");
-        assert_eq!(val["binary"].as_str().unwrap(), "0b10");
+        assert_eq!(val["decimal"].as_str().unwrap(), "6");
     }
 }
@@ -1,6 +1,6 @@
 use crate::args::StringInput;
 use crate::tool::{Output, Tool};
-use clap::{Command, CommandFactory, Parser, Subcommand};
+use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(name = "case", about = "Convert text between different case formats")]
@@ -51,6 +51,171 @@ enum CaseCommand {
         /// Text to convert (use "-" for stdin)
         text: StringInput,
     },
+    /// Convert text to an arbitrary case via a word-pattern + delimiter pair
+    Convert {
+        /// Text to convert (use "-" for stdin)
+        text: StringInput,
+        /// Source case. Accepted for symmetry with --to; segmentation
+        /// already works regardless of the input's case, so this has no
+        /// effect on the result
+        #[arg(long, value_enum)]
+        from: Option<Case>,
+        /// Target case
+        #[arg(long, value_enum)]
+        to: Case,
+        /// Override the target case's delimiter (empty, "_", "-", " ", ".", ...)
+        #[arg(long)]
+        delimiter: Option<String>,
+        /// Override the target case's word pattern
+        #[arg(long, value_enum)]
+        pattern: Option<Pattern>,
+    },
+    /// Report which well-known case(s) an input is consistent with
+    Detect {
+        /// Text to check (use "-" for stdin)
+        text: StringInput,
+    },
+}
+
+// A named, well-known case, expressed as a (Pattern, delimiter) pair so the
+// `Convert` subcommand and the presets below share one rendering engine.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+enum Case {
+    Camel,
+    Pascal,
+    Snake,
+    Kebab,
+    #[value(name = "shouty-snake")]
+    ShoutySnake,
+    #[value(name = "shouty-kebab")]
+    ShoutyKebab,
+    Train,
+    Dot,
+    Lower,
+    Upper,
+    Title,
+    Sentence,
+    Alternating,
+    Toggle,
+}
+
+impl Case {
+    fn pattern_and_delimiter(self) -> (Pattern, &'static str) {
+        match self {
+            Case::Camel => (Pattern::Camel, ""),
+            Case::Pascal => (Pattern::Capitalized, ""),
+            Case::Snake => (Pattern::Lower, "_"),
+            Case::Kebab => (Pattern::Lower, "-"),
+            Case::ShoutySnake => (Pattern::Upper, "_"),
+            Case::ShoutyKebab => (Pattern::Upper, "-"),
+            Case::Train => (Pattern::Capitalized, "-"),
+            Case::Dot => (Pattern::Lower, "."),
+            Case::Lower => (Pattern::Lower, " "),
+            Case::Upper => (Pattern::Upper, " "),
+            Case::Title => (Pattern::Capitalized, " "),
+            Case::Sentence => (Pattern::Sentence, " "),
+            Case::Alternating => (Pattern::Alternating, " "),
+            Case::Toggle => (Pattern::Toggle, " "),
+        }
+    }
+
+    // Human-readable name used in `case detect` output, matching how each
+    // case is conventionally written rather than the CLI's kebab-case value.
+    fn label(self) -> &'static str {
+        match self {
+            Case::Camel => "camelCase",
+            Case::Pascal => "PascalCase",
+            Case::Snake => "snake_case",
+            Case::Kebab => "kebab-case",
+            Case::ShoutySnake => "SCREAMING_SNAKE",
+            Case::ShoutyKebab => "SCREAMING-KEBAB",
+            Case::Train => "Train-Case",
+            Case::Dot => "dot.case",
+            Case::Lower => "lower case",
+            Case::Upper => "UPPER CASE",
+            Case::Title => "Title Case",
+            Case::Sentence => "Sentence case",
+            Case::Alternating => "aLtErNaTiNg cAsE",
+            Case::Toggle => "TOGGLE case",
+        }
+    }
+}
+
+// How each individual word is cased. `Camel`/`Sentence` are index-aware (the
+// first word is treated differently from the rest); `Alternating`/`Toggle`
+// apply per-character across the whole joined output rather than per word,
+// so they're handled separately in `render`.
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq)]
+enum Pattern {
+    Lower,
+    Upper,
+    Capitalized,
+    Camel,
+    Sentence,
+    Alternating,
+    Toggle,
+}
+
+fn apply_pattern(pattern: Pattern, index: usize, word: &str) -> String {
+    match pattern {
+        Pattern::Lower => word.to_lowercase(),
+        Pattern::Upper => word.to_uppercase(),
+        Pattern::Capitalized => capitalize_first(word),
+        Pattern::Camel => {
+            if index == 0 {
+                word.to_lowercase()
+            } else {
+                capitalize_first(word)
+            }
+        }
+        Pattern::Sentence => {
+            if index == 0 {
+                capitalize_first(word)
+            } else {
+                word.to_lowercase()
+            }
+        }
+        Pattern::Alternating | Pattern::Toggle => unreachable!("handled in render"),
+    }
+}
+
+// Renders already-segmented words with a pattern and delimiter. Alternating
+// and toggle case ignore word boundaries entirely and flip per character
+// (skipping non-alphabetic chars) across the delimiter-joined string, which
+// is how "aLtErNaTiNg" / "ToGgLe" style text actually reads.
+fn render(words: &[String], pattern: Pattern, delimiter: &str) -> String {
+    if words.is_empty() {
+        return String::new();
+    }
+
+    match pattern {
+        Pattern::Alternating | Pattern::Toggle => {
+            let mut upper = matches!(pattern, Pattern::Toggle);
+            words
+                .join(delimiter)
+                .chars()
+                .map(|c| {
+                    if c.is_alphabetic() {
+                        let out: String = if upper {
+                            c.to_uppercase().collect()
+                        } else {
+                            c.to_lowercase().collect()
+                        };
+                        upper = !upper;
+                        out
+                    } else {
+                        c.to_string()
+                    }
+                })
+                .collect()
+        }
+        _ => words
+            .iter()
+            .enumerate()
+            .map(|(i, word)| apply_pattern(pattern, i, word))
+            .collect::<Vec<_>>()
+            .join(delimiter),
+    }
 }
 
 impl Tool for CaseTool {
@@ -68,6 +233,23 @@ impl Tool for CaseTool {
             CaseCommand::Header { text } => to_header_case(text.as_ref()),
             CaseCommand::Sentence { text } => to_sentence_case(text.as_ref()),
             CaseCommand::Snake { text } => to_snake_case(text.as_ref()),
+            CaseCommand::Convert {
+                text,
+                to,
+                delimiter,
+                pattern,
+                ..
+            } => {
+                let (default_pattern, default_delimiter) = to.pattern_and_delimiter();
+                let pattern = pattern.unwrap_or(default_pattern);
+                let delimiter = delimiter.as_deref().unwrap_or(default_delimiter);
+                render(&split_words(text.as_ref(), true), pattern, delimiter)
+            }
+            CaseCommand::Detect { text } => {
+                return Ok(Some(Output::JsonValue(serde_json::json!(detect_cases(
+                    text.as_ref()
+                )))));
+            }
         };
 
         Ok(Some(Output::JsonValue(serde_json::json!(result))))
@@ -86,106 +268,113 @@ fn to_uppercase(text: &str) -> String {
 
 // camelCase
 fn to_camel_case(text: &str) -> String {
-    let words = split_words(text);
-    if words.is_empty() {
-        return String::new();
-    }
-
-    let mut result = words[0].to_lowercase();
-    for word in &words[1..] {
-        if !word.is_empty() {
-            result.push_str(&capitalize_first(word));
-        }
-    }
-    result
+    render(&split_words(text, true), Pattern::Camel, "")
 }
 
 // Title Case
 fn to_title_case(text: &str) -> String {
-    split_words(text)
-        .iter()
-        .map(|word| capitalize_first(word))
-        .collect::<Vec<_>>()
-        .join(" ")
+    render(&split_words(text, true), Pattern::Capitalized, " ")
 }
 
 // CONSTANT_CASE
 fn to_constant_case(text: &str) -> String {
-    split_words(text)
-        .iter()
-        .map(|word| word.to_uppercase())
-        .collect::<Vec<_>>()
-        .join("_")
+    render(&split_words(text, true), Pattern::Upper, "_")
 }
 
 // header-case
 fn to_header_case(text: &str) -> String {
-    split_words(text)
-        .iter()
-        .map(|word| capitalize_first(word))
-        .collect::<Vec<_>>()
-        .join("-")
+    render(&split_words(text, true), Pattern::Capitalized, "-")
 }
 
 // Sentence case
 fn to_sentence_case(text: &str) -> String {
-    let words = split_words(text);
-    if words.is_empty() {
-        return String::new();
-    }
-
-    let mut result = capitalize_first(&words[0]);
-    for word in &words[1..] {
-        if !word.is_empty() {
-            result.push(' ');
-            result.push_str(&word.to_lowercase());
-        }
-    }
-    result
+    render(&split_words(text, true), Pattern::Sentence, " ")
 }
 
 // snake_case
 fn to_snake_case(text: &str) -> String {
-    split_words(text)
-        .iter()
-        .map(|word| word.to_lowercase())
-        .collect::<Vec<_>>()
-        .join("_")
+    render(&split_words(text, true), Pattern::Lower, "_")
 }
 
-// Splits a string into a sequence of words based on the whitespace, hyphens,
-// underscore and casing boundaries.
-fn split_words(text: &str) -> Vec<String> {
-    let mut chars = text.chars().peekable();
+// Reports every named case that losslessly round-trips `text`: segment it
+// once, then re-render with each case's (pattern, delimiter) pair and keep
+// the ones that reproduce the input exactly. A single word like "single"
+// naturally matches several cases since there's no delimiter to tell them
+// apart.
+fn detect_cases(text: &str) -> Vec<&'static str> {
+    let words = split_words(text, true);
+    if words.is_empty() {
+        return Vec::new();
+    }
+
+    Case::value_variants()
+        .iter()
+        .filter(|case| {
+            let (pattern, delimiter) = case.pattern_and_delimiter();
+            render(&words, pattern, delimiter) == text
+        })
+        .map(|case| case.label())
+        .collect()
+}
 
+// Splits a string into a sequence of words, based on explicit delimiters
+// (whitespace, `_`, `-`, `.`) and casing boundaries. `split_digits` controls
+// whether a letter/digit transition (`Foo2` -> `Foo`, `2`) also counts as a
+// boundary; case conversions that don't care about digits can disable it.
+//
+// Boundaries are detected by looking at each adjacent pair of chars (with
+// one char of lookahead for the acronym case) rather than a single-peek
+// state machine, so runs of uppercase letters and digits are both handled:
+//   - lower/digit followed by upper: `aB` -> `a|B`
+//   - a run of >= 2 uppercase letters followed by upper-then-lower joins the
+//     trailing capital to the next word: `HTTPRequest` -> `HTTP|Request`
+//   - a letter adjacent to a digit on either side (toggleable): `Foo2` ->
+//     `Foo|2`, `2Bar` -> `2|Bar`
+fn split_words(text: &str, split_digits: bool) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
     let mut words = Vec::new();
-    let mut current_word = String::new();
+    let mut current = String::new();
 
-    while let Some(ch) = chars.next() {
-        // Split on explicit separators (space, underscore, hyphen)
+    for (i, &ch) in chars.iter().enumerate() {
         if ch.is_whitespace() || ch == '_' || ch == '-' || ch == '.' {
-            if !current_word.is_empty() {
-                words.push(current_word.clone());
-                current_word.clear();
+            if !current.is_empty() {
+                words.push(std::mem::take(&mut current));
             }
-        // Split on camelCase boundaries (uppercase followed by lowercase)
-        // Example: "XMLParser" -> ["XML", "Parser"]
-        } else if ch.is_uppercase() && !current_word.is_empty() {
-            // Check if this uppercase letter starts a new word
-            // (uppercase followed by lowercase indicates word boundary)
-            if chars.peek().map_or(false, |&next| next.is_lowercase()) {
-                words.push(current_word.clone());
-                current_word.clear();
+            continue;
+        }
+
+        if i > 0 {
+            let prev = chars[i - 1];
+
+            let lower_or_digit_then_upper =
+                (prev.is_lowercase() || prev.is_numeric()) && ch.is_uppercase();
+
+            // Requires a run of at least two uppercase letters before `ch`
+            // so that acronyms like "XML" stay together while the trailing
+            // capital that actually starts the next word (`Parser`) splits
+            // off: "XMLParser" -> boundary before the "P".
+            let acronym_then_word = i >= 2
+                && chars[i - 2].is_uppercase()
+                && prev.is_uppercase()
+                && ch.is_uppercase()
+                && chars.get(i + 1).map_or(false, |next| next.is_lowercase());
+
+            let letter_digit_transition = split_digits
+                && ((prev.is_alphabetic() && ch.is_numeric())
+                    || (prev.is_numeric() && ch.is_alphabetic()));
+
+            if (lower_or_digit_then_upper || acronym_then_word || letter_digit_transition)
+                && !current.is_empty()
+            {
+                words.push(std::mem::take(&mut current));
             }
-            current_word.push(ch);
-        } else {
-            current_word.push(ch);
         }
+
+        current.push(ch);
     }
 
-    // Add the final word if it exists
-    if !current_word.is_empty() {
-        words.push(current_word);
+    if !current.is_empty() {
+        words.push(current);
     }
 
     words
@@ -275,14 +464,45 @@ mod tests {
 
     #[test]
     fn test_split_words() {
-        assert_eq!(split_words("hello world"), vec!["hello", "world"]);
-        assert_eq!(split_words("helloWorld"), vec!["hello", "World"]);
-        assert_eq!(split_words("hello_world"), vec!["hello", "world"]);
-        assert_eq!(split_words("hello-world"), vec!["hello", "world"]);
-        assert_eq!(split_words("HTTPSConnection"), vec!["HTTPS", "Connection"]);
-        assert_eq!(split_words("XMLParser"), vec!["XML", "Parser"]);
-        assert_eq!(split_words("single"), vec!["single"]);
-        assert_eq!(split_words(""), Vec::<String>::new());
+        assert_eq!(split_words("hello world", true), vec!["hello", "world"]);
+        assert_eq!(split_words("helloWorld", true), vec!["hello", "World"]);
+        assert_eq!(split_words("hello_world", true), vec!["hello", "world"]);
+        assert_eq!(split_words("hello-world", true), vec!["hello", "world"]);
+        assert_eq!(
+            split_words("HTTPSConnection", true),
+            vec!["HTTPS", "Connection"]
+        );
+        assert_eq!(split_words("XMLParser", true), vec!["XML", "Parser"]);
+        assert_eq!(split_words("single", true), vec!["single"]);
+        assert_eq!(split_words("", true), Vec::<String>::new());
+    }
+
+    #[test]
+    fn test_split_words_is_digit_sensitive() {
+        assert_eq!(split_words("Foo2", true), vec!["Foo", "2"]);
+        assert_eq!(split_words("2Bar", true), vec!["2", "Bar"]);
+        assert_eq!(split_words("v1_2_3", true), vec!["v", "1", "2", "3"]);
+        assert_eq!(
+            split_words("parseHTTP2Request", true),
+            vec!["parse", "HTTP", "2", "Request"]
+        );
+        assert_eq!(
+            split_words("IPv4Address", true),
+            vec!["IPv", "4", "Address"]
+        );
+    }
+
+    #[test]
+    fn test_split_words_digit_splitting_is_toggleable() {
+        // Lowercase-after-digit only splits via the (toggleable) letter/digit
+        // rule, not the always-on lower/digit-then-upper rule.
+        assert_eq!(split_words("Foo2bar", false), vec!["Foo2bar"]);
+        assert_eq!(split_words("Foo2bar", true), vec!["Foo", "2", "bar"]);
+    }
+
+    #[test]
+    fn test_split_words_unicode_letters() {
+        assert_eq!(split_words("café_auLait", true), vec!["café", "au", "Lait"]);
     }
 
     #[test]
@@ -292,4 +512,110 @@ mod tests {
         assert_eq!(capitalize_first("h"), "H");
         assert_eq!(capitalize_first(""), "");
     }
+
+    #[test]
+    fn test_named_case_presets() {
+        let words = split_words("hello world", true);
+        let preset = |case: Case| {
+            let (pattern, delimiter) = case.pattern_and_delimiter();
+            render(&words, pattern, delimiter)
+        };
+        assert_eq!(preset(Case::Pascal), "HelloWorld");
+        assert_eq!(preset(Case::ShoutySnake), "HELLO_WORLD");
+        assert_eq!(preset(Case::ShoutyKebab), "HELLO-WORLD");
+        assert_eq!(preset(Case::Train), "Hello-World");
+        assert_eq!(preset(Case::Dot), "hello.world");
+    }
+
+    #[test]
+    fn test_alternating_and_toggle_ignore_word_boundaries() {
+        let words = split_words("hello world", true);
+        assert_eq!(render(&words, Pattern::Alternating, " "), "hElLo WoRlD");
+        assert_eq!(render(&words, Pattern::Toggle, " "), "HeLlO wOrLd");
+    }
+
+    #[test]
+    fn test_convert_with_pattern_and_delimiter_overrides() {
+        let words = split_words("Hello World", true);
+        // A named preset's delimiter, overridden.
+        assert_eq!(render(&words, Pattern::Upper, "+"), "HELLO+WORLD");
+        // A named preset's pattern, overridden.
+        assert_eq!(render(&words, Pattern::Lower, "_"), "hello_world");
+    }
+
+    #[test]
+    fn test_convert_tool_executes_with_named_case() {
+        let tool = CaseTool {
+            command: CaseCommand::Convert {
+                text: StringInput("hello world".to_string()),
+                from: None,
+                to: Case::ShoutySnake,
+                delimiter: None,
+                pattern: None,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap(), "HELLO_WORLD");
+    }
+
+    #[test]
+    fn test_detect_distinguishes_each_named_case() {
+        assert_eq!(detect_cases("camelCase"), vec!["camelCase"]);
+        assert_eq!(detect_cases("PascalCase"), vec!["PascalCase"]);
+        assert_eq!(detect_cases("snake_case"), vec!["snake_case"]);
+        assert_eq!(detect_cases("kebab-case"), vec!["kebab-case"]);
+        assert_eq!(detect_cases("SCREAMING_SNAKE"), vec!["SCREAMING_SNAKE"]);
+        assert_eq!(detect_cases("Title Case"), vec!["Title Case"]);
+        assert_eq!(detect_cases("Sentence case"), vec!["Sentence case"]);
+    }
+
+    #[test]
+    fn test_detect_single_word_matches_several_cases() {
+        let matches = detect_cases("single");
+        assert!(matches.contains(&"camelCase"));
+        assert!(matches.contains(&"snake_case"));
+        assert!(matches.contains(&"kebab-case"));
+        assert!(!matches.contains(&"PascalCase"));
+        assert!(!matches.contains(&"SCREAMING_SNAKE"));
+    }
+
+    #[test]
+    fn test_detect_empty_input_matches_nothing() {
+        assert_eq!(detect_cases(""), Vec::<&str>::new());
+    }
+
+    #[test]
+    fn test_convert_tool_honors_overrides() {
+        let tool = CaseTool {
+            command: CaseCommand::Convert {
+                text: StringInput("hello world".to_string()),
+                from: None,
+                to: Case::Snake,
+                delimiter: Some("~".to_string()),
+                pattern: Some(Pattern::Upper),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap(), "HELLO~WORLD");
+    }
+
+    #[test]
+    fn test_detect_tool_executes() {
+        let tool = CaseTool {
+            command: CaseCommand::Detect {
+                text: StringInput("snake_case".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val, serde_json::json!(["snake_case"]));
+    }
 }
@@ -0,0 +1,277 @@
+use crate::args::StringInput;
+use crate::tool::{Output, Tool};
+use anyhow::{Context, Result, bail};
+use base64::{Engine as _, engine::general_purpose};
+use chacha20poly1305::aead::{Aead, KeyInit};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use clap::{Command, CommandFactory, Parser, Subcommand};
+use rand::{RngCore, rngs::OsRng};
+use sha2::{Digest, Sha256};
+
+const KEY_LEN: usize = 32;
+const NONCE_LEN: usize = 12;
+
+#[derive(Parser, Debug)]
+#[command(
+    name = "cipher",
+    about = "Authenticated symmetric encryption (ChaCha20-Poly1305)"
+)]
+pub struct CipherTool {
+    #[command(subcommand)]
+    command: CipherCommand,
+}
+
+#[derive(Subcommand, Debug)]
+enum CipherCommand {
+    /// Encrypt plaintext, emitting base64(nonce || ciphertext || tag)
+    Encrypt {
+        /// Plaintext to encrypt
+        text: StringInput,
+
+        /// Key as 64 hex chars, base64, or a passphrase (stretched via SHA-256)
+        #[arg(short, long)]
+        key: String,
+
+        /// Nonce as 24 hex chars or base64 (12 bytes). Random when omitted
+        #[arg(short, long)]
+        nonce: Option<String>,
+    },
+    /// Decrypt base64(nonce || ciphertext || tag), emitting the raw plaintext
+    Decrypt {
+        /// Base64-encoded nonce || ciphertext || tag
+        text: StringInput,
+
+        /// Key as 64 hex chars, base64, or a passphrase (stretched via SHA-256)
+        #[arg(short, long)]
+        key: String,
+    },
+}
+
+impl Tool for CipherTool {
+    fn cli() -> Command {
+        CipherTool::command()
+    }
+
+    fn execute(&self) -> Result<Option<Output>> {
+        match &self.command {
+            CipherCommand::Encrypt { text, key, nonce } => {
+                let key = parse_key(key)?;
+                let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+
+                let nonce_bytes = match nonce {
+                    Some(nonce) => parse_nonce(nonce)?,
+                    None => {
+                        let mut bytes = [0u8; NONCE_LEN];
+                        OsRng.fill_bytes(&mut bytes);
+                        bytes
+                    }
+                };
+
+                let ciphertext = cipher
+                    .encrypt(Nonce::from_slice(&nonce_bytes), text.as_ref().as_bytes())
+                    .map_err(|_| anyhow::anyhow!("Could not encrypt the given plaintext"))?;
+
+                let mut output = Vec::with_capacity(NONCE_LEN + ciphertext.len());
+                output.extend_from_slice(&nonce_bytes);
+                output.extend_from_slice(&ciphertext);
+
+                Ok(Some(Output::JsonValue(serde_json::json!(
+                    general_purpose::STANDARD.encode(output)
+                ))))
+            }
+            CipherCommand::Decrypt { text, key } => {
+                let key = parse_key(key)?;
+                let cipher = ChaCha20Poly1305::new(Key::from_slice(&key));
+
+                let raw = general_purpose::STANDARD
+                    .decode(text.as_ref())
+                    .context("Could not decode base64 input")?;
+
+                if raw.len() < NONCE_LEN {
+                    bail!("Input is too short to contain a nonce");
+                }
+
+                let (nonce_bytes, ciphertext) = raw.split_at(NONCE_LEN);
+
+                let plaintext = cipher
+                    .decrypt(Nonce::from_slice(nonce_bytes), ciphertext)
+                    .map_err(|_| anyhow::anyhow!("Decryption failed: data tampered or wrong key"))?;
+
+                Ok(Some(Output::Bytes(plaintext)))
+            }
+        }
+    }
+}
+
+// Accepts a key as 64 hex chars, standard base64 (32 bytes decoded), or
+// falls back to treating it as a passphrase stretched to 32 bytes via
+// SHA-256. This mirrors how the hash tool already hex/base64-encodes
+// digests, just in reverse.
+fn parse_key(raw: &str) -> Result<[u8; KEY_LEN]> {
+    if let Some(bytes) = decode_hex(raw) {
+        if bytes.len() == KEY_LEN {
+            let mut key = [0u8; KEY_LEN];
+            key.copy_from_slice(&bytes);
+            return Ok(key);
+        }
+        bail!("Hex key must be exactly 64 hex chars (32 bytes)");
+    }
+
+    if let Ok(bytes) = general_purpose::STANDARD.decode(raw) {
+        if bytes.len() == KEY_LEN {
+            let mut key = [0u8; KEY_LEN];
+            key.copy_from_slice(&bytes);
+            return Ok(key);
+        }
+    }
+
+    let mut hasher = Sha256::new();
+    hasher.update(raw.as_bytes());
+    Ok(hasher.finalize().into())
+}
+
+fn parse_nonce(raw: &str) -> Result<[u8; NONCE_LEN]> {
+    if let Some(bytes) = decode_hex(raw) {
+        if bytes.len() == NONCE_LEN {
+            let mut nonce = [0u8; NONCE_LEN];
+            nonce.copy_from_slice(&bytes);
+            return Ok(nonce);
+        }
+        bail!("Hex nonce must be exactly 24 hex chars (12 bytes)");
+    }
+
+    let bytes = general_purpose::STANDARD
+        .decode(raw)
+        .context("Nonce must be hex or base64")?;
+
+    if bytes.len() != NONCE_LEN {
+        bail!("Nonce must decode to exactly 12 bytes");
+    }
+
+    let mut nonce = [0u8; NONCE_LEN];
+    nonce.copy_from_slice(&bytes);
+    Ok(nonce)
+}
+
+fn decode_hex(s: &str) -> Option<Vec<u8>> {
+    if s.len() % 2 != 0 || !s.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    (0..s.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&s[i..i + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip_hex_key() {
+        let key = "00".repeat(KEY_LEN);
+
+        let encrypt_tool = CipherTool {
+            command: CipherCommand::Encrypt {
+                text: StringInput("hello world".to_string()),
+                key: key.clone(),
+                nonce: None,
+            },
+        };
+        let encrypted = encrypt_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encrypted else {
+            panic!("Expected JsonValue output");
+        };
+
+        let decrypt_tool = CipherTool {
+            command: CipherCommand::Decrypt {
+                text: StringInput(val.as_str().unwrap().to_string()),
+                key,
+            },
+        };
+        let decrypted = decrypt_tool.execute().unwrap().unwrap();
+        let Output::Bytes(bytes) = decrypted else {
+            panic!("Expected Bytes output");
+        };
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), "hello world");
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_roundtrip_passphrase() {
+        let encrypt_tool = CipherTool {
+            command: CipherCommand::Encrypt {
+                text: StringInput("secret message".to_string()),
+                key: "correct horse battery staple".to_string(),
+                nonce: None,
+            },
+        };
+        let encrypted = encrypt_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encrypted else {
+            panic!("Expected JsonValue output");
+        };
+
+        let decrypt_tool = CipherTool {
+            command: CipherCommand::Decrypt {
+                text: StringInput(val.as_str().unwrap().to_string()),
+                key: "correct horse battery staple".to_string(),
+            },
+        };
+        let decrypted = decrypt_tool.execute().unwrap().unwrap();
+        let Output::Bytes(bytes) = decrypted else {
+            panic!("Expected Bytes output");
+        };
+
+        assert_eq!(String::from_utf8(bytes).unwrap(), "secret message");
+    }
+
+    #[test]
+    fn test_decrypt_tampered_data_fails() {
+        let key = "ab".repeat(KEY_LEN);
+
+        let encrypt_tool = CipherTool {
+            command: CipherCommand::Encrypt {
+                text: StringInput("hello".to_string()),
+                key: key.clone(),
+                nonce: None,
+            },
+        };
+        let encrypted = encrypt_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = encrypted else {
+            panic!("Expected JsonValue output");
+        };
+
+        let mut raw = general_purpose::STANDARD
+            .decode(val.as_str().unwrap())
+            .unwrap();
+        let last = raw.len() - 1;
+        raw[last] ^= 0xFF;
+        let tampered = general_purpose::STANDARD.encode(raw);
+
+        let decrypt_tool = CipherTool {
+            command: CipherCommand::Decrypt {
+                text: StringInput(tampered),
+                key,
+            },
+        };
+
+        let result = decrypt_tool.execute();
+        assert!(result.is_err());
+        assert!(result.unwrap_err().to_string().contains("tampered"));
+    }
+
+    #[test]
+    fn test_encrypt_rejects_wrong_length_nonce() {
+        let tool = CipherTool {
+            command: CipherCommand::Encrypt {
+                text: StringInput("hello".to_string()),
+                key: "00".repeat(KEY_LEN),
+                nonce: Some("ab".to_string()),
+            },
+        };
+
+        let result = tool.execute();
+        assert!(result.is_err());
+    }
+}
@@ -1,19 +1,17 @@
 use crate::tool::{Output, Tool};
 use anyhow::Context;
-use chrono::{DateTime, FixedOffset, Utc};
-use clap::{Command, CommandFactory, Parser};
+use chrono::{DateTime, Duration, FixedOffset, LocalResult, TimeZone, Timelike, Utc};
+use chrono_tz::Tz;
+use clap::{Command, CommandFactory, Parser, ValueEnum};
 use cron::Schedule;
 use serde_json::json;
 use std::str::FromStr;
 
 #[derive(Parser, Debug)]
 #[command(
-    name = "cron",
+    name = "schedule",
     about = "Parse crontab expression and show upcoming firing times"
 )]
-/// TODO:
-/// 1. Support --before
-/// 2. Output in a different timezone
 pub struct CronTool {
     /// Crontab expression (e.g., "0 9 * * 1-5" for weekdays at 9 AM, or "0 0 9 * * 1-5" for extended format)
     pub expression: String,
@@ -25,6 +23,41 @@ pub struct CronTool {
     /// Calculate firing times after this time (ISO 8601 format, defaults to now)
     #[arg(short = 'a', long = "after")]
     pub after: Option<String>,
+
+    /// Calculate firing times strictly before this time (ISO 8601 format). Combine
+    /// with --after to list only the occurrences within the half-open `[after,
+    /// before)` window, e.g. to check whether a job ran between two deploys
+    #[arg(short = 'b', long = "before")]
+    pub before: Option<String>,
+
+    /// Report firing times in this IANA timezone (e.g. "America/New_York") instead of
+    /// the offset carried by --after. Firing times are computed against the zone's wall
+    /// clock, so they stay at the same local time across DST changes.
+    #[arg(short = 'z', long = "timezone")]
+    pub timezone: Option<String>,
+
+    /// Print the expression as a plain-English sentence instead of computing firing
+    /// times, e.g. "0 9 * * 1-5" -> "At 09:00, Monday through Friday"
+    #[arg(long)]
+    pub describe: bool,
+
+    /// Test whether the expression fires at exactly this instant (ISO 8601 format).
+    /// The instant is truncated to the schedule's resolution (minute for 5-field
+    /// expressions, second for 6-field) before comparing
+    #[arg(long)]
+    pub matches: Option<String>,
+
+    /// Output format for firing times: a flat array of RFC3339 strings, or an
+    /// object per occurrence with the Unix timestamp, weekday name, and time
+    /// since the previous occurrence, handy for spotting irregular schedules
+    #[arg(long, value_enum, default_value = "simple")]
+    pub format: OutputFormat,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum OutputFormat {
+    Simple,
+    Detailed,
 }
 
 impl Tool for CronTool {
@@ -33,6 +66,10 @@ impl Tool for CronTool {
     }
 
     fn execute(&self) -> anyhow::Result<Option<Output>> {
+        if self.describe {
+            return Ok(Some(Output::JsonValue(describe_cron(&self.expression)?)));
+        }
+
         // Try to parse as-is first, then try adding seconds if it fails
         let schedule = Schedule::from_str(&self.expression)
             .or_else(|_| {
@@ -44,24 +81,122 @@ impl Tool for CronTool {
                 "Invalid crontab expression. Use format like '0 9 * * 1-5' or '0 0 9 * * 1-5'",
             )?;
 
-        let (after_utc, offset) = match &self.after {
-            Some(time_str) => {
-                let parsed = DateTime::parse_from_rfc3339(time_str).context(
-                    "Invalid after time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
-                )?;
-                let offset = parsed.timezone();
-                (parsed.with_timezone(&Utc), offset)
+        if let Some(time_str) = &self.matches {
+            // A traditional 5-field expression has no seconds field of its
+            // own (it's pinned to 0 by the "0 " prefix above), so the
+            // instant only needs truncating to the minute to compare fairly.
+            let minute_resolution = self.expression.split_whitespace().count() == 5;
+
+            if let Some(tz_name) = &self.timezone {
+                let tz: Tz = tz_name
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("Invalid IANA timezone name: {tz_name}"))?;
+                let parsed = DateTime::parse_from_rfc3339(time_str)
+                    .context(
+                        "Invalid matches time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+                    )?
+                    .with_timezone(&tz);
+                let candidate = truncate_to_resolution(parsed, minute_resolution);
+
+                return Ok(Some(Output::JsonValue(match_result_in_zone(
+                    &schedule, candidate,
+                ))));
             }
-            None => {
-                let now = Utc::now();
-                let offset = FixedOffset::east_opt(0).unwrap(); // UTC has offset 0
-                (now, offset)
+
+            let parsed = DateTime::parse_from_rfc3339(time_str).context(
+                "Invalid matches time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+            )?;
+            let offset = parsed.timezone();
+            let candidate = truncate_to_resolution(parsed, minute_resolution).with_timezone(&Utc);
+
+            return Ok(Some(Output::JsonValue(match_result(
+                &schedule, candidate, offset,
+            ))));
+        }
+
+        if let Some(tz_name) = &self.timezone {
+            let tz: Tz = tz_name
+                .parse()
+                .map_err(|_| anyhow::anyhow!("Invalid IANA timezone name: {tz_name}"))?;
+
+            let before_local = self
+                .before
+                .as_ref()
+                .map(|time_str| {
+                    DateTime::parse_from_rfc3339(time_str)
+                        .context(
+                            "Invalid before time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+                        )
+                        .map(|parsed| parsed.with_timezone(&tz))
+                })
+                .transpose()?;
+
+            let after_local = match &self.after {
+                Some(time_str) => {
+                    let parsed = DateTime::parse_from_rfc3339(time_str).context(
+                        "Invalid after time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+                    )?;
+                    Some(parsed.with_timezone(&tz))
+                }
+                None => None,
+            };
+
+            let times = match (after_local, before_local) {
+                (Some(after_local), Some(before_local)) => {
+                    get_window_times_in_zone(&schedule, after_local, before_local, self.count)
+                }
+                (None, Some(before_local)) => {
+                    get_previous_times_in_zone(&schedule, before_local, self.count)
+                }
+                (after_local, None) => get_upcoming_times_in_zone(
+                    &schedule,
+                    after_local.unwrap_or_else(|| Utc::now().with_timezone(&tz)),
+                    self.count,
+                ),
+            };
+
+            return Ok(Some(Output::JsonValue(render_times(times, self.format))));
+        }
+
+        let before_utc = self
+            .before
+            .as_ref()
+            .map(|time_str| {
+                DateTime::parse_from_rfc3339(time_str)
+                    .context(
+                        "Invalid before time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+                    )
+                    .map(|parsed| (parsed.with_timezone(&Utc), parsed.timezone()))
+            })
+            .transpose()?;
+
+        let after_utc = self
+            .after
+            .as_ref()
+            .map(|time_str| {
+                DateTime::parse_from_rfc3339(time_str)
+                    .context(
+                        "Invalid after time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+                    )
+                    .map(|parsed| (parsed.with_timezone(&Utc), parsed.timezone()))
+            })
+            .transpose()?;
+
+        let times = match (after_utc, before_utc) {
+            (Some((after, offset)), Some((before, _))) => {
+                get_window_times(&schedule, after, before, offset, self.count)
+            }
+            (None, Some((before, offset))) => {
+                get_previous_times(&schedule, before, offset, self.count)
+            }
+            (after_utc, None) => {
+                let (after, offset) =
+                    after_utc.unwrap_or_else(|| (Utc::now(), FixedOffset::east_opt(0).unwrap()));
+                get_upcoming_times(&schedule, after, offset, self.count)?
             }
         };
 
-        Ok(Some(Output::JsonValue(json!(get_upcoming_times(
-            &schedule, after_utc, offset, self.count
-        )?))))
+        Ok(Some(Output::JsonValue(render_times(times, self.format))))
     }
 }
 
@@ -83,6 +218,580 @@ fn get_upcoming_times(
     Ok(upcoming_times)
 }
 
+// `cron::Schedule` only exposes forward iteration via `.after()`, so the
+// backward walk brackets the reference instant: open a window some coarse
+// span before it, collect every forward fire strictly before `before`, and
+// double the lookback until `count` fires were found (or a sane cap is hit,
+// for schedules that fire less than once per lookback span).
+fn get_previous_times(
+    schedule: &Schedule,
+    before: DateTime<Utc>,
+    offset: FixedOffset,
+    count: usize,
+) -> Vec<String> {
+    const MAX_DOUBLINGS: u32 = 20;
+    let mut lookback = Duration::hours(1);
+
+    for _ in 0..MAX_DOUBLINGS {
+        let window_start = before - lookback;
+
+        let fires: Vec<DateTime<Utc>> = schedule
+            .after(&window_start)
+            .take_while(|dt| *dt < before)
+            .collect();
+
+        if fires.len() >= count || lookback > Duration::days(365 * 50) {
+            let skip = fires.len().saturating_sub(count);
+            return fires
+                .into_iter()
+                .skip(skip)
+                .map(|dt| dt.with_timezone(&offset).to_rfc3339())
+                .collect();
+        }
+
+        lookback *= 2;
+    }
+
+    Vec::new()
+}
+
+fn get_window_times(
+    schedule: &Schedule,
+    after: DateTime<Utc>,
+    before: DateTime<Utc>,
+    offset: FixedOffset,
+    count: usize,
+) -> Vec<String> {
+    schedule
+        .after(&after)
+        .take_while(|dt| *dt < before)
+        .take(count)
+        .map(|dt| dt.with_timezone(&offset).to_rfc3339())
+        .collect()
+}
+
+// Resolves a candidate wall-clock time in `tz` to a concrete instant, the
+// way a real clock would: nonexistent times in the spring-forward gap are
+// skipped (`None`), and ambiguous times in the fall-back fold resolve to the
+// earlier of the two instants.
+fn resolve_local(tz: Tz, naive: chrono::NaiveDateTime) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Some(dt),
+        LocalResult::Ambiguous(earlier, _later) => Some(earlier),
+        LocalResult::None => None,
+    }
+}
+
+// `cron::Schedule::after` steps in the timezone of whatever instant you hand
+// it, but minute-by-minute stepping over `Tz` wall-clock values (rather than
+// converting to/through UTC) is what keeps a "9am" schedule firing at 9am
+// local even as the UTC offset shifts across a DST boundary. Bounded so a
+// schedule that can never fire again doesn't spin forever.
+const MAX_ZONE_SCAN_MINUTES: i64 = 5 * 365 * 24 * 60;
+
+fn get_upcoming_times_in_zone(
+    schedule: &Schedule,
+    after: DateTime<Tz>,
+    count: usize,
+) -> Vec<String> {
+    let tz = after.timezone();
+    let mut naive = after
+        .naive_local()
+        .with_second(0)
+        .unwrap()
+        .with_nanosecond(0)
+        .unwrap()
+        + Duration::minutes(1);
+
+    let mut found = Vec::new();
+    let mut scanned = 0;
+
+    while found.len() < count && scanned < MAX_ZONE_SCAN_MINUTES {
+        if let Some(candidate) = resolve_local(tz, naive) {
+            if schedule.includes(candidate) {
+                found.push(candidate.to_rfc3339());
+            }
+        }
+
+        naive += Duration::minutes(1);
+        scanned += 1;
+    }
+
+    found
+}
+
+fn get_previous_times_in_zone(
+    schedule: &Schedule,
+    before: DateTime<Tz>,
+    count: usize,
+) -> Vec<String> {
+    let tz = before.timezone();
+    let mut naive = before
+        .naive_local()
+        .with_second(0)
+        .unwrap()
+        .with_nanosecond(0)
+        .unwrap();
+
+    let mut found = Vec::new();
+    let mut scanned = 0;
+
+    while found.len() < count && scanned < MAX_ZONE_SCAN_MINUTES {
+        naive -= Duration::minutes(1);
+
+        if let Some(candidate) = resolve_local(tz, naive) {
+            if candidate < before && schedule.includes(candidate) {
+                found.push(candidate.to_rfc3339());
+            }
+        }
+
+        scanned += 1;
+    }
+
+    found.reverse();
+    found
+}
+
+fn get_window_times_in_zone(
+    schedule: &Schedule,
+    after: DateTime<Tz>,
+    before: DateTime<Tz>,
+    count: usize,
+) -> Vec<String> {
+    let tz = after.timezone();
+    let mut naive = after
+        .naive_local()
+        .with_second(0)
+        .unwrap()
+        .with_nanosecond(0)
+        .unwrap()
+        + Duration::minutes(1);
+
+    let mut found = Vec::new();
+    let mut scanned = 0;
+
+    while found.len() < count && scanned < MAX_ZONE_SCAN_MINUTES {
+        match resolve_local(tz, naive) {
+            Some(candidate) if candidate >= before => break,
+            Some(candidate) if schedule.includes(candidate) => found.push(candidate.to_rfc3339()),
+            _ => {}
+        }
+
+        naive += Duration::minutes(1);
+        scanned += 1;
+    }
+
+    found
+}
+
+fn truncate_to_resolution<Tz: TimeZone>(dt: DateTime<Tz>, minute_resolution: bool) -> DateTime<Tz> {
+    let dt = dt.with_nanosecond(0).unwrap();
+    if minute_resolution {
+        dt.with_second(0).unwrap()
+    } else {
+        dt
+    }
+}
+
+// Renders a list of RFC3339 firing times according to the requested
+// `OutputFormat`. `Detailed` re-parses each entry to attach the Unix
+// timestamp, weekday name, and the gap since the previous occurrence, which
+// is handy for spotting irregular schedules (e.g. a twice-daily job where
+// the two fires land an uneven number of hours apart).
+fn render_times(times: Vec<String>, format: OutputFormat) -> serde_json::Value {
+    let OutputFormat::Detailed = format else {
+        return json!(times);
+    };
+
+    let mut previous: Option<DateTime<FixedOffset>> = None;
+    let entries: Vec<serde_json::Value> = times
+        .iter()
+        .map(|time| {
+            let parsed = DateTime::parse_from_rfc3339(time).expect("already-formatted RFC3339");
+
+            let since_previous = previous.map(|prev| parsed.signed_duration_since(prev));
+            previous = Some(parsed);
+
+            json!({
+                "time": time,
+                "unix": parsed.timestamp(),
+                "weekday": parsed.format("%A").to_string(),
+                "since_previous_seconds": since_previous.map(|d| d.num_seconds()),
+                "since_previous_human": since_previous.map(format_duration_human),
+            })
+        })
+        .collect();
+
+    json!(entries)
+}
+
+// Renders a duration as a single coarse unit (e.g. "23h", "1d"), picking the
+// largest unit that divides the duration evenly and falling back to seconds
+// otherwise.
+fn format_duration_human(duration: Duration) -> String {
+    let total_seconds = duration.num_seconds();
+
+    if total_seconds != 0 && total_seconds % 86400 == 0 {
+        format!("{}d", total_seconds / 86400)
+    } else if total_seconds != 0 && total_seconds % 3600 == 0 {
+        format!("{}h", total_seconds / 3600)
+    } else if total_seconds != 0 && total_seconds % 60 == 0 {
+        format!("{}m", total_seconds / 60)
+    } else {
+        format!("{total_seconds}s")
+    }
+}
+
+fn match_result(
+    schedule: &Schedule,
+    candidate: DateTime<Utc>,
+    offset: FixedOffset,
+) -> serde_json::Value {
+    if schedule.includes(candidate) {
+        return json!({
+            "matches": true,
+            "instant": candidate.with_timezone(&offset).to_rfc3339(),
+        });
+    }
+
+    let preceding = get_previous_times(schedule, candidate, offset, 1)
+        .into_iter()
+        .next();
+    let following = get_upcoming_times(schedule, candidate, offset, 1)
+        .unwrap_or_default()
+        .into_iter()
+        .next();
+
+    json!({
+        "matches": false,
+        "instant": candidate.with_timezone(&offset).to_rfc3339(),
+        "preceding": preceding,
+        "following": following,
+    })
+}
+
+fn match_result_in_zone(schedule: &Schedule, candidate: DateTime<Tz>) -> serde_json::Value {
+    if schedule.includes(candidate) {
+        return json!({
+            "matches": true,
+            "instant": candidate.to_rfc3339(),
+        });
+    }
+
+    let preceding = get_previous_times_in_zone(schedule, candidate, 1)
+        .into_iter()
+        .next();
+    let following = get_upcoming_times_in_zone(schedule, candidate, 1)
+        .into_iter()
+        .next();
+
+    json!({
+        "matches": false,
+        "instant": candidate.to_rfc3339(),
+        "preceding": preceding,
+        "following": following,
+    })
+}
+
+// A parsed crontab field: a single value, a range, a step over a base
+// (wildcard or range), or a comma-separated list of any of the above.
+// This is intentionally simpler than `cron::Schedule`'s own field model
+// (no named months/weekdays, no "L"/"W"/"#" extensions) since it only
+// needs to drive plain-English rendering, not scheduling.
+#[derive(Debug, Clone)]
+enum FieldValue {
+    Wildcard,
+    Single(i64),
+    Range(i64, i64),
+    Step { base: Box<FieldValue>, step: i64 },
+    List(Vec<FieldValue>),
+}
+
+// `cron::Schedule` happily accepts field values outside the range a real
+// crontab implementation would honor, so `--describe` enforces the
+// conventional bounds itself rather than silently describing nonsense.
+fn validate_field_range(field: &FieldValue, min: i64, max: i64, noun: &str) -> anyhow::Result<()> {
+    match field {
+        FieldValue::Wildcard => Ok(()),
+        FieldValue::Single(value) => check_field_range(*value, min, max, noun),
+        FieldValue::Range(start, end) => {
+            check_field_range(*start, min, max, noun)?;
+            check_field_range(*end, min, max, noun)
+        }
+        FieldValue::Step { base, .. } => validate_field_range(base, min, max, noun),
+        FieldValue::List(items) => items
+            .iter()
+            .try_for_each(|item| validate_field_range(item, min, max, noun)),
+    }
+}
+
+fn check_field_range(value: i64, min: i64, max: i64, noun: &str) -> anyhow::Result<()> {
+    if value < min || value > max {
+        anyhow::bail!("Invalid {noun} value {value}: expected {min}-{max}");
+    }
+    Ok(())
+}
+
+fn parse_field(raw: &str, min: i64, max: i64, noun: &str) -> anyhow::Result<FieldValue> {
+    let value = parse_field_item(raw)?;
+    validate_field_range(&value, min, max, noun)?;
+    Ok(value)
+}
+
+fn parse_field_item(raw: &str) -> anyhow::Result<FieldValue> {
+    let items: Vec<&str> = raw.split(',').collect();
+
+    if items.len() > 1 {
+        let parsed = items
+            .iter()
+            .map(|item| parse_field_step(item))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        return Ok(FieldValue::List(parsed));
+    }
+
+    parse_field_step(raw)
+}
+
+fn parse_field_step(raw: &str) -> anyhow::Result<FieldValue> {
+    if let Some((base, step)) = raw.split_once('/') {
+        let step: i64 = step
+            .parse()
+            .with_context(|| format!("Invalid step value in cron field: {raw}"))?;
+
+        return Ok(FieldValue::Step {
+            base: Box::new(parse_field_base(base)?),
+            step,
+        });
+    }
+
+    parse_field_base(raw)
+}
+
+fn parse_field_base(raw: &str) -> anyhow::Result<FieldValue> {
+    if raw == "*" {
+        return Ok(FieldValue::Wildcard);
+    }
+
+    if let Some((start, end)) = raw.split_once('-') {
+        let start: i64 = start
+            .parse()
+            .with_context(|| format!("Invalid range start in cron field: {raw}"))?;
+        let end: i64 = end
+            .parse()
+            .with_context(|| format!("Invalid range end in cron field: {raw}"))?;
+        return Ok(FieldValue::Range(start, end));
+    }
+
+    let value: i64 = raw
+        .parse()
+        .with_context(|| format!("Invalid value in cron field: {raw}"))?;
+    Ok(FieldValue::Single(value))
+}
+
+fn weekday_name(day: i64) -> String {
+    const NAMES: [&str; 7] = [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ];
+    NAMES[(day.rem_euclid(7)) as usize].to_string()
+}
+
+fn month_name(month: i64) -> String {
+    const NAMES: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    NAMES[((month - 1).rem_euclid(12)) as usize].to_string()
+}
+
+fn join_with_and(items: Vec<String>) -> String {
+    match items.len() {
+        0 => String::new(),
+        1 => items[0].clone(),
+        2 => format!("{} and {}", items[0], items[1]),
+        _ => {
+            let (last, rest) = items.split_last().unwrap();
+            format!("{}, and {}", rest.join(", "), last)
+        }
+    }
+}
+
+// Renders a field's value using `namer` for individual numbers (plain
+// digits for minute/hour/day-of-month, weekday/month names otherwise).
+fn describe_field(field: &FieldValue, namer: &dyn Fn(i64) -> String, noun: &str) -> String {
+    match field {
+        FieldValue::Wildcard => format!("every {noun}"),
+        FieldValue::Single(value) => namer(*value),
+        FieldValue::Range(start, end) => format!("{} through {}", namer(*start), namer(*end)),
+        FieldValue::Step { base, step } => match base.as_ref() {
+            FieldValue::Range(start, end) => format!(
+                "every {step} {noun}s from {} through {}",
+                namer(*start),
+                namer(*end)
+            ),
+            _ => format!("every {step} {noun}s"),
+        },
+        FieldValue::List(items) => join_with_and(
+            items
+                .iter()
+                .map(|item| describe_field(item, namer, noun))
+                .collect(),
+        ),
+    }
+}
+
+fn describe_seconds_suffix(second: &FieldValue) -> String {
+    match second {
+        FieldValue::Single(0) => String::new(),
+        FieldValue::Wildcard => " and every second".to_string(),
+        FieldValue::Step { base, step } if matches!(base.as_ref(), FieldValue::Wildcard) => {
+            format!(" and every {step} seconds")
+        }
+        other => format!(
+            " at second {}",
+            describe_field(other, &|v| v.to_string(), "second")
+        ),
+    }
+}
+
+fn describe_time(second: &FieldValue, minute: &FieldValue, hour: &FieldValue) -> String {
+    let seconds_suffix = describe_seconds_suffix(second);
+
+    match (minute, hour) {
+        (FieldValue::Wildcard, FieldValue::Wildcard) => format!("every minute{seconds_suffix}"),
+        (FieldValue::Single(0), FieldValue::Wildcard) => {
+            format!("every hour, on the hour{seconds_suffix}")
+        }
+        (FieldValue::Step { base, step }, FieldValue::Wildcard)
+            if matches!(base.as_ref(), FieldValue::Wildcard) =>
+        {
+            format!("every {step} minutes{seconds_suffix}")
+        }
+        (FieldValue::Single(minute), FieldValue::Single(hour)) => {
+            format!("At {hour:02}:{minute:02}{seconds_suffix}")
+        }
+        _ => format!(
+            "At minute {} past hour {}{seconds_suffix}",
+            describe_field(minute, &|v| v.to_string(), "minute"),
+            describe_field(hour, &|v| v.to_string(), "hour")
+        ),
+    }
+}
+
+fn describe_day_of_week(field: &FieldValue) -> Option<String> {
+    match field {
+        FieldValue::Wildcard => None,
+        other => Some(describe_field(other, &weekday_name, "day")),
+    }
+}
+
+fn describe_date(day_of_month: &FieldValue, month: &FieldValue) -> Option<String> {
+    let day_clause = match day_of_month {
+        FieldValue::Wildcard => None,
+        other => Some(format!(
+            "on day {}",
+            describe_field(other, &|v| v.to_string(), "day")
+        )),
+    };
+
+    let month_clause = match month {
+        FieldValue::Wildcard => None,
+        other => Some(format!(
+            "in {}",
+            describe_field(other, &month_name, "month")
+        )),
+    };
+
+    match (day_clause, month_clause) {
+        (None, None) => None,
+        (Some(day), None) => Some(day),
+        (None, Some(month)) => Some(month),
+        (Some(day), Some(month)) => Some(format!("{day} {month}")),
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+// Turns a crontab expression into a plain-English sentence, decomposing
+// each field itself rather than relying on `cron::Schedule` (which has
+// already collapsed the field structure by the time it parses). Returns
+// both the rendered sentence and each field's normalized text so callers
+// get a structured description to go with the human-readable one.
+fn describe_cron(expression: &str) -> anyhow::Result<serde_json::Value> {
+    let expanded = match expression.split_whitespace().count() {
+        5 => format!("0 {expression}"),
+        _ => expression.to_string(),
+    };
+
+    let fields: Vec<&str> = expanded.split_whitespace().collect();
+    if fields.len() != 6 {
+        anyhow::bail!(
+            "Invalid crontab expression. Use format like '0 9 * * 1-5' or '0 0 9 * * 1-5'"
+        );
+    }
+
+    let second = parse_field(fields[0], 0, 59, "second")?;
+    let minute = parse_field(fields[1], 0, 59, "minute")?;
+    let hour = parse_field(fields[2], 0, 23, "hour")?;
+    let day_of_month = parse_field(fields[3], 1, 31, "day of month")?;
+    let month = parse_field(fields[4], 1, 12, "month")?;
+    let day_of_week = parse_field(fields[5], 0, 7, "day of week")?;
+
+    let time_clause = describe_time(&second, &minute, &hour);
+    let dow_clause = describe_day_of_week(&day_of_week);
+    let date_clause = describe_date(&day_of_month, &month);
+
+    let mut clauses = vec![time_clause.clone()];
+    if dow_clause.is_none() && date_clause.is_none() && time_clause.starts_with("At ") {
+        clauses[0] = format!("Daily {}", lowercase_first(&time_clause));
+    }
+    clauses.extend(dow_clause);
+    clauses.extend(date_clause);
+
+    let description = capitalize_first(&clauses.join(", "));
+
+    Ok(json!({
+        "description": description,
+        "fields": {
+            "second": describe_field(&second, &|v| format!("{v:02}"), "second"),
+            "minute": describe_field(&minute, &|v| format!("{v:02}"), "minute"),
+            "hour": describe_field(&hour, &|v| format!("{v:02}"), "hour"),
+            "day_of_month": describe_field(&day_of_month, &|v| v.to_string(), "day"),
+            "month": describe_field(&month, &month_name, "month"),
+            "day_of_week": describe_field(&day_of_week, &weekday_name, "day"),
+        }
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -93,6 +802,11 @@ mod tests {
             expression: "0 9 * * 1-5".to_string(),
             count: 3,
             after: Some("2024-01-01T00:00:00Z".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -115,6 +829,11 @@ mod tests {
             expression: "0 0 * * *".to_string(),
             count: 2,
             after: Some("2024-01-01T00:00:00Z".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -136,6 +855,11 @@ mod tests {
             expression: "0 * * * *".to_string(),
             count: 5,
             after: Some("2024-01-01T00:00:00Z".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -160,6 +884,11 @@ mod tests {
             expression: "0 9 * * 1-5".to_string(),
             count: 2,
             after: Some("2024-03-15T10:00:00Z".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -181,6 +910,11 @@ mod tests {
             expression: "invalid".to_string(),
             count: 5,
             after: None,
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute();
 
@@ -193,6 +927,11 @@ mod tests {
             expression: "0 9 * * 1-5".to_string(),
             count: 5,
             after: Some("invalid-time".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute();
 
@@ -205,6 +944,11 @@ mod tests {
             expression: "0 9 * * 1-5".to_string(),
             count: 2,
             after: Some("2024-01-01T00:00:00+05:30".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -219,4 +963,462 @@ mod tests {
         assert_eq!(arr[0].as_str().unwrap(), "2024-01-01T14:30:00+05:30");
         assert_eq!(arr[1].as_str().unwrap(), "2024-01-02T14:30:00+05:30");
     }
+
+    #[test]
+    fn test_timezone_option_tracks_dst_offset_change() {
+        // Daylight saving in America/New_York starts 2024-03-10, so the
+        // third 9am firing crosses from EST (-05:00) into EDT (-04:00)
+        // while staying at 9am local time both days.
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 3,
+            after: Some("2024-03-08T00:00:00-05:00".to_string()),
+            before: None,
+            timezone: Some("America/New_York".to_string()),
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-03-08T09:00:00-05:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-03-09T09:00:00-05:00");
+        assert_eq!(arr[2].as_str().unwrap(), "2024-03-10T09:00:00-04:00");
+    }
+
+    #[test]
+    fn test_timezone_option_skips_nonexistent_spring_forward_time() {
+        // On 2024-03-10 in America/New_York, clocks jump from 02:00 to
+        // 03:00, so 02:30 never happens and that day's firing is skipped.
+        let tool = CronTool {
+            expression: "30 2 * * *".to_string(),
+            count: 1,
+            after: Some("2024-03-09T03:00:00-05:00".to_string()),
+            before: None,
+            timezone: Some("America/New_York".to_string()),
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-03-11T02:30:00-04:00");
+    }
+
+    #[test]
+    fn test_timezone_option_resolves_ambiguous_fall_back_time_to_earlier_instant() {
+        // On 2024-11-03 in America/New_York, 01:30 happens twice as clocks
+        // fall back; the earlier (still-EDT) instant should be reported.
+        let tool = CronTool {
+            expression: "30 1 * * *".to_string(),
+            count: 1,
+            after: Some("2024-11-03T00:00:00-04:00".to_string()),
+            before: None,
+            timezone: Some("America/New_York".to_string()),
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-11-03T01:30:00-04:00");
+    }
+
+    #[test]
+    fn test_timezone_option_rejects_unknown_zone_name() {
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 1,
+            after: None,
+            before: None,
+            timezone: Some("Not/A_Zone".to_string()),
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_before_returns_previous_fires_in_chronological_order() {
+        let tool = CronTool {
+            expression: "0 9 * * 1-5".to_string(),
+            count: 3,
+            after: None,
+            before: Some("2024-01-10T00:00:00Z".to_string()),
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 3);
+
+        // 2024-01-10 is a Wednesday, so the previous weekday firings walk
+        // back over the weekend: Fri 5th, Mon 8th, then Tue 9th.
+        assert_eq!(arr[0].as_str().unwrap(), "2024-01-05T09:00:00+00:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-01-08T09:00:00+00:00");
+        assert_eq!(arr[2].as_str().unwrap(), "2024-01-09T09:00:00+00:00");
+    }
+
+    #[test]
+    fn test_before_is_exclusive_of_the_reference_instant() {
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 1,
+            after: None,
+            before: Some("2024-01-09T09:00:00Z".to_string()),
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-01-08T09:00:00+00:00");
+    }
+
+    #[test]
+    fn test_after_and_before_return_occurrences_within_window() {
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 10,
+            after: Some("2024-01-05T00:00:00Z".to_string()),
+            before: Some("2024-01-08T09:00:00Z".to_string()),
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-01-05T09:00:00+00:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-01-06T09:00:00+00:00");
+    }
+
+    #[test]
+    fn test_before_in_timezone_returns_previous_fires() {
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 2,
+            after: None,
+            before: Some("2024-03-10T09:00:00-04:00".to_string()),
+            timezone: Some("America/New_York".to_string()),
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-03-08T09:00:00-05:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-03-09T09:00:00-05:00");
+    }
+
+    #[test]
+    fn test_after_and_before_in_timezone_return_occurrences_within_window() {
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 10,
+            after: Some("2024-03-08T00:00:00-05:00".to_string()),
+            before: Some("2024-03-10T09:00:00-04:00".to_string()),
+            timezone: Some("America/New_York".to_string()),
+            describe: false,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 2);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-03-08T09:00:00-05:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-03-09T09:00:00-05:00");
+    }
+
+    #[test]
+    fn test_describe_weekday_schedule() {
+        let tool = CronTool {
+            expression: "0 9 * * 1-5".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: true,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(
+            val["description"].as_str().unwrap(),
+            "At 09:00, Monday through Friday"
+        );
+        assert_eq!(val["fields"]["hour"].as_str().unwrap(), "09");
+        assert_eq!(val["fields"]["minute"].as_str().unwrap(), "00");
+        assert_eq!(
+            val["fields"]["day_of_week"].as_str().unwrap(),
+            "Monday through Friday"
+        );
+    }
+
+    #[test]
+    fn test_describe_wildcard_schedule_is_daily() {
+        let tool = CronTool {
+            expression: "0 0 * * *".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: true,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(val["description"].as_str().unwrap(), "Daily at 00:00");
+    }
+
+    #[test]
+    fn test_describe_rejects_out_of_range_field() {
+        let tool = CronTool {
+            expression: "0 9 * * 8".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: true,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+
+        let err = tool.execute().unwrap_err();
+        assert!(err.to_string().contains("day of week"));
+    }
+
+    #[test]
+    fn test_describe_rejects_extended_field_out_of_range() {
+        let tool = CronTool {
+            expression: "70 0 9 * * *".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: true,
+            matches: None,
+            format: OutputFormat::Simple,
+        };
+
+        let err = tool.execute().unwrap_err();
+        assert!(err.to_string().contains("second"));
+    }
+
+    #[test]
+    fn test_matches_true_for_firing_instant() {
+        let tool = CronTool {
+            expression: "0 9 * * 1-5".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: Some("2024-01-01T09:00:00Z".to_string()),
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(val["matches"], true);
+        assert_eq!(
+            val["instant"].as_str().unwrap(),
+            "2024-01-01T09:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_matches_truncates_seconds_for_five_field_expression() {
+        let tool = CronTool {
+            expression: "0 9 * * 1-5".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: Some("2024-01-01T09:00:47Z".to_string()),
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(val["matches"], true);
+    }
+
+    #[test]
+    fn test_matches_false_reports_preceding_and_following() {
+        let tool = CronTool {
+            expression: "0 9 * * 1-5".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: Some("2024-01-02T12:00:00Z".to_string()),
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(val["matches"], false);
+        assert_eq!(
+            val["preceding"].as_str().unwrap(),
+            "2024-01-02T09:00:00+00:00"
+        );
+        assert_eq!(
+            val["following"].as_str().unwrap(),
+            "2024-01-03T09:00:00+00:00"
+        );
+    }
+
+    #[test]
+    fn test_matches_respects_timezone_across_dst() {
+        let tool = CronTool {
+            expression: "0 9 * * *".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: Some("America/New_York".to_string()),
+            describe: false,
+            matches: Some("2024-03-10T09:00:00-04:00".to_string()),
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(val["matches"], true);
+    }
+
+    #[test]
+    fn test_matches_preserves_seconds_for_six_field_expression() {
+        let tool = CronTool {
+            expression: "30 0 9 * * 1-5".to_string(),
+            count: 5,
+            after: None,
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: Some("2024-01-01T09:00:00Z".to_string()),
+            format: OutputFormat::Simple,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        assert_eq!(val["matches"], false);
+    }
+
+    #[test]
+    fn test_detailed_format_reports_timestamp_weekday_and_gap() {
+        let tool = CronTool {
+            expression: "0 9,17 * * 1-5".to_string(),
+            count: 3,
+            after: Some("2024-01-01T00:00:00Z".to_string()),
+            before: None,
+            timezone: None,
+            describe: false,
+            matches: None,
+            format: OutputFormat::Detailed,
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 3);
+
+        // 2024-01-01 was Monday, so the schedule fires at 09:00 and 17:00.
+        assert_eq!(arr[0]["time"], "2024-01-01T09:00:00+00:00");
+        assert_eq!(arr[0]["unix"], 1704099600);
+        assert_eq!(arr[0]["weekday"], "Monday");
+        assert!(arr[0]["since_previous_seconds"].is_null());
+        assert!(arr[0]["since_previous_human"].is_null());
+
+        // 09:00 -> 17:00 the same day is an 8 hour gap.
+        assert_eq!(arr[1]["time"], "2024-01-01T17:00:00+00:00");
+        assert_eq!(arr[1]["since_previous_seconds"], 8 * 3600);
+        assert_eq!(arr[1]["since_previous_human"], "8h");
+
+        // 17:00 -> next day's 09:00 is a 16 hour gap.
+        assert_eq!(arr[2]["time"], "2024-01-02T09:00:00+00:00");
+        assert_eq!(arr[2]["since_previous_seconds"], 16 * 3600);
+        assert_eq!(arr[2]["since_previous_human"], "16h");
+    }
 }
@@ -1,7 +1,11 @@
 use crate::args::StringInput;
 use crate::tool::{Output, Tool};
-use anyhow::Context;
-use chrono::{DateTime, FixedOffset, Utc};
+use anyhow::{Context, bail};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, LocalResult, NaiveDate, NaiveTime, TimeZone,
+    Timelike, Utc, Weekday,
+};
+use chrono_tz::Tz;
 use clap::{Command, CommandFactory, Parser, Subcommand};
 use cron::Schedule;
 use serde_json::json;
@@ -14,24 +18,50 @@ pub struct CrontabTool {
     command: CrontabCommand,
 }
 
-/// TODO:
-/// 1. Support --before
-/// 2. Output in a different timezone
 #[derive(Subcommand, Debug)]
 enum CrontabCommand {
-    /// Parse crontab expression and show upcoming firing times
+    /// Parse crontab expression and show upcoming (or, with --before, past) firing times
     Schedule {
         /// Crontab expression (use "-" for stdin)
         expression: StringInput,
 
-        /// Number of upcoming firing times to show (default: 5)
+        /// Number of firing times to show (default: 5)
         #[arg(short = 'n', long = "count", default_value = "5")]
         count: usize,
 
         /// Calculate firing times after this time (ISO 8601 format, defaults to now)
+        #[arg(short = 'a', long = "after", conflicts_with = "before")]
+        after: Option<String>,
+
+        /// Calculate the most recent firing times at or before this time (ISO 8601 format),
+        /// returned in descending order
+        #[arg(short = 'b', long = "before", conflicts_with = "after")]
+        before: Option<String>,
+
+        /// Report firing times in this IANA timezone (e.g. "America/New_York") instead of
+        /// the offset carried by --after/--before. Firing times are computed against the
+        /// zone's wall clock, so they stay at the same local time across DST changes.
+        #[arg(short = 'z', long = "timezone")]
+        timezone: Option<String>,
+    },
+    /// Parse an RFC 5545 RRULE and show upcoming occurrences
+    Rrule {
+        /// RRULE string, e.g. "FREQ=MONTHLY;BYDAY=-1MO;INTERVAL=2;COUNT=5" (use "-" for stdin)
+        rule: StringInput,
+
+        /// Number of upcoming occurrences to show (default: 5)
+        #[arg(short = 'n', long = "count", default_value = "5")]
+        count: usize,
+
+        /// Calculate occurrences after this time (ISO 8601 format, defaults to now)
         #[arg(short = 'a', long = "after")]
         after: Option<String>,
     },
+    /// Describe a crontab expression in plain English, without computing firing times
+    Describe {
+        /// Crontab expression (use "-" for stdin)
+        expression: StringInput,
+    },
 }
 
 impl Tool for CrontabTool {
@@ -45,25 +75,91 @@ impl Tool for CrontabTool {
                 expression,
                 count,
                 after,
-            } => execute_schedule(expression.as_ref(), *count, after.as_ref()),
+                before,
+                timezone,
+            } => execute_schedule(
+                expression.as_ref(),
+                *count,
+                after.as_ref(),
+                before.as_ref(),
+                timezone.as_ref(),
+            ),
+            CrontabCommand::Rrule { rule, count, after } => {
+                execute_rrule(rule.as_ref(), *count, after.as_ref())
+            }
+            CrontabCommand::Describe { expression } => {
+                Ok(Some(Output::Text(describe_cron(expression.as_ref())?)))
+            }
         }
     }
 }
 
+// Traditional crontab expressions omit the leading seconds field that
+// `cron::Schedule` expects; prepending "0 " maps the familiar 5-field
+// format onto the 6-field one both `Schedule` and `describe_cron` parse.
+fn extend_to_six_fields(expression: &str) -> String {
+    format!("0 {}", expression)
+}
+
 fn execute_schedule(
     expression: &str,
     count: usize,
     after: Option<&String>,
+    before: Option<&String>,
+    timezone: Option<&String>,
 ) -> anyhow::Result<Option<Output>> {
     // Try to parse as-is first, then try adding seconds if it fails
     let schedule = Schedule::from_str(expression)
-        .or_else(|_| {
-            // If parsing fails, try adding "0 " at the beginning for traditional 5-field format
-            let extended_expr = format!("0 {}", expression);
-            Schedule::from_str(&extended_expr)
-        })
+        .or_else(|_| Schedule::from_str(&extend_to_six_fields(expression)))
         .context("Invalid crontab expression. Use format like '0 9 * * 1-5' or '0 0 9 * * 1-5'")?;
 
+    if let Some(tz_name) = timezone {
+        let tz: Tz = tz_name
+            .parse()
+            .map_err(|_| anyhow::anyhow!("Invalid IANA timezone name: {tz_name}"))?;
+
+        if let Some(time_str) = before {
+            let parsed = DateTime::parse_from_rfc3339(time_str).context(
+                "Invalid before time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+            )?;
+
+            return Ok(Some(Output::JsonValue(json!(get_previous_times_in_zone(
+                &schedule,
+                parsed.with_timezone(&tz),
+                count
+            )))));
+        }
+
+        let after_local = match after {
+            Some(time_str) => {
+                let parsed = DateTime::parse_from_rfc3339(time_str).context(
+                    "Invalid after time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)",
+                )?;
+                parsed.with_timezone(&tz)
+            }
+            None => Utc::now().with_timezone(&tz),
+        };
+
+        return Ok(Some(Output::JsonValue(json!(get_upcoming_times_in_zone(
+            &schedule,
+            after_local,
+            count
+        )))));
+    }
+
+    if let Some(time_str) = before {
+        let parsed = DateTime::parse_from_rfc3339(time_str)
+            .context("Invalid before time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)")?;
+        let offset = parsed.timezone();
+
+        return Ok(Some(Output::JsonValue(json!(get_previous_times(
+            &schedule,
+            parsed.with_timezone(&Utc),
+            offset,
+            count
+        )))));
+    }
+
     let (after_utc, offset) = match after {
         Some(time_str) => {
             let parsed = DateTime::parse_from_rfc3339(time_str).context(
@@ -102,6 +198,872 @@ fn get_upcoming_times(
     Ok(upcoming_times)
 }
 
+// `cron::Schedule` only exposes forward iteration via `.after()`, so the
+// backward walk brackets the reference instant: open a window some coarse
+// span before it, collect every forward fire up to the reference, and
+// double the lookback until `count` fires were found (or a sane cap is
+// hit, for schedules that fire less than once per lookback span).
+fn get_previous_times(
+    schedule: &Schedule,
+    before: DateTime<Utc>,
+    offset: FixedOffset,
+    count: usize,
+) -> Vec<String> {
+    const MAX_DOUBLINGS: u32 = 20;
+    let mut lookback = Duration::hours(1);
+
+    for _ in 0..MAX_DOUBLINGS {
+        let window_start = before - lookback;
+
+        let fires: Vec<DateTime<Utc>> = schedule
+            .after(&window_start)
+            .take_while(|dt| *dt <= before)
+            .collect();
+
+        if fires.len() >= count || lookback > Duration::days(365 * 50) {
+            return fires
+                .into_iter()
+                .rev()
+                .take(count)
+                .map(|dt| dt.with_timezone(&offset).to_rfc3339())
+                .collect();
+        }
+
+        lookback *= 2;
+    }
+
+    Vec::new()
+}
+
+// Resolves a candidate wall-clock time in `tz` to a concrete instant,
+// the way a real clock would: nonexistent times in the spring-forward
+// gap are skipped (`None`), and ambiguous times in the fall-back fold
+// resolve to the earlier of the two instants.
+fn resolve_local(tz: Tz, naive: chrono::NaiveDateTime) -> Option<DateTime<Tz>> {
+    match tz.from_local_datetime(&naive) {
+        LocalResult::Single(dt) => Some(dt),
+        LocalResult::Ambiguous(earlier, _later) => Some(earlier),
+        LocalResult::None => None,
+    }
+}
+
+// `cron::Schedule::after` steps in the timezone of whatever instant you
+// hand it, but minute-by-minute stepping over `Tz` wall-clock values
+// (rather than converting to/through UTC) is what keeps a "9am" schedule
+// firing at 9am local even as the UTC offset shifts across a DST
+// boundary. Bounded the same way the RRULE expander is, so a schedule
+// that can never fire again doesn't spin forever.
+const MAX_ZONE_SCAN_MINUTES: i64 = 5 * 365 * 24 * 60;
+
+fn get_upcoming_times_in_zone(schedule: &Schedule, after: DateTime<Tz>, count: usize) -> Vec<String> {
+    let tz = after.timezone();
+    let mut naive = after
+        .naive_local()
+        .with_second(0)
+        .unwrap()
+        .with_nanosecond(0)
+        .unwrap()
+        + Duration::minutes(1);
+
+    let mut found = Vec::new();
+    let mut scanned = 0;
+
+    while found.len() < count && scanned < MAX_ZONE_SCAN_MINUTES {
+        if let Some(candidate) = resolve_local(tz, naive) {
+            if schedule.includes(candidate) {
+                found.push(candidate.to_rfc3339());
+            }
+        }
+
+        naive += Duration::minutes(1);
+        scanned += 1;
+    }
+
+    found
+}
+
+fn get_previous_times_in_zone(schedule: &Schedule, before: DateTime<Tz>, count: usize) -> Vec<String> {
+    let tz = before.timezone();
+    let mut naive = before
+        .naive_local()
+        .with_second(0)
+        .unwrap()
+        .with_nanosecond(0)
+        .unwrap();
+
+    let mut found = Vec::new();
+    let mut scanned = 0;
+
+    while found.len() < count && scanned < MAX_ZONE_SCAN_MINUTES {
+        if let Some(candidate) = resolve_local(tz, naive) {
+            if candidate <= before && schedule.includes(candidate) {
+                found.push(candidate.to_rfc3339());
+            }
+        }
+
+        naive -= Duration::minutes(1);
+        scanned += 1;
+    }
+
+    found
+}
+
+fn execute_rrule(
+    rule: &str,
+    count: usize,
+    after: Option<&String>,
+) -> anyhow::Result<Option<Output>> {
+    let rule = parse_rrule(rule)?;
+
+    let after_utc = match after {
+        Some(time_str) => DateTime::parse_from_rfc3339(time_str)
+            .context("Invalid after time format. Use ISO 8601 format (e.g., 2024-01-01T00:00:00Z)")?
+            .with_timezone(&Utc),
+        None => Utc::now(),
+    };
+
+    let occurrences: Vec<String> = expand_rrule(&rule, after_utc, count)
+        .into_iter()
+        .map(|dt| dt.to_rfc3339())
+        .collect();
+
+    Ok(Some(Output::JsonValue(json!(occurrences))))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Freq {
+    Secondly,
+    Minutely,
+    Hourly,
+    Daily,
+    Weekly,
+    Monthly,
+    Yearly,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct ByDay {
+    ordinal: Option<i32>,
+    weekday: Weekday,
+}
+
+#[derive(Debug, Clone, Default)]
+struct RRule {
+    freq: Option<Freq>,
+    interval: u32,
+    count: Option<u32>,
+    until: Option<DateTime<Utc>>,
+    by_month: Vec<u32>,
+    by_monthday: Vec<i32>,
+    by_day: Vec<ByDay>,
+    by_hour: Vec<u32>,
+    by_minute: Vec<u32>,
+    by_second: Vec<u32>,
+}
+
+// Parses an RFC 5545 RRULE value string (with or without a leading
+// "RRULE:") into the fields this expander understands. Parts this tool
+// doesn't implement (WKST, BYSETPOS, BYWEEKNO, BYYEARDAY, ...) are
+// silently ignored rather than rejected, since they're far less common
+// than the FREQ/INTERVAL/COUNT/UNTIL/BY{MONTH,MONTHDAY,DAY,HOUR,MINUTE,SECOND}
+// subset implemented here.
+fn parse_rrule(input: &str) -> anyhow::Result<RRule> {
+    let input = input.strip_prefix("RRULE:").unwrap_or(input);
+    let mut rule = RRule {
+        interval: 1,
+        ..Default::default()
+    };
+
+    for part in input.split(';') {
+        let part = part.trim();
+        if part.is_empty() {
+            continue;
+        }
+
+        let (key, value) = part
+            .split_once('=')
+            .context("RRULE parts must be KEY=VALUE")?;
+
+        match key.to_uppercase().as_str() {
+            "FREQ" => rule.freq = Some(parse_freq(value)?),
+            "INTERVAL" => {
+                rule.interval = value.parse().context("INTERVAL must be a positive integer")?
+            }
+            "COUNT" => rule.count = Some(value.parse().context("COUNT must be a positive integer")?),
+            "UNTIL" => rule.until = Some(parse_until(value)?),
+            "BYMONTH" => rule.by_month = parse_int_list(value, "BYMONTH")?,
+            "BYMONTHDAY" => rule.by_monthday = parse_int_list(value, "BYMONTHDAY")?,
+            "BYDAY" => {
+                rule.by_day = value
+                    .split(',')
+                    .map(parse_byday)
+                    .collect::<anyhow::Result<_>>()?
+            }
+            "BYHOUR" => rule.by_hour = parse_int_list(value, "BYHOUR")?,
+            "BYMINUTE" => rule.by_minute = parse_int_list(value, "BYMINUTE")?,
+            "BYSECOND" => rule.by_second = parse_int_list(value, "BYSECOND")?,
+            _ => {}
+        }
+    }
+
+    if rule.freq.is_none() {
+        bail!("RRULE must include FREQ");
+    }
+    if rule.interval == 0 {
+        bail!("INTERVAL must be a positive integer");
+    }
+
+    Ok(rule)
+}
+
+fn parse_int_list<T: FromStr>(value: &str, field: &str) -> anyhow::Result<Vec<T>> {
+    value
+        .split(',')
+        .map(|v| {
+            v.trim()
+                .parse()
+                .map_err(|_| anyhow::anyhow!("{field} values must be integers"))
+        })
+        .collect()
+}
+
+fn parse_freq(value: &str) -> anyhow::Result<Freq> {
+    match value.to_uppercase().as_str() {
+        "SECONDLY" => Ok(Freq::Secondly),
+        "MINUTELY" => Ok(Freq::Minutely),
+        "HOURLY" => Ok(Freq::Hourly),
+        "DAILY" => Ok(Freq::Daily),
+        "WEEKLY" => Ok(Freq::Weekly),
+        "MONTHLY" => Ok(Freq::Monthly),
+        "YEARLY" => Ok(Freq::Yearly),
+        other => bail!("Unsupported FREQ: {other}"),
+    }
+}
+
+fn parse_until(value: &str) -> anyhow::Result<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Ok(dt.with_timezone(&Utc));
+    }
+    if let Ok(naive) = chrono::NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%SZ") {
+        return Ok(naive.and_utc());
+    }
+    if let Ok(date) = NaiveDate::parse_from_str(value, "%Y%m%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap().and_utc());
+    }
+    bail!("Invalid UNTIL value: {value}")
+}
+
+// BYDAY tokens are an optional signed ordinal followed by a two-letter
+// weekday code, e.g. "MO", "-1MO" (last Monday), "2TU" (second Tuesday).
+fn parse_byday(token: &str) -> anyhow::Result<ByDay> {
+    let token = token.trim();
+    if token.len() < 2 {
+        bail!("Invalid BYDAY token: {token}");
+    }
+
+    let (ordinal_part, day_part) = token.split_at(token.len() - 2);
+    let weekday = match day_part.to_uppercase().as_str() {
+        "MO" => Weekday::Mon,
+        "TU" => Weekday::Tue,
+        "WE" => Weekday::Wed,
+        "TH" => Weekday::Thu,
+        "FR" => Weekday::Fri,
+        "SA" => Weekday::Sat,
+        "SU" => Weekday::Sun,
+        other => bail!("Unknown BYDAY weekday: {other}"),
+    };
+
+    let ordinal = if ordinal_part.is_empty() {
+        None
+    } else {
+        Some(
+            ordinal_part
+                .parse()
+                .context("Invalid BYDAY ordinal")?,
+        )
+    };
+
+    Ok(ByDay { ordinal, weekday })
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    let next_month_start = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)
+    }
+    .unwrap();
+
+    (next_month_start - NaiveDate::from_ymd_opt(year, month, 1).unwrap()).num_days() as u32
+}
+
+fn add_months(date: NaiveDate, months: i32) -> NaiveDate {
+    let total = date.year() * 12 + date.month0() as i32 + months;
+    let year = total.div_euclid(12);
+    let month0 = total.rem_euclid(12);
+    NaiveDate::from_ymd_opt(year, month0 as u32 + 1, 1).unwrap()
+}
+
+// Resolves a BYDAY token to the day(s)-of-month in `month_start`'s month
+// matching its weekday, narrowed to a single ordinal occurrence (from the
+// front for positive ordinals, from the end for negative ones) when given.
+fn resolve_byday_in_month(token: &ByDay, month_start: NaiveDate, days_in_month: u32) -> Vec<u32> {
+    let matches: Vec<u32> = (1..=days_in_month)
+        .filter(|&day| {
+            NaiveDate::from_ymd_opt(month_start.year(), month_start.month(), day)
+                .unwrap()
+                .weekday()
+                == token.weekday
+        })
+        .collect();
+
+    match token.ordinal {
+        None => matches,
+        Some(n) if n > 0 => matches.get((n - 1) as usize).copied().into_iter().collect(),
+        Some(n) => {
+            let index = matches.len() as i32 + n;
+            if index >= 0 {
+                matches.get(index as usize).copied().into_iter().collect()
+            } else {
+                Vec::new()
+            }
+        }
+    }
+}
+
+// Cartesian product of BYHOUR/BYMINUTE/BYSECOND (or the seed's own
+// hour/minute/second when a field has no BY* override).
+fn candidate_times(rule: &RRule, seed_time: NaiveTime) -> Vec<NaiveTime> {
+    let hours = if rule.by_hour.is_empty() {
+        vec![seed_time.hour()]
+    } else {
+        rule.by_hour.clone()
+    };
+    let minutes = if rule.by_minute.is_empty() {
+        vec![seed_time.minute()]
+    } else {
+        rule.by_minute.clone()
+    };
+    let seconds = if rule.by_second.is_empty() {
+        vec![seed_time.second()]
+    } else {
+        rule.by_second.clone()
+    };
+
+    hours
+        .iter()
+        .flat_map(|&h| minutes.iter().map(move |&m| (h, m)))
+        .flat_map(|(h, m)| seconds.iter().map(move |&s| (h, m, s)))
+        .filter_map(|(h, m, s)| NaiveTime::from_hms_opt(h, m, s))
+        .collect()
+}
+
+// All occurrences within a single month, applying BYMONTH/BYMONTHDAY/BYDAY
+// in RFC order and falling back to the seed's own day-of-month when
+// neither is given.
+fn candidates_for_month(
+    rule: &RRule,
+    month_start: NaiveDate,
+    seed_day: u32,
+    seed_time: NaiveTime,
+) -> Vec<DateTime<Utc>> {
+    if !rule.by_month.is_empty() && !rule.by_month.contains(&month_start.month()) {
+        return Vec::new();
+    }
+
+    let days_in_month = days_in_month(month_start.year(), month_start.month());
+    let mut days: Vec<u32> = Vec::new();
+
+    for &md in &rule.by_monthday {
+        let day = resolve_monthday(md, days_in_month);
+        if day >= 1 && day <= days_in_month {
+            days.push(day);
+        }
+    }
+
+    for token in &rule.by_day {
+        days.extend(resolve_byday_in_month(token, month_start, days_in_month));
+    }
+
+    if rule.by_monthday.is_empty() && rule.by_day.is_empty() && seed_day <= days_in_month {
+        days.push(seed_day);
+    }
+
+    days.sort_unstable();
+    days.dedup();
+
+    let times = candidate_times(rule, seed_time);
+
+    let mut result: Vec<DateTime<Utc>> = days
+        .into_iter()
+        .flat_map(|day| {
+            let date = NaiveDate::from_ymd_opt(month_start.year(), month_start.month(), day).unwrap();
+            times.iter().map(move |&time| date.and_time(time).and_utc())
+        })
+        .collect();
+
+    result.sort();
+    result
+}
+
+fn candidates_for_year(
+    rule: &RRule,
+    year: i32,
+    seed_month: u32,
+    seed_day: u32,
+    seed_time: NaiveTime,
+) -> Vec<DateTime<Utc>> {
+    let months: Vec<u32> = if rule.by_month.is_empty() {
+        vec![seed_month]
+    } else {
+        rule.by_month.clone()
+    };
+
+    let mut result: Vec<DateTime<Utc>> = months
+        .into_iter()
+        .filter(|&m| (1..=12).contains(&m))
+        .flat_map(|month| {
+            let month_start = NaiveDate::from_ymd_opt(year, month, 1).unwrap();
+            candidates_for_month(rule, month_start, seed_day, seed_time)
+        })
+        .collect();
+
+    result.sort();
+    result
+}
+
+fn passes_time_filters(rule: &RRule, candidate: DateTime<Utc>) -> bool {
+    (rule.by_hour.is_empty() || rule.by_hour.contains(&candidate.hour()))
+        && (rule.by_minute.is_empty() || rule.by_minute.contains(&candidate.minute()))
+        && (rule.by_second.is_empty() || rule.by_second.contains(&candidate.second()))
+}
+
+fn passes_day_filter(rule: &RRule, candidate: DateTime<Utc>) -> bool {
+    rule.by_day.is_empty() || rule.by_day.iter().any(|d| d.weekday == candidate.weekday())
+}
+
+// BYMONTH for the sub-daily/daily frequencies, which (unlike Monthly/Yearly)
+// step straight through instants rather than enumerating a month at a time.
+fn passes_month_filter(rule: &RRule, candidate: DateTime<Utc>) -> bool {
+    rule.by_month.is_empty() || rule.by_month.contains(&candidate.month())
+}
+
+// BYMONTHDAY counterpart to `passes_month_filter`, resolving negative
+// (from-the-end) days the same way `candidates_for_month` does.
+fn passes_monthday_filter(rule: &RRule, candidate: DateTime<Utc>) -> bool {
+    if rule.by_monthday.is_empty() {
+        return true;
+    }
+
+    let days_in_month = days_in_month(candidate.year(), candidate.month());
+    rule.by_monthday
+        .iter()
+        .any(|&md| resolve_monthday(md, days_in_month) == candidate.day())
+}
+
+// Resolves a BYMONTHDAY value to a 1-based day-of-month, treating negative
+// values as counting back from the end of the month (-1 is the last day).
+fn resolve_monthday(md: i32, days_in_month: u32) -> u32 {
+    if md > 0 {
+        md as u32
+    } else {
+        (days_in_month as i32 + md + 1).max(0) as u32
+    }
+}
+
+// Pushes `candidate` onto `results` (already known to be after the
+// reference instant), returning true once the scan should stop: the
+// UNTIL boundary was passed, the requested `limit` was reached, or the
+// RRULE's own COUNT was satisfied.
+fn accept_candidate(
+    rule: &RRule,
+    results: &mut Vec<DateTime<Utc>>,
+    limit: usize,
+    candidate: DateTime<Utc>,
+) -> bool {
+    if let Some(until) = rule.until {
+        if candidate > until {
+            return true;
+        }
+    }
+
+    results.push(candidate);
+
+    if results.len() >= limit {
+        return true;
+    }
+    if let Some(count) = rule.count {
+        if results.len() >= count as usize {
+            return true;
+        }
+    }
+
+    false
+}
+
+// Bounded forward scan guarding against rules that can never produce
+// another occurrence (e.g. BYMONTHDAY=31 landing on every February).
+const MAX_RRULE_PERIODS: usize = 20_000;
+
+fn expand_rrule(rule: &RRule, after: DateTime<Utc>, limit: usize) -> Vec<DateTime<Utc>> {
+    let mut results = Vec::new();
+    let seed_time = after.time();
+
+    let freq = rule.freq.expect("FREQ is required by parse_rrule");
+    match freq {
+        Freq::Secondly | Freq::Minutely | Freq::Hourly | Freq::Daily => {
+            let step = match freq {
+                Freq::Secondly => Duration::seconds(rule.interval as i64),
+                Freq::Minutely => Duration::minutes(rule.interval as i64),
+                Freq::Hourly => Duration::hours(rule.interval as i64),
+                Freq::Daily => Duration::days(rule.interval as i64),
+                _ => unreachable!(),
+            };
+
+            let mut candidate = after;
+            for _ in 0..MAX_RRULE_PERIODS {
+                candidate += step;
+                if !passes_month_filter(rule, candidate)
+                    || !passes_monthday_filter(rule, candidate)
+                    || !passes_day_filter(rule, candidate)
+                    || !passes_time_filters(rule, candidate)
+                {
+                    continue;
+                }
+                if accept_candidate(rule, &mut results, limit, candidate) {
+                    break;
+                }
+            }
+        }
+        Freq::Weekly => {
+            let mut period_start =
+                after.date_naive() - Duration::days(after.weekday().num_days_from_monday() as i64);
+            let weekdays: Vec<Weekday> = if rule.by_day.is_empty() {
+                vec![after.weekday()]
+            } else {
+                rule.by_day.iter().map(|d| d.weekday).collect()
+            };
+            let times = candidate_times(rule, seed_time);
+
+            'weekly: for _ in 0..MAX_RRULE_PERIODS {
+                let mut period_candidates: Vec<DateTime<Utc>> = weekdays
+                    .iter()
+                    .flat_map(|wd| {
+                        let offset = wd.num_days_from_monday() as i64
+                            - period_start.weekday().num_days_from_monday() as i64;
+                        let date = period_start + Duration::days(offset);
+                        times.iter().map(move |&time| date.and_time(time).and_utc())
+                    })
+                    .collect();
+                period_candidates.sort();
+
+                for candidate in period_candidates {
+                    if candidate <= after {
+                        continue;
+                    }
+                    if !passes_month_filter(rule, candidate)
+                        || !passes_monthday_filter(rule, candidate)
+                    {
+                        continue;
+                    }
+                    if accept_candidate(rule, &mut results, limit, candidate) {
+                        break 'weekly;
+                    }
+                }
+
+                period_start += Duration::weeks(rule.interval as i64);
+            }
+        }
+        Freq::Monthly => {
+            let mut month_start = NaiveDate::from_ymd_opt(after.year(), after.month(), 1).unwrap();
+
+            'monthly: for _ in 0..MAX_RRULE_PERIODS {
+                let candidates = candidates_for_month(rule, month_start, after.day(), seed_time);
+                for candidate in candidates {
+                    if candidate <= after {
+                        continue;
+                    }
+                    if accept_candidate(rule, &mut results, limit, candidate) {
+                        break 'monthly;
+                    }
+                }
+
+                month_start = add_months(month_start, rule.interval as i32);
+            }
+        }
+        Freq::Yearly => {
+            let mut year = after.year();
+
+            'yearly: for _ in 0..MAX_RRULE_PERIODS {
+                let candidates =
+                    candidates_for_year(rule, year, after.month(), after.day(), seed_time);
+                for candidate in candidates {
+                    if candidate <= after {
+                        continue;
+                    }
+                    if accept_candidate(rule, &mut results, limit, candidate) {
+                        break 'yearly;
+                    }
+                }
+
+                year += rule.interval as i32;
+            }
+        }
+    }
+
+    results
+}
+
+// A parsed crontab field: a single value, a range, a step over a base
+// (wildcard or range), or a comma-separated list of any of the above.
+// This is intentionally simpler than `cron::Schedule`'s own field model
+// (no named months/weekdays, no "L"/"W"/"#" extensions) since it only
+// needs to drive plain-English rendering, not scheduling.
+#[derive(Debug, Clone)]
+enum FieldValue {
+    Wildcard,
+    Single(i64),
+    Range(i64, i64),
+    Step { base: Box<FieldValue>, step: i64 },
+    List(Vec<FieldValue>),
+}
+
+fn parse_field(raw: &str) -> anyhow::Result<FieldValue> {
+    let items: Vec<&str> = raw.split(',').collect();
+
+    if items.len() > 1 {
+        let parsed = items
+            .iter()
+            .map(|item| parse_field_item(item))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+        return Ok(FieldValue::List(parsed));
+    }
+
+    parse_field_item(raw)
+}
+
+fn parse_field_item(raw: &str) -> anyhow::Result<FieldValue> {
+    if let Some((base, step)) = raw.split_once('/') {
+        let step: i64 = step
+            .parse()
+            .with_context(|| format!("Invalid step value in cron field: {raw}"))?;
+
+        return Ok(FieldValue::Step {
+            base: Box::new(parse_field_base(base)?),
+            step,
+        });
+    }
+
+    parse_field_base(raw)
+}
+
+fn parse_field_base(raw: &str) -> anyhow::Result<FieldValue> {
+    if raw == "*" {
+        return Ok(FieldValue::Wildcard);
+    }
+
+    if let Some((start, end)) = raw.split_once('-') {
+        let start: i64 = start
+            .parse()
+            .with_context(|| format!("Invalid range start in cron field: {raw}"))?;
+        let end: i64 = end
+            .parse()
+            .with_context(|| format!("Invalid range end in cron field: {raw}"))?;
+        return Ok(FieldValue::Range(start, end));
+    }
+
+    let value: i64 = raw
+        .parse()
+        .with_context(|| format!("Invalid value in cron field: {raw}"))?;
+    Ok(FieldValue::Single(value))
+}
+
+fn weekday_name(day: i64) -> String {
+    const NAMES: [&str; 7] = [
+        "Sunday",
+        "Monday",
+        "Tuesday",
+        "Wednesday",
+        "Thursday",
+        "Friday",
+        "Saturday",
+    ];
+    NAMES[(day.rem_euclid(7)) as usize].to_string()
+}
+
+fn month_name(month: i64) -> String {
+    const NAMES: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+    NAMES[((month - 1).rem_euclid(12)) as usize].to_string()
+}
+
+fn join_with_and(items: Vec<String>) -> String {
+    match items.len() {
+        0 => String::new(),
+        1 => items[0].clone(),
+        2 => format!("{} and {}", items[0], items[1]),
+        _ => {
+            let (last, rest) = items.split_last().unwrap();
+            format!("{}, and {}", rest.join(", "), last)
+        }
+    }
+}
+
+// Renders a field's value using `namer` for individual numbers (plain
+// digits for minute/hour/day-of-month, weekday/month names otherwise).
+fn describe_field(field: &FieldValue, namer: &dyn Fn(i64) -> String, noun: &str) -> String {
+    match field {
+        FieldValue::Wildcard => format!("every {noun}"),
+        FieldValue::Single(value) => namer(*value),
+        FieldValue::Range(start, end) => format!("{} through {}", namer(*start), namer(*end)),
+        FieldValue::Step { base, step } => match base.as_ref() {
+            FieldValue::Range(start, end) => format!(
+                "every {step} {noun}s from {} through {}",
+                namer(*start),
+                namer(*end)
+            ),
+            _ => format!("every {step} {noun}s"),
+        },
+        FieldValue::List(items) => join_with_and(
+            items
+                .iter()
+                .map(|item| describe_field(item, namer, noun))
+                .collect(),
+        ),
+    }
+}
+
+fn describe_seconds_suffix(second: &FieldValue) -> String {
+    match second {
+        FieldValue::Single(0) => String::new(),
+        FieldValue::Wildcard => " and every second".to_string(),
+        FieldValue::Step { base, step } if matches!(base.as_ref(), FieldValue::Wildcard) => {
+            format!(" and every {step} seconds")
+        }
+        other => format!(
+            " at second {}",
+            describe_field(other, &|v| v.to_string(), "second")
+        ),
+    }
+}
+
+fn describe_time(second: &FieldValue, minute: &FieldValue, hour: &FieldValue) -> String {
+    let seconds_suffix = describe_seconds_suffix(second);
+
+    match (minute, hour) {
+        (FieldValue::Wildcard, FieldValue::Wildcard) => format!("every minute{seconds_suffix}"),
+        (FieldValue::Single(0), FieldValue::Wildcard) => {
+            format!("every hour, on the hour{seconds_suffix}")
+        }
+        (FieldValue::Step { base, step }, FieldValue::Wildcard)
+            if matches!(base.as_ref(), FieldValue::Wildcard) =>
+        {
+            format!("every {step} minutes{seconds_suffix}")
+        }
+        (FieldValue::Single(minute), FieldValue::Single(hour)) => {
+            format!("At {hour:02}:{minute:02}{seconds_suffix}")
+        }
+        _ => format!(
+            "At minute {} past hour {}{seconds_suffix}",
+            describe_field(minute, &|v| v.to_string(), "minute"),
+            describe_field(hour, &|v| v.to_string(), "hour")
+        ),
+    }
+}
+
+fn describe_day_of_week(field: &FieldValue) -> Option<String> {
+    match field {
+        FieldValue::Wildcard => None,
+        other => Some(describe_field(other, &weekday_name, "day")),
+    }
+}
+
+fn describe_date(day_of_month: &FieldValue, month: &FieldValue) -> Option<String> {
+    let day_clause = match day_of_month {
+        FieldValue::Wildcard => None,
+        other => Some(format!(
+            "on day {}",
+            describe_field(other, &|v| v.to_string(), "day")
+        )),
+    };
+
+    let month_clause = match month {
+        FieldValue::Wildcard => None,
+        other => Some(format!("in {}", describe_field(other, &month_name, "month"))),
+    };
+
+    match (day_clause, month_clause) {
+        (None, None) => None,
+        (Some(day), None) => Some(day),
+        (None, Some(month)) => Some(month),
+        (Some(day), Some(month)) => Some(format!("{day} {month}")),
+    }
+}
+
+fn lowercase_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_lowercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+fn capitalize_first(text: &str) -> String {
+    let mut chars = text.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
+// Turns a crontab expression into a plain-English sentence without
+// computing any firing times, the inverse of `execute_schedule`. Reuses
+// the same 5→6 field normalization so both subcommands accept the same
+// inputs.
+fn describe_cron(expression: &str) -> anyhow::Result<String> {
+    let expanded = match expression.split_whitespace().count() {
+        5 => extend_to_six_fields(expression),
+        _ => expression.to_string(),
+    };
+
+    let fields: Vec<&str> = expanded.split_whitespace().collect();
+    if fields.len() != 6 {
+        bail!("Invalid crontab expression. Use format like '0 9 * * 1-5' or '0 0 9 * * 1-5'");
+    }
+
+    let second = parse_field(fields[0])?;
+    let minute = parse_field(fields[1])?;
+    let hour = parse_field(fields[2])?;
+    let day_of_month = parse_field(fields[3])?;
+    let month = parse_field(fields[4])?;
+    let day_of_week = parse_field(fields[5])?;
+
+    let time_clause = describe_time(&second, &minute, &hour);
+    let dow_clause = describe_day_of_week(&day_of_week);
+    let date_clause = describe_date(&day_of_month, &month);
+
+    let mut clauses = vec![time_clause.clone()];
+    if dow_clause.is_none() && date_clause.is_none() && time_clause.starts_with("At ") {
+        clauses[0] = format!("Daily {}", lowercase_first(&time_clause));
+    }
+    clauses.extend(dow_clause);
+    clauses.extend(date_clause);
+
+    Ok(capitalize_first(&clauses.join(", ")))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -114,6 +1076,8 @@ mod tests {
                 expression: StringInput("0 9 * * 1-5".to_string()),
                 count: 3,
                 after: Some("2024-01-01T00:00:00Z".to_string()),
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -138,6 +1102,8 @@ mod tests {
                 expression: StringInput("0 0 * * *".to_string()),
                 count: 2,
                 after: Some("2024-01-01T00:00:00Z".to_string()),
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -161,6 +1127,8 @@ mod tests {
                 expression: StringInput("0 * * * *".to_string()),
                 count: 5,
                 after: Some("2024-01-01T00:00:00Z".to_string()),
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -187,6 +1155,8 @@ mod tests {
                 expression: StringInput("0 9 * * 1-5".to_string()),
                 count: 2,
                 after: Some("2024-03-15T10:00:00Z".to_string()),
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -210,6 +1180,8 @@ mod tests {
                 expression: StringInput("invalid".to_string()),
                 count: 5,
                 after: None,
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute();
@@ -224,6 +1196,8 @@ mod tests {
                 expression: StringInput("0 9 * * 1-5".to_string()),
                 count: 5,
                 after: Some("invalid-time".to_string()),
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute();
@@ -238,6 +1212,8 @@ mod tests {
                 expression: StringInput("0 9 * * 1-5".to_string()),
                 count: 2,
                 after: Some("2024-01-01T00:00:00+05:30".to_string()),
+                before: None,
+            timezone: None,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -253,4 +1229,369 @@ mod tests {
         assert_eq!(arr[0].as_str().unwrap(), "2024-01-01T14:30:00+05:30");
         assert_eq!(arr[1].as_str().unwrap(), "2024-01-02T14:30:00+05:30");
     }
+
+    #[test]
+    fn test_before_returns_previous_fires_in_descending_order() {
+        let tool = CrontabTool {
+            command: CrontabCommand::Schedule {
+                expression: StringInput("0 9 * * 1-5".to_string()),
+                count: 3,
+                after: None,
+                before: Some("2024-01-10T00:00:00Z".to_string()),
+                timezone: None,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 3);
+
+        // 2024-01-10 is a Wednesday, so the previous weekday firings walk
+        // back over the weekend: Tue 9th, Mon 8th, then Fri 5th.
+        assert_eq!(arr[0].as_str().unwrap(), "2024-01-09T09:00:00+00:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-01-08T09:00:00+00:00");
+        assert_eq!(arr[2].as_str().unwrap(), "2024-01-05T09:00:00+00:00");
+    }
+
+    #[test]
+    fn test_before_preserves_timezone_offset() {
+        let tool = CrontabTool {
+            command: CrontabCommand::Schedule {
+                expression: StringInput("0 9 * * 1-5".to_string()),
+                count: 1,
+                after: None,
+                before: Some("2024-01-10T00:00:00+05:30".to_string()),
+                timezone: None,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-01-09T14:30:00+05:30");
+    }
+
+    #[test]
+    fn test_after_and_before_conflict() {
+        let cli = CrontabTool::cli();
+        let result = cli.try_get_matches_from(vec![
+            "crontab",
+            "schedule",
+            "0 9 * * 1-5",
+            "--after",
+            "2024-01-01T00:00:00Z",
+            "--before",
+            "2024-01-10T00:00:00Z",
+        ]);
+
+        assert!(result.is_err());
+    }
+
+    fn schedule_tool(
+        expression: &str,
+        count: usize,
+        after: Option<&str>,
+        before: Option<&str>,
+        timezone: Option<&str>,
+    ) -> CrontabTool {
+        CrontabTool {
+            command: CrontabCommand::Schedule {
+                expression: StringInput(expression.to_string()),
+                count,
+                after: after.map(str::to_string),
+                before: before.map(str::to_string),
+                timezone: timezone.map(str::to_string),
+            },
+        }
+    }
+
+    #[test]
+    fn test_timezone_option_tracks_dst_offset_change() {
+        // Daylight saving in America/New_York starts 2024-03-10, so the
+        // third 9am firing crosses from EST (-05:00) into EDT (-04:00)
+        // while staying at 9am local time both days.
+        let tool = schedule_tool(
+            "0 9 * * *",
+            3,
+            Some("2024-03-08T00:00:00-05:00"),
+            None,
+            Some("America/New_York"),
+        );
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 3);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-03-08T09:00:00-05:00");
+        assert_eq!(arr[1].as_str().unwrap(), "2024-03-09T09:00:00-05:00");
+        assert_eq!(arr[2].as_str().unwrap(), "2024-03-10T09:00:00-04:00");
+    }
+
+    #[test]
+    fn test_timezone_option_skips_nonexistent_spring_forward_time() {
+        // On 2024-03-10 in America/New_York, clocks jump from 02:00 to
+        // 03:00, so 02:30 never happens and that day's firing is skipped.
+        let tool = schedule_tool(
+            "30 2 * * *",
+            1,
+            Some("2024-03-09T03:00:00-05:00"),
+            None,
+            Some("America/New_York"),
+        );
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-03-11T02:30:00-04:00");
+    }
+
+    #[test]
+    fn test_timezone_option_resolves_ambiguous_fall_back_time_to_earlier_instant() {
+        // On 2024-11-03 in America/New_York, 01:30 happens twice as clocks
+        // fall back; the earlier (still-EDT) instant should be reported.
+        let tool = schedule_tool(
+            "30 1 * * *",
+            1,
+            None,
+            Some("2024-11-04T00:00:00-05:00"),
+            Some("America/New_York"),
+        );
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+
+        let arr = val.as_array().unwrap();
+        assert_eq!(arr.len(), 1);
+        assert_eq!(arr[0].as_str().unwrap(), "2024-11-03T01:30:00-04:00");
+    }
+
+    #[test]
+    fn test_timezone_option_rejects_unknown_zone_name() {
+        let tool = schedule_tool(
+            "0 9 * * *",
+            1,
+            None,
+            None,
+            Some("Not/A_Zone"),
+        );
+
+        assert!(tool.execute().is_err());
+    }
+
+    fn rrule_tool(rule: &str, count: usize, after: &str) -> CrontabTool {
+        CrontabTool {
+            command: CrontabCommand::Rrule {
+                rule: StringInput(rule.to_string()),
+                count,
+                after: Some(after.to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_rrule_last_monday_of_every_other_month() {
+        let tool = rrule_tool(
+            "FREQ=MONTHLY;BYDAY=-1MO;INTERVAL=2;COUNT=5",
+            5,
+            "2024-01-01T00:00:00Z",
+        );
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let arr = val.as_array().unwrap();
+
+        // COUNT=5 caps the occurrences even though more were requested.
+        assert_eq!(arr.len(), 5);
+        // Last Monday of Jan 2024 is the 29th.
+        assert!(arr[0].as_str().unwrap().starts_with("2024-01-29"));
+        // Every other month: next is March.
+        assert!(arr[1].as_str().unwrap().starts_with("2024-03-25"));
+    }
+
+    #[test]
+    fn test_rrule_second_tuesday_of_every_month() {
+        let tool = rrule_tool("FREQ=MONTHLY;BYDAY=2TU", 2, "2024-01-01T00:00:00Z");
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let arr = val.as_array().unwrap();
+
+        assert_eq!(arr.len(), 2);
+        // Second Tuesday of Jan 2024 is the 9th.
+        assert!(arr[0].as_str().unwrap().starts_with("2024-01-09"));
+        // Second Tuesday of Feb 2024 is the 13th.
+        assert!(arr[1].as_str().unwrap().starts_with("2024-02-13"));
+    }
+
+    #[test]
+    fn test_rrule_until_bounds_occurrences() {
+        let tool = CrontabTool {
+            command: CrontabCommand::Rrule {
+                rule: StringInput("FREQ=DAILY;UNTIL=20240103T000000Z".to_string()),
+                count: 100,
+                after: Some("2024-01-01T00:00:00Z".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let arr = val.as_array().unwrap();
+
+        // Daily from Jan 1, stopping at the Jan 3 UNTIL boundary: Jan 2, Jan 3.
+        assert_eq!(arr.len(), 2);
+    }
+
+    #[test]
+    fn test_rrule_missing_freq_is_invalid() {
+        let tool = rrule_tool("INTERVAL=2", 5, "2024-01-01T00:00:00Z");
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_rrule_bymonthday_skips_invalid_month_lengths() {
+        // BYMONTHDAY=31 should only fire in months that actually have 31 days.
+        let tool = rrule_tool("FREQ=MONTHLY;BYMONTHDAY=31", 2, "2024-01-01T00:00:00Z");
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let arr = val.as_array().unwrap();
+
+        assert_eq!(arr.len(), 2);
+        assert!(arr[0].as_str().unwrap().starts_with("2024-01-31"));
+        // February has no 31st, so the next hit is March.
+        assert!(arr[1].as_str().unwrap().starts_with("2024-03-31"));
+    }
+
+    #[test]
+    fn test_rrule_daily_honors_bymonth() {
+        // FREQ=DAILY;BYMONTH=1 should only fire in January, not every day
+        // of the year.
+        let tool = rrule_tool("FREQ=DAILY;BYMONTH=1", 3, "2024-01-30T00:00:00Z");
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let arr = val.as_array().unwrap();
+
+        assert_eq!(arr.len(), 3);
+        assert!(arr[0].as_str().unwrap().starts_with("2024-01-31"));
+        // February is skipped; the next January is a year away.
+        assert!(arr[1].as_str().unwrap().starts_with("2025-01-01"));
+        assert!(arr[2].as_str().unwrap().starts_with("2025-01-02"));
+    }
+
+    #[test]
+    fn test_rrule_weekly_honors_bymonth() {
+        // FREQ=WEEKLY;BYMONTH=1;BYDAY=MO should only fire on Mondays in
+        // January, not every Monday of the year.
+        let tool = rrule_tool("FREQ=WEEKLY;BYMONTH=1;BYDAY=MO", 3, "2024-01-01T00:00:00Z");
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let arr = val.as_array().unwrap();
+
+        assert_eq!(arr.len(), 3);
+        assert!(arr[0].as_str().unwrap().starts_with("2024-01-08"));
+        assert!(arr[1].as_str().unwrap().starts_with("2024-01-15"));
+        assert!(arr[2].as_str().unwrap().starts_with("2024-01-22"));
+    }
+
+    fn describe_tool(expression: &str) -> CrontabTool {
+        CrontabTool {
+            command: CrontabCommand::Describe {
+                expression: StringInput(expression.to_string()),
+            },
+        }
+    }
+
+    #[test]
+    fn test_describe_weekdays_at_fixed_time() {
+        let tool = describe_tool("0 9 * * 1-5");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "At 09:00, Monday through Friday");
+    }
+
+    #[test]
+    fn test_describe_every_minute() {
+        let tool = describe_tool("* * * * *");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "Every minute");
+    }
+
+    #[test]
+    fn test_describe_hourly() {
+        let tool = describe_tool("0 * * * *");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "Every hour, on the hour");
+    }
+
+    #[test]
+    fn test_describe_daily_at_time() {
+        let tool = describe_tool("30 14 * * *");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "Daily at 14:30");
+    }
+
+    #[test]
+    fn test_describe_every_15_minutes() {
+        let tool = describe_tool("*/15 * * * *");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "Every 15 minutes");
+    }
+
+    #[test]
+    fn test_describe_accepts_six_field_expression_with_seconds() {
+        let tool = describe_tool("30 0 9 * * 1-5");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "At 09:00 at second 30, Monday through Friday");
+    }
+
+    #[test]
+    fn test_describe_month_and_day_of_month() {
+        let tool = describe_tool("0 0 1 1 *");
+        let Output::Text(text) = tool.execute().unwrap().unwrap() else {
+            unreachable!()
+        };
+        assert_eq!(text, "At 00:00, on day 1 in January");
+    }
+
+    #[test]
+    fn test_describe_rejects_malformed_expression() {
+        let tool = describe_tool("not a cron expression");
+        assert!(tool.execute().is_err());
+    }
 }
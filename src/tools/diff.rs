@@ -1,8 +1,8 @@
 use crate::tool::{Output, Tool};
 use anyhow::{Context, Result};
-use clap::{Command, CommandFactory, Parser};
+use clap::{Command, CommandFactory, Parser, ValueEnum};
 use colored::Colorize;
-use similar::{ChangeTag, TextDiff};
+use similar::{Algorithm, ChangeTag, TextDiff};
 use std::fs;
 use std::path::{Path, PathBuf};
 
@@ -14,6 +14,55 @@ pub struct DiffTool {
 
     /// Second version of the file, omit to use editor
     b: Option<PathBuf>,
+
+    /// Output format: a colorized inline view, or a unified patch suitable
+    /// for `patch`/`git apply`
+    #[arg(long, value_enum, default_value = "inline")]
+    format: DiffFormat,
+
+    /// With --format unified, number of context lines around each change
+    #[arg(long, default_value_t = 3)]
+    context: usize,
+
+    /// Diffing algorithm to use. Patience anchors on unique lines and tends
+    /// to read better on code; LCS is the classic minimal-edit algorithm
+    #[arg(long, value_enum, default_value = "myers")]
+    algorithm: DiffAlgorithm,
+
+    /// Unit to diff by. Finer granularities highlight smaller changes but
+    /// get noisy on large inputs; --format unified always diffs by line
+    #[arg(long, value_enum, default_value = "char")]
+    granularity: Granularity,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DiffFormat {
+    Inline,
+    Unified,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum DiffAlgorithm {
+    Myers,
+    Patience,
+    Lcs,
+}
+
+impl From<DiffAlgorithm> for Algorithm {
+    fn from(algorithm: DiffAlgorithm) -> Self {
+        match algorithm {
+            DiffAlgorithm::Myers => Algorithm::Myers,
+            DiffAlgorithm::Patience => Algorithm::Patience,
+            DiffAlgorithm::Lcs => Algorithm::Lcs,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Granularity {
+    Char,
+    Word,
+    Line,
 }
 
 impl Tool for DiffTool {
@@ -32,6 +81,30 @@ impl Tool for DiffTool {
             None => get_content_from_editor("# b")?,
         };
 
+        if let DiffFormat::Unified = self.format {
+            let label_a = self
+                .a
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "a".to_string());
+            let label_b = self
+                .b
+                .as_ref()
+                .map(|p| p.display().to_string())
+                .unwrap_or_else(|| "b".to_string());
+
+            let text_diff = TextDiff::configure()
+                .algorithm(self.algorithm.into())
+                .diff_lines(&first_content, &second_content);
+            let patch = text_diff
+                .unified_diff()
+                .context_radius(self.context)
+                .header(&label_a, &label_b)
+                .to_string();
+
+            return Ok(Some(Output::Text(patch)));
+        }
+
         let line_no_width = (first_content
             .lines()
             .count()
@@ -48,72 +121,87 @@ impl Tool for DiffTool {
         let mut n_line_no: u64 = 0;
         let mut o_n_line_no = n_line_no;
 
-        for change in TextDiff::from_chars(&first_content, &second_content).iter_all_changes() {
-            let Some(ch) = change.as_str() else {
+        let mut configured = TextDiff::configure();
+        configured.algorithm(self.algorithm.into());
+        let text_diff = match self.granularity {
+            Granularity::Char => configured.diff_chars(&first_content, &second_content),
+            Granularity::Word => configured.diff_words(&first_content, &second_content),
+            Granularity::Line => configured.diff_lines(&first_content, &second_content),
+        };
+
+        for change in text_diff.iter_all_changes() {
+            let Some(text) = change.as_str() else {
                 continue;
             };
 
-            // Handle line breaks so we can keep track of line numbers.
-            if ch == "\n" {
-                o_o_line_no = o_line_no;
-                o_n_line_no = n_line_no;
+            // A single change can span several characters (a word or a
+            // whole line), so walk it one character at a time to keep the
+            // line-number gutter correct regardless of granularity.
+            for ch in text.chars() {
+                let ch = ch.to_string();
 
-                let push: bool;
-                match change.tag() {
-                    ChangeTag::Equal => {
-                        push = true;
+                // Handle line breaks so we can keep track of line numbers.
+                if ch == "\n" {
+                    o_o_line_no = o_line_no;
+                    o_n_line_no = n_line_no;
 
-                        buffer.push_str(&ch);
+                    let push: bool;
+                    match change.tag() {
+                        ChangeTag::Equal => {
+                            push = true;
 
-                        o_line_no += 1;
-                        n_line_no += 1;
-                    }
-                    ChangeTag::Delete => {
-                        push = buffer.is_empty();
-
-                        buffer.push_str(&"↙".black().on_red().to_string());
-                        if push {
                             buffer.push_str(&ch);
+
+                            o_line_no += 1;
+                            n_line_no += 1;
                         }
+                        ChangeTag::Delete => {
+                            push = buffer.is_empty();
 
-                        o_line_no += 1;
-                    }
-                    ChangeTag::Insert => {
-                        push = true;
+                            buffer.push_str(&"↙".black().on_red().to_string());
+                            if push {
+                                buffer.push_str(&ch);
+                            }
+
+                            o_line_no += 1;
+                        }
+                        ChangeTag::Insert => {
+                            push = true;
 
-                        buffer.push_str(&format!("{}{}", "↙".black().on_green(), ch));
+                            buffer.push_str(&format!("{}{}", "↙".black().on_green(), ch));
 
-                        n_line_no += 1;
+                            n_line_no += 1;
+                        }
+                    };
+
+                    if push {
+                        lines.push((
+                            if o_o_line_no == o_line_no {
+                                None
+                            } else {
+                                Some(o_line_no)
+                            },
+                            if o_n_line_no == n_line_no {
+                                None
+                            } else {
+                                Some(n_line_no)
+                            },
+                            buffer.clone(),
+                        ));
+                        buffer.clear();
+                    }
+                } else {
+                    // Represent meta characters.
+                    let ch = match change.tag() {
+                        ChangeTag::Equal if ch == "\r" => "␍",
+                        _ => ch.as_str(),
+                    };
+
+                    match change.tag() {
+                        ChangeTag::Equal => buffer.push_str(ch),
+                        ChangeTag::Delete => buffer.push_str(&ch.black().on_red().to_string()),
+                        ChangeTag::Insert => buffer.push_str(&ch.black().on_green().to_string()),
                     }
-                };
-
-                if push {
-                    lines.push((
-                        if o_o_line_no == o_line_no {
-                            None
-                        } else {
-                            Some(o_line_no)
-                        },
-                        if o_n_line_no == n_line_no {
-                            None
-                        } else {
-                            Some(n_line_no)
-                        },
-                        buffer.clone(),
-                    ));
-                    buffer.clear();
-                }
-            } else {
-                // Represent meta characters.
-                let ch = match change.tag() {
-                    ChangeTag::Equal if ch == "\r" => "␍",
-                    _ => ch,
-                };
-
-                match change.tag() {
-                    ChangeTag::Equal => buffer.push_str(&ch),
-                    ChangeTag::Delete => buffer.push_str(&ch.black().on_red().to_string()),
-                    ChangeTag::Insert => buffer.push_str(&ch.black().on_green().to_string()),
                 }
             }
         }
@@ -1,50 +1,357 @@
-use crate::args::StringInput;
 use crate::tool::{Output, Tool};
-use clap::{Command, CommandFactory, Parser, Subcommand};
+use anyhow::{Context, Result, bail};
+use base64::{Engine as _, engine::general_purpose};
+use clap::{Command, CommandFactory, Parser, ValueEnum};
+use hmac::{Hmac, Mac};
 use md5::Md5;
+use ripemd::Ripemd160;
 use sha1::Sha1;
-use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+use sha2::{Digest, Sha256, Sha512};
+use sha3::{Shake128, Shake256};
+use serde_json::json;
+use std::convert::Infallible;
+use std::fs;
+use std::io::{self, Cursor, Read};
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
 
 #[derive(Parser, Debug)]
-#[command(name = "hash")]
-#[command(about = "Generate hash digests using various algorithms")]
+#[command(name = "hash", about = "Generate hash digests using various algorithms")]
 pub struct HashTool {
-    #[command(subcommand)]
-    command: HashCommand,
-}
-
-#[derive(Subcommand, Debug)]
-enum HashCommand {
-    /// Generate MD5 hash
-    Md5 {
-        /// Input to hash (use "-" for stdin)
-        input: StringInput,
-    },
-    /// Generate SHA-1 hash
-    Sha1 {
-        /// Input to hash (use "-" for stdin)
-        input: StringInput,
-    },
-    /// Generate SHA-224 hash
-    Sha224 {
-        /// Input to hash (use "-" for stdin)
-        input: StringInput,
-    },
-    /// Generate SHA-256 hash
-    Sha256 {
-        /// Input to hash (use "-" for stdin)
-        input: StringInput,
-    },
-    /// Generate SHA-384 hash
-    Sha384 {
-        /// Input to hash (use "-" for stdin)
-        input: StringInput,
-    },
-    /// Generate SHA-512 hash
-    Sha512 {
-        /// Input to hash (use "-" for stdin)
-        input: StringInput,
-    },
+    /// Files to hash, or "-" for stdin. May be repeated; with more than one
+    /// input, output becomes a map of input to digest. Not used with --check
+    #[arg(required_unless_present = "check")]
+    inputs: Vec<HashInput>,
+
+    /// Algorithm to hash with. May be repeated to produce several digests
+    #[arg(short = 'a', long = "algorithm", value_enum)]
+    algorithm: Vec<Algorithm>,
+
+    /// Switch to keyed HMAC mode using this literal key instead of a plain
+    /// digest, reusing the selected --algorithm as the inner hash
+    #[arg(long, conflicts_with_all = ["hmac_file", "hmac_hex"])]
+    hmac: Option<String>,
+
+    /// Read the HMAC key from a file instead of passing it literally
+    #[arg(long, conflicts_with_all = ["hmac", "hmac_hex"])]
+    hmac_file: Option<PathBuf>,
+
+    /// Provide the HMAC key as hex, for binary keys that aren't valid UTF-8
+    #[arg(long, conflicts_with_all = ["hmac", "hmac_file"])]
+    hmac_hex: Option<String>,
+
+    /// Encode digests as base64 instead of lowercase hex
+    #[arg(long)]
+    base64: bool,
+
+    /// Use the URL-safe base64 alphabet (implies --base64)
+    #[arg(long)]
+    urlsafe: bool,
+
+    /// Emit a BSD-style tagged line (`ALGO (path) = digest`) instead of a
+    /// bare digest, so the output can be fed straight back into --check
+    #[arg(long, conflicts_with = "check")]
+    tag: bool,
+
+    /// Verify files against a checksum manifest instead of hashing INPUT.
+    /// Accepts GNU coreutils `<hex-digest>  <path>` lines as well as the
+    /// BSD tag form `ALGO (path) = <digest>`
+    #[arg(long, conflicts_with_all = ["algorithm", "hmac", "hmac_file", "hmac_hex", "base64", "urlsafe"])]
+    check: Option<PathBuf>,
+
+    /// With --check, suppress "OK" lines for files that verified successfully
+    #[arg(long, requires = "check")]
+    quiet: bool,
+
+    /// With --check, print nothing; only the exit code reports the result
+    #[arg(long, requires = "check")]
+    status: bool,
+
+    /// With --check, exit non-zero if the manifest contains any improperly
+    /// formatted lines
+    #[arg(long, requires = "check")]
+    strict: bool,
+
+    /// With --check, warn about improperly formatted manifest lines instead
+    /// of silently skipping them
+    #[arg(long, requires = "check")]
+    warn: bool,
+
+    /// Output digest length in bits. Fixed-size algorithms reject a value
+    /// that disagrees with their natural digest size; BLAKE3 and the SHAKE
+    /// XOFs use this to pick their (otherwise arbitrary) output width,
+    /// defaulting to 256
+    #[arg(long)]
+    length: Option<u32>,
+}
+
+// A file to stream through the hasher, or stdin when given as "-".
+#[derive(Debug, Clone)]
+enum HashInput {
+    Stdin,
+    File(PathBuf),
+}
+
+impl FromStr for HashInput {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s == "-" {
+            Ok(HashInput::Stdin)
+        } else {
+            Ok(HashInput::File(PathBuf::from(s)))
+        }
+    }
+}
+
+impl HashInput {
+    // The label used for JSON map keys and BSD-tag lines; "-" for stdin,
+    // matching the coreutils convention.
+    fn label(&self) -> String {
+        match self {
+            HashInput::Stdin => "-".to_string(),
+            HashInput::File(path) => path.display().to_string(),
+        }
+    }
+
+    fn open(&self) -> Result<Box<dyn Read>> {
+        match self {
+            HashInput::Stdin => Ok(Box::new(io::stdin())),
+            HashInput::File(path) => Ok(Box::new(
+                fs::File::open(path).with_context(|| format!("Could not open {}", path.display()))?,
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq, Eq, Hash)]
+enum Algorithm {
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+    /// SHA-256 applied twice: sha256(sha256(input))
+    #[value(name = "sha256d")]
+    Sha256d,
+    /// ripemd160(sha256(input)), as used to derive Bitcoin addresses
+    #[value(name = "hash160")]
+    Hash160,
+    /// BLAKE3, an arbitrary-length extendable-output hash
+    Blake3,
+    /// SHAKE128, a 128-bit-security extendable-output function
+    Shake128,
+    /// SHAKE256, a 256-bit-security extendable-output function
+    Shake256,
+}
+
+impl Algorithm {
+    fn name(self) -> &'static str {
+        match self {
+            Algorithm::Md5 => "md5",
+            Algorithm::Sha1 => "sha1",
+            Algorithm::Sha256 => "sha256",
+            Algorithm::Sha512 => "sha512",
+            Algorithm::Sha256d => "sha256d",
+            Algorithm::Hash160 => "hash160",
+            Algorithm::Blake3 => "blake3",
+            Algorithm::Shake128 => "shake128",
+            Algorithm::Shake256 => "shake256",
+        }
+    }
+
+    // The digest size of fixed-size algorithms, in bits. `None` for the
+    // extendable-output algorithms, whose output width is caller-chosen.
+    fn natural_bit_length(self) -> Option<u32> {
+        match self {
+            Algorithm::Md5 => Some(128),
+            Algorithm::Sha1 => Some(160),
+            Algorithm::Sha256 => Some(256),
+            Algorithm::Sha512 => Some(512),
+            Algorithm::Sha256d => Some(256),
+            Algorithm::Hash160 => Some(160),
+            Algorithm::Blake3 | Algorithm::Shake128 | Algorithm::Shake256 => None,
+        }
+    }
+}
+
+// The output width an XOF/BLAKE3 digest uses when --length isn't given.
+const DEFAULT_XOF_BIT_LENGTH: u32 = 256;
+
+#[derive(Debug, Clone, Copy)]
+enum DigestEncoding {
+    Hex,
+    Base64,
+    Base64Url,
+}
+
+impl DigestEncoding {
+    fn encode(self, bytes: &[u8]) -> String {
+        match self {
+            DigestEncoding::Hex => hex_encode(bytes),
+            DigestEncoding::Base64 => general_purpose::STANDARD.encode(bytes),
+            DigestEncoding::Base64Url => general_purpose::URL_SAFE.encode(bytes),
+        }
+    }
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn decode_hex(hex: &str) -> Result<Vec<u8>> {
+    if hex.len() % 2 != 0 {
+        bail!("hex string must have an even number of digits");
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).context("invalid hex digit"))
+        .collect()
+}
+
+// Read in fixed-size chunks so hashing a large file never requires buffering
+// the whole thing in memory.
+const STREAM_CHUNK_BYTES: usize = 64 * 1024;
+
+// Computes a plain digest, or an RFC 2104 HMAC over the same input when a
+// key is supplied, for a single algorithm, streaming the reader in chunks.
+fn hash<D: Digest>(mut reader: impl Read, hmac_key: Option<&[u8]>) -> Result<Vec<u8>>
+where
+    Hmac<D>: Mac,
+{
+    let mut buffer = [0u8; STREAM_CHUNK_BYTES];
+
+    match hmac_key {
+        Some(key) => {
+            let mut mac = <Hmac<D> as Mac>::new_from_slice(key)
+                .context("HMAC key is of invalid length for this algorithm")?;
+            loop {
+                let read = reader.read(&mut buffer).context("Could not read input")?;
+                if read == 0 {
+                    break;
+                }
+                mac.update(&buffer[..read]);
+            }
+            Ok(mac.finalize().into_bytes().to_vec())
+        }
+        None => {
+            let mut hasher = D::new();
+            loop {
+                let read = reader.read(&mut buffer).context("Could not read input")?;
+                if read == 0 {
+                    break;
+                }
+                hasher.update(&buffer[..read]);
+            }
+            Ok(hasher.finalize().to_vec())
+        }
+    }
+}
+
+// Resolves --length against an algorithm's natural digest size: fixed-size
+// algorithms must either omit --length or repeat their own size, while the
+// extendable-output algorithms use it (or DEFAULT_XOF_BIT_LENGTH) to pick
+// how many bytes to squeeze out.
+fn resolve_length_bytes(algorithm: Algorithm, requested_bits: Option<u32>) -> Result<usize> {
+    let bits = match (algorithm.natural_bit_length(), requested_bits) {
+        (Some(natural), Some(requested)) if requested != natural => {
+            bail!(
+                "--length {requested} does not match {}'s fixed {natural}-bit digest size",
+                algorithm.name()
+            );
+        }
+        (Some(natural), _) => natural,
+        (None, requested) => requested.unwrap_or(DEFAULT_XOF_BIT_LENGTH),
+    };
+
+    if bits % 8 != 0 {
+        bail!("--length must be a multiple of 8, got {bits}");
+    }
+
+    Ok((bits / 8) as usize)
+}
+
+fn digest(algorithm: Algorithm, reader: impl Read, hmac_key: Option<&[u8]>, length_bits: Option<u32>) -> Result<Vec<u8>> {
+    let length_bytes = resolve_length_bytes(algorithm, length_bits)?;
+
+    match algorithm {
+        Algorithm::Md5 => hash::<Md5>(reader, hmac_key),
+        Algorithm::Sha1 => hash::<Sha1>(reader, hmac_key),
+        Algorithm::Sha256 => hash::<Sha256>(reader, hmac_key),
+        Algorithm::Sha512 => hash::<Sha512>(reader, hmac_key),
+        Algorithm::Sha256d => {
+            if hmac_key.is_some() {
+                bail!("HMAC mode is not supported for sha256d");
+            }
+            let inner = hash::<Sha256>(reader, None)?;
+            Ok(Sha256::digest(inner).to_vec())
+        }
+        Algorithm::Hash160 => {
+            if hmac_key.is_some() {
+                bail!("HMAC mode is not supported for hash160");
+            }
+            let inner = hash::<Sha256>(reader, None)?;
+            Ok(Ripemd160::digest(inner).to_vec())
+        }
+        Algorithm::Blake3 => {
+            if hmac_key.is_some() {
+                bail!("HMAC mode is not supported for blake3");
+            }
+            hash_blake3(reader, length_bytes)
+        }
+        Algorithm::Shake128 => {
+            if hmac_key.is_some() {
+                bail!("HMAC mode is not supported for shake128");
+            }
+            hash_xof::<Shake128>(reader, length_bytes)
+        }
+        Algorithm::Shake256 => {
+            if hmac_key.is_some() {
+                bail!("HMAC mode is not supported for shake256");
+            }
+            hash_xof::<Shake256>(reader, length_bytes)
+        }
+    }
+}
+
+// BLAKE3 has no fixed output size; it's squeezed for as many bytes as asked.
+fn hash_blake3(mut reader: impl Read, length_bytes: usize) -> Result<Vec<u8>> {
+    let mut hasher = blake3::Hasher::new();
+    let mut buffer = [0u8; STREAM_CHUNK_BYTES];
+    loop {
+        let read = reader.read(&mut buffer).context("Could not read input")?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    let mut output = vec![0u8; length_bytes];
+    hasher.finalize_xof().fill(&mut output);
+    Ok(output)
+}
+
+// Squeezes `length_bytes` out of a SHAKE128/SHAKE256 extendable-output
+// function, then hands the buffer off to the same hex/base64 encoders used
+// by every fixed-size digest.
+fn hash_xof<D: Default + ::digest::Update + ::digest::ExtendableOutput>(
+    mut reader: impl Read,
+    length_bytes: usize,
+) -> Result<Vec<u8>> {
+    use ::digest::{Update, XofReader};
+
+    let mut hasher = D::default();
+    let mut buffer = [0u8; STREAM_CHUNK_BYTES];
+    loop {
+        let read = reader.read(&mut buffer).context("Could not read input")?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    let mut output = vec![0u8; length_bytes];
+    hasher.finalize_xof().read(&mut output);
+    Ok(output)
 }
 
 impl Tool for HashTool {
@@ -52,40 +359,737 @@ impl Tool for HashTool {
         HashTool::command()
     }
 
-    fn execute(&self) -> anyhow::Result<Option<Output>> {
-        let hash = match &self.command {
-            HashCommand::Md5 { input } => {
-                let mut hasher = Md5::new();
-                hasher.update(input.as_ref().as_bytes());
-                format!("{:x}", hasher.finalize())
-            }
-            HashCommand::Sha1 { input } => {
-                let mut hasher = Sha1::new();
-                hasher.update(input.as_ref().as_bytes());
-                format!("{:x}", hasher.finalize())
+    fn execute(&self) -> Result<Option<Output>> {
+        if let Some(manifest) = &self.check {
+            return Ok(Some(self.run_check(manifest)?));
+        }
+
+        let encoding = if self.urlsafe {
+            DigestEncoding::Base64Url
+        } else if self.base64 {
+            DigestEncoding::Base64
+        } else {
+            DigestEncoding::Hex
+        };
+
+        let algorithms = if self.algorithm.is_empty() {
+            vec![Algorithm::Sha256]
+        } else {
+            self.algorithm.clone()
+        };
+
+        let hmac_key = self.resolve_hmac_key()?;
+
+        // Guaranteed non-empty by clap's `required_unless_present = "check"`.
+        let mut results = Vec::with_capacity(self.inputs.len());
+        for input in &self.inputs {
+            let label = input.label();
+            let value = self.hash_input(&label, input.open()?, &algorithms, encoding, hmac_key.as_deref())?;
+            results.push((label, value));
+        }
+
+        if let [(_, value)] = &results[..] {
+            return Ok(Some(Output::JsonValue(value.clone())));
+        }
+
+        Ok(Some(Output::JsonValue(serde_json::Value::Object(
+            results.into_iter().collect(),
+        ))))
+    }
+}
+
+impl HashTool {
+    // Resolves whichever of --hmac/--hmac-file/--hmac-hex was given (clap's
+    // conflicts_with_all guarantees at most one) into raw key bytes.
+    fn resolve_hmac_key(&self) -> Result<Option<Vec<u8>>> {
+        if let Some(key) = &self.hmac {
+            return Ok(Some(key.as_bytes().to_vec()));
+        }
+        if let Some(path) = &self.hmac_file {
+            return Ok(Some(
+                fs::read(path).with_context(|| format!("Could not read HMAC key file {}", path.display()))?,
+            ));
+        }
+        if let Some(hex) = &self.hmac_hex {
+            return Ok(Some(decode_hex(hex).context("--hmac-hex is not valid hex")?));
+        }
+        Ok(None)
+    }
+
+    // Hashes a single input with every requested algorithm, returning a bare
+    // digest (or tag line) for one algorithm, or a map of algorithm to digest
+    // for several. A single-pass reader can only be streamed through one
+    // algorithm, so with more than one algorithm the input is buffered once
+    // and re-hashed from memory for the rest.
+    fn hash_input(
+        &self,
+        label: &str,
+        mut reader: Box<dyn Read>,
+        algorithms: &[Algorithm],
+        encoding: DigestEncoding,
+        hmac_key: Option<&[u8]>,
+    ) -> Result<serde_json::Value> {
+        if let [algorithm] = *algorithms {
+            let bytes = digest(algorithm, reader, hmac_key, self.length)?;
+            let encoded = encoding.encode(&bytes);
+            return Ok(if self.tag {
+                json!(format_tag(algorithm, label, &encoded))
+            } else {
+                json!(encoded)
+            });
+        }
+
+        let mut buffer = Vec::new();
+        reader.read_to_end(&mut buffer).context("Could not read input")?;
+
+        let mut digests = serde_json::Map::new();
+        for algorithm in algorithms {
+            let bytes = digest(*algorithm, Cursor::new(&buffer), hmac_key, self.length)?;
+            let encoded = encoding.encode(&bytes);
+            let value = if self.tag {
+                json!(format_tag(*algorithm, label, &encoded))
+            } else {
+                json!(encoded)
+            };
+            digests.insert(algorithm.name().to_string(), value);
+        }
+
+        Ok(serde_json::Value::Object(digests))
+    }
+
+    // Reads the checksum manifest at `manifest_path`, recomputes each listed
+    // file's digest, and reports `path: OK` / `path: FAILED` per entry. The
+    // process exits non-zero if any file fails, is missing, or (with
+    // --strict) if the manifest itself was malformed.
+    fn run_check(&self, manifest_path: &Path) -> Result<Output> {
+        let manifest = fs::read_to_string(manifest_path)
+            .with_context(|| format!("Could not read checksum manifest {}", manifest_path.display()))?;
+
+        let default_algorithm = self.algorithm.first().copied().unwrap_or(Algorithm::Sha256);
+
+        let mut lines = Vec::new();
+        let mut malformed = 0u32;
+        let mut failed = 0u32;
+        let mut missing = 0u32;
+
+        for (index, raw_line) in manifest.lines().enumerate() {
+            let line = raw_line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
             }
-            HashCommand::Sha224 { input } => {
-                let mut hasher = Sha224::new();
-                hasher.update(input.as_ref().as_bytes());
-                format!("{:x}", hasher.finalize())
+
+            let Some(entry) = parse_manifest_line(line) else {
+                malformed += 1;
+                if self.warn {
+                    eprintln!(
+                        "{}: {}: improperly formatted checksum line",
+                        manifest_path.display(),
+                        index + 1
+                    );
+                }
+                continue;
+            };
+
+            let algorithm = entry.algorithm.unwrap_or(default_algorithm);
+
+            match fs::File::open(&entry.path) {
+                Ok(file) => {
+                    let actual = hex_encode(&digest(algorithm, file, None, self.length)?);
+                    if actual.eq_ignore_ascii_case(&entry.digest) {
+                        if !self.quiet && !self.status {
+                            lines.push(format!("{}: OK", entry.path));
+                        }
+                    } else {
+                        failed += 1;
+                        if !self.status {
+                            lines.push(format!("{}: FAILED", entry.path));
+                        }
+                    }
+                }
+                Err(_) => {
+                    missing += 1;
+                    if !self.status {
+                        lines.push(format!("{}: FAILED open or read", entry.path));
+                    }
+                }
             }
-            HashCommand::Sha256 { input } => {
-                let mut hasher = Sha256::new();
-                hasher.update(input.as_ref().as_bytes());
-                format!("{:x}", hasher.finalize())
+        }
+
+        if !self.status {
+            if missing > 0 {
+                eprintln!(
+                    "{}: WARNING: {missing} listed file{} could not be read",
+                    manifest_path.display(),
+                    if missing == 1 { "" } else { "s" }
+                );
             }
-            HashCommand::Sha384 { input } => {
-                let mut hasher = Sha384::new();
-                hasher.update(input.as_ref().as_bytes());
-                format!("{:x}", hasher.finalize())
+            if failed > 0 {
+                eprintln!(
+                    "{}: WARNING: {failed} computed checksum{} did NOT match",
+                    manifest_path.display(),
+                    if failed == 1 { "" } else { "s" }
+                );
             }
-            HashCommand::Sha512 { input } => {
-                let mut hasher = Sha512::new();
-                hasher.update(input.as_ref().as_bytes());
-                format!("{:x}", hasher.finalize())
+            if malformed > 0 {
+                eprintln!(
+                    "{}: WARNING: {malformed} line{} improperly formatted",
+                    manifest_path.display(),
+                    if malformed == 1 { "" } else { "s" }
+                );
             }
+        }
+
+        let ok = failed == 0 && missing == 0 && (!self.strict || malformed == 0);
+
+        Ok(Output::Status {
+            value: json!(lines),
+            exit_code: if ok { 0 } else { 1 },
+        })
+    }
+}
+
+// Formats a digest as a BSD-style tagged line (`ALGO (label) = digest`),
+// the same shape --check parses back.
+fn format_tag(algorithm: Algorithm, label: &str, digest: &str) -> String {
+    format!("{} ({label}) = {digest}", algorithm.name().to_uppercase())
+}
+
+// A single parsed line of a checksum manifest. `algorithm` is `None` for
+// GNU-style lines, which don't name an algorithm; the caller falls back to
+// whatever algorithm it was told to verify with.
+struct ManifestEntry {
+    algorithm: Option<Algorithm>,
+    digest: String,
+    path: String,
+}
+
+fn algorithm_from_name(name: &str) -> Option<Algorithm> {
+    [
+        Algorithm::Md5,
+        Algorithm::Sha1,
+        Algorithm::Sha256,
+        Algorithm::Sha512,
+        Algorithm::Sha256d,
+        Algorithm::Hash160,
+        Algorithm::Blake3,
+        Algorithm::Shake128,
+        Algorithm::Shake256,
+    ]
+    .into_iter()
+    .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+}
+
+fn parse_manifest_line(line: &str) -> Option<ManifestEntry> {
+    parse_bsd_manifest_line(line).or_else(|| parse_gnu_manifest_line(line))
+}
+
+// BSD/OpenSSL tag form: `ALGO (path) = digest`.
+fn parse_bsd_manifest_line(line: &str) -> Option<ManifestEntry> {
+    let (name, rest) = line.split_once(" (")?;
+    let algorithm = algorithm_from_name(name)?;
+
+    let (path, digest) = rest.rsplit_once(") = ")?;
+    if path.is_empty() || digest.is_empty() || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    Some(ManifestEntry {
+        algorithm: Some(algorithm),
+        digest: digest.to_lowercase(),
+        path: path.to_string(),
+    })
+}
+
+// GNU coreutils form: `<hex-digest>  <path>`, where the single character
+// right before the path is a mode marker (' ' for text, '*' for binary).
+fn parse_gnu_manifest_line(line: &str) -> Option<ManifestEntry> {
+    let (digest, rest) = line.split_once(char::is_whitespace)?;
+    if digest.is_empty() || !digest.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let path = rest.strip_prefix('*').unwrap_or(rest).trim();
+    if path.is_empty() {
+        return None;
+    }
+
+    Some(ManifestEntry {
+        algorithm: None,
+        digest: digest.to_lowercase(),
+        path: path.to_string(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Writes `content` to a deterministic temp file and returns its path, so
+    // tests can exercise the file-path-based `inputs` field without pulling
+    // in a `tempfile` crate dependency.
+    fn write_temp_input(content: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("ut-hash-test-input-{}.bin", hex_encode(content.as_bytes())));
+        fs::write(&path, content).unwrap();
+        path
+    }
+
+    fn tool(input: &str, algorithm: Vec<Algorithm>, hmac_key: Option<&str>, base64: bool) -> HashTool {
+        HashTool {
+            inputs: vec![HashInput::File(write_temp_input(input))],
+            algorithm,
+            hmac: hmac_key.map(str::to_string),
+            hmac_file: None,
+            hmac_hex: None,
+            base64,
+            urlsafe: false,
+            tag: false,
+            check: None,
+            quiet: false,
+            status: false,
+            strict: false,
+            warn: false,
+            length: None,
+        }
+    }
+
+    fn check_tool(manifest: &std::path::Path, quiet: bool, status: bool, strict: bool, warn: bool) -> HashTool {
+        HashTool {
+            inputs: vec![],
+            algorithm: vec![],
+            hmac: None,
+            hmac_file: None,
+            hmac_hex: None,
+            base64: false,
+            urlsafe: false,
+            tag: false,
+            check: Some(manifest.to_path_buf()),
+            quiet,
+            status,
+            strict,
+            warn,
+            length: None,
+        }
+    }
+
+    #[test]
+    fn test_default_algorithm_is_sha256() {
+        let result = tool("hello", vec![], None, false).execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(
+            val.as_str().unwrap(),
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+    }
+
+    #[test]
+    fn test_md5() {
+        let result = tool("hello", vec![Algorithm::Md5], None, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap(), "5d41402abc4b2a76b9719d911017c592");
+    }
+
+    #[test]
+    fn test_multiple_algorithms_produce_a_map() {
+        let result = tool("hello", vec![Algorithm::Md5, Algorithm::Sha1], None, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert!(val["md5"].is_string());
+        assert!(val["sha1"].is_string());
+    }
+
+    #[test]
+    fn test_base64_encoding() {
+        let result = tool("hello", vec![Algorithm::Md5], None, true)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+
+        let mut hasher = Md5::new();
+        hasher.update(b"hello");
+        let expected = general_purpose::STANDARD.encode(hasher.finalize());
+        assert_eq!(val.as_str().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sha256d() {
+        let result = tool("hello", vec![Algorithm::Sha256d], None, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+
+        let first = Sha256::digest(b"hello");
+        let expected = hex_encode(&Sha256::digest(first));
+        assert_eq!(val.as_str().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_hash160() {
+        let result = tool("hello", vec![Algorithm::Hash160], None, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+
+        let sha = Sha256::digest(b"hello");
+        let expected = hex_encode(&Ripemd160::digest(sha));
+        assert_eq!(val.as_str().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_sha256d_rejects_hmac_key() {
+        let result = tool("hello", vec![Algorithm::Sha256d], Some("secret"), false).execute();
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_hmac_mode() {
+        let result = tool("hello", vec![Algorithm::Sha256], Some("secret"), false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        // HMAC output differs from the plain digest of the same input.
+        let plain = tool("hello", vec![Algorithm::Sha256], None, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(plain_val) = plain else {
+            panic!("Expected JsonValue output");
+        };
+        assert_ne!(val, plain_val);
+    }
+
+    #[test]
+    fn test_hmac_file_and_hmac_hex_agree_with_hmac() {
+        let baseline = tool("hello", vec![Algorithm::Sha256], Some("secret"), false)
+            .execute()
+            .unwrap()
+            .unwrap();
+
+        let key_file = write_temp_input("secret");
+        let mut by_file = tool("hello", vec![Algorithm::Sha256], None, false);
+        by_file.hmac_file = Some(key_file);
+        assert_eq!(by_file.execute().unwrap().unwrap(), baseline);
+
+        let mut by_hex = tool("hello", vec![Algorithm::Sha256], None, false);
+        by_hex.hmac_hex = Some(hex_encode(b"secret"));
+        assert_eq!(by_hex.execute().unwrap().unwrap(), baseline);
+    }
+
+    #[test]
+    fn test_hmac_hex_rejects_invalid_hex() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Sha256], None, false);
+        hash_tool.hmac_hex = Some("not-hex".to_string());
+        assert!(hash_tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_tag_flag_emits_bsd_style_line() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Md5], None, false);
+        hash_tool.tag = true;
+        let HashInput::File(path) = &hash_tool.inputs[0] else {
+            panic!("Expected a file input");
+        };
+        let expected = format!("MD5 ({}) = 5d41402abc4b2a76b9719d911017c592", path.display());
+        let result = hash_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_multiple_inputs_produce_a_path_to_digest_map() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Md5], None, false);
+        hash_tool.inputs.push(HashInput::File(write_temp_input("world")));
+
+        let Output::JsonValue(val) = hash_tool.execute().unwrap().unwrap() else {
+            panic!("Expected JsonValue output");
+        };
+
+        let HashInput::File(first) = &hash_tool.inputs[0] else {
+            panic!("Expected a file input");
+        };
+        let HashInput::File(second) = &hash_tool.inputs[1] else {
+            panic!("Expected a file input");
         };
+        assert_eq!(
+            val[first.display().to_string()].as_str().unwrap(),
+            "5d41402abc4b2a76b9719d911017c592"
+        );
+        assert_eq!(
+            val[second.display().to_string()].as_str().unwrap(),
+            "7d793037a0760186574b0282f2f435e7"
+        );
+    }
+
+    #[test]
+    fn test_streaming_matches_expected_digest_across_a_chunk_boundary() {
+        let content = "a".repeat(STREAM_CHUNK_BYTES + 1);
+        let path = write_temp_input(&content);
+
+        let hash_tool = HashTool {
+            inputs: vec![HashInput::File(path)],
+            algorithm: vec![Algorithm::Sha256],
+            hmac: None,
+            hmac_file: None,
+            hmac_hex: None,
+            base64: false,
+            urlsafe: false,
+            tag: false,
+            check: None,
+            quiet: false,
+            status: false,
+            strict: false,
+            warn: false,
+            length: None,
+        };
+
+        let Output::JsonValue(val) = hash_tool.execute().unwrap().unwrap() else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap(), hex_encode(&Sha256::digest(content.as_bytes())));
+    }
+
+    #[test]
+    fn test_check_accepts_gnu_style_manifest() {
+        let file = std::env::temp_dir().join("ut-hash-test-gnu-ok.txt");
+        fs::write(&file, "hello").unwrap();
+
+        let manifest = std::env::temp_dir().join("ut-hash-test-gnu.sha256");
+        fs::write(
+            &manifest,
+            format!(
+                "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  {}\n",
+                file.display()
+            ),
+        )
+        .unwrap();
+
+        let result = check_tool(&manifest, false, false, false, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { value, exit_code } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 0);
+        assert_eq!(value[0].as_str().unwrap(), format!("{}: OK", file.display()));
+    }
+
+    #[test]
+    fn test_check_accepts_bsd_tag_manifest_and_detects_mismatch() {
+        let file = std::env::temp_dir().join("ut-hash-test-bsd-mismatch.txt");
+        fs::write(&file, "hello").unwrap();
+
+        let manifest = std::env::temp_dir().join("ut-hash-test-bsd.sha256");
+        fs::write(&manifest, format!("SHA256 ({}) = deadbeef\n", file.display())).unwrap();
+
+        let result = check_tool(&manifest, false, false, false, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { value, exit_code } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 1);
+        assert_eq!(
+            value[0].as_str().unwrap(),
+            format!("{}: FAILED", file.display())
+        );
+    }
+
+    #[test]
+    fn test_check_reports_missing_file() {
+        let manifest = std::env::temp_dir().join("ut-hash-test-missing.sha256");
+        fs::write(
+            &manifest,
+            "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  /no/such/file\n",
+        )
+        .unwrap();
+
+        let result = check_tool(&manifest, false, false, false, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { value, exit_code } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 1);
+        assert_eq!(value[0].as_str().unwrap(), "/no/such/file: FAILED open or read");
+    }
+
+    #[test]
+    fn test_check_quiet_suppresses_ok_lines() {
+        let file = std::env::temp_dir().join("ut-hash-test-quiet.txt");
+        fs::write(&file, "hello").unwrap();
+
+        let manifest = std::env::temp_dir().join("ut-hash-test-quiet.sha256");
+        fs::write(
+            &manifest,
+            format!(
+                "2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824  {}\n",
+                file.display()
+            ),
+        )
+        .unwrap();
+
+        let result = check_tool(&manifest, true, false, false, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { value, exit_code } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 0);
+        assert!(value.as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_status_suppresses_all_lines() {
+        let file = std::env::temp_dir().join("ut-hash-test-status.txt");
+        fs::write(&file, "hello").unwrap();
+
+        let manifest = std::env::temp_dir().join("ut-hash-test-status.sha256");
+        fs::write(&manifest, format!("deadbeef  {}\n", file.display())).unwrap();
+
+        let result = check_tool(&manifest, false, true, false, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { value, exit_code } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 1);
+        assert!(value.as_array().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_check_ignores_malformed_lines_without_strict() {
+        let manifest = std::env::temp_dir().join("ut-hash-test-malformed.sha256");
+        fs::write(&manifest, "not a valid manifest line\n").unwrap();
+
+        let result = check_tool(&manifest, false, false, false, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { exit_code, .. } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 0);
+    }
+
+    #[test]
+    fn test_check_strict_fails_on_malformed_lines() {
+        let manifest = std::env::temp_dir().join("ut-hash-test-strict.sha256");
+        fs::write(&manifest, "not a valid manifest line\n").unwrap();
+
+        let result = check_tool(&manifest, false, false, true, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::Status { exit_code, .. } = result else {
+            panic!("Expected Status output");
+        };
+        assert_eq!(exit_code, 1);
+    }
+
+    #[test]
+    fn test_parse_gnu_manifest_line_strips_binary_marker() {
+        let entry = parse_manifest_line("abc123 *some/file").unwrap();
+        assert_eq!(entry.digest, "abc123");
+        assert_eq!(entry.path, "some/file");
+        assert!(entry.algorithm.is_none());
+    }
+
+    #[test]
+    fn test_parse_bsd_manifest_line() {
+        let entry = parse_manifest_line("MD5 (some/file) = abc123").unwrap();
+        assert_eq!(entry.digest, "abc123");
+        assert_eq!(entry.path, "some/file");
+        assert_eq!(entry.algorithm, Some(Algorithm::Md5));
+    }
+
+    #[test]
+    fn test_blake3_default_length_is_32_bytes() {
+        let result = tool("hello", vec![Algorithm::Blake3], None, false)
+            .execute()
+            .unwrap()
+            .unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap(), blake3::hash(b"hello").to_hex().as_str());
+    }
+
+    #[test]
+    fn test_blake3_respects_custom_length() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Blake3], None, false);
+        hash_tool.length = Some(512);
+        let result = hash_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap().len(), 128); // 64 bytes, hex-encoded
+    }
+
+    #[test]
+    fn test_shake128_and_shake256_produce_default_length_digests() {
+        for algorithm in [Algorithm::Shake128, Algorithm::Shake256] {
+            let result = tool("hello", vec![algorithm], None, false)
+                .execute()
+                .unwrap()
+                .unwrap();
+            let Output::JsonValue(val) = result else {
+                panic!("Expected JsonValue output");
+            };
+            assert_eq!(val.as_str().unwrap().len(), 64); // 32 bytes, hex-encoded
+        }
+    }
+
+    #[test]
+    fn test_shake256_respects_custom_length() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Shake256], None, false);
+        hash_tool.length = Some(128);
+        let result = hash_tool.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(val.as_str().unwrap().len(), 32); // 16 bytes, hex-encoded
+    }
+
+    #[test]
+    fn test_length_rejects_mismatched_fixed_size_algorithm() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Sha256], None, false);
+        hash_tool.length = Some(512);
+        assert!(hash_tool.execute().is_err());
+    }
 
-        Ok(Some(Output::JsonValue(serde_json::json!(hash))))
+    #[test]
+    fn test_length_accepts_matching_fixed_size_algorithm() {
+        let mut hash_tool = tool("hello", vec![Algorithm::Sha256], None, false);
+        hash_tool.length = Some(256);
+        assert!(hash_tool.execute().is_ok());
     }
 }
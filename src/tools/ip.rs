@@ -5,7 +5,7 @@ use crate::{
 use anyhow::{Result, bail};
 use clap::Parser;
 use serde_json::json;
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
 
 #[derive(Parser, Debug)]
 #[command(name = "ip", about = "IP address utilities")]
@@ -25,11 +25,21 @@ pub enum IPCommand {
 
 #[derive(Parser, Debug)]
 pub enum CIDRCommand {
-    /// Show information about a CIDR block
+    /// Show information about a CIDR block (IPv4 or IPv6)
     Describe {
-        /// CIDR notation (e.g. 192.168.1.0/24)
+        /// CIDR notation (e.g. 192.168.1.0/24 or 2001:db8::/32)
         notation: StringInput,
     },
+
+    /// Split a CIDR block into equally-sized child subnets (VLSM-style)
+    Split {
+        /// Parent CIDR notation (e.g. 10.0.0.0/16 or 2001:db8::/32)
+        notation: StringInput,
+
+        /// Target prefix length for the child subnets, must be longer than
+        /// the parent's prefix (e.g. 24 to split a /16 into /24s)
+        new_prefix: u8,
+    },
 }
 
 impl Tool for IPTool {
@@ -43,18 +53,33 @@ impl Tool for IPTool {
                 CIDRCommand::Describe { notation } => {
                     Ok(Some(Output::JsonValue(cidr_info(notation.as_ref())?)))
                 }
+                CIDRCommand::Split {
+                    notation,
+                    new_prefix,
+                } => Ok(Some(Output::JsonValue(split_cidr(
+                    notation.as_ref(),
+                    *new_prefix,
+                )?))),
             },
         }
     }
 }
 
-fn cidr_info(notation: &str) -> Result<serde_json::Value> {
+// The highest number of child subnets `split_cidr` will emit. Splitting a
+// large-enough block into small-enough children is otherwise an easy way to
+// ask for billions of JSON objects.
+const MAX_SPLIT_SUBNETS: u128 = 65536;
+
+// Parses "IP/prefix" into its address and prefix length, validating the
+// prefix against the address family's width (32 for IPv4, 128 for IPv6).
+// Shared by `cidr_info` and `split_cidr` so both commands agree on notation.
+fn parse_notation(notation: &str) -> Result<(IpAddr, u8)> {
     let parts: Vec<&str> = notation.split('/').collect();
     if parts.len() != 2 {
         bail!("Invalid CIDR notation. Expected format: IP/prefix (e.g., 192.168.1.0/24)");
     }
 
-    let ip: Ipv4Addr = parts[0]
+    let ip: IpAddr = parts[0]
         .parse()
         .map_err(|_| anyhow::anyhow!("Invalid IP address: {}", parts[0]))?;
 
@@ -62,10 +87,117 @@ fn cidr_info(notation: &str) -> Result<serde_json::Value> {
         .parse()
         .map_err(|_| anyhow::anyhow!("Invalid prefix length: {}", parts[1]))?;
 
-    if prefix > 32 {
-        bail!("Prefix length must be between 0 and 32, got: {}", prefix);
+    let max_prefix = address_bits(&ip);
+    if prefix > max_prefix {
+        bail!(
+            "Prefix length must be between 0 and {}, got: {}",
+            max_prefix,
+            prefix
+        );
+    }
+
+    Ok((ip, prefix))
+}
+
+fn address_bits(ip: &IpAddr) -> u8 {
+    match ip {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+fn ip_to_u128(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(v4) => u32::from(v4) as u128,
+        IpAddr::V6(v6) => u128::from(v6),
+    }
+}
+
+fn u128_to_ip(value: u128, is_v6: bool) -> IpAddr {
+    if is_v6 {
+        IpAddr::V6(Ipv6Addr::from(value))
+    } else {
+        IpAddr::V4(Ipv4Addr::from(value as u32))
+    }
+}
+
+// Number of addresses in a `/prefix` block out of `bits` total address bits,
+// saturating instead of overflowing a `u128` shift for huge IPv6 blocks
+// (e.g. a /0 has 2^128 addresses, which doesn't fit in a u128).
+fn block_size(bits: u8, prefix: u8) -> u128 {
+    let shift = bits - prefix;
+    if shift >= 128 { u128::MAX } else { 1u128 << shift }
+}
+
+fn netmask_for(bits: u8, prefix: u8) -> u128 {
+    let mask_all = address_mask(bits);
+    if prefix == 0 {
+        0
+    } else {
+        mask_all & (!0u128 << (bits - prefix))
+    }
+}
+
+fn address_mask(bits: u8) -> u128 {
+    if bits == 128 {
+        u128::MAX
+    } else {
+        (1u128 << bits) - 1
+    }
+}
+
+// Network/broadcast/host-range bounds for a `/prefix` block containing
+// `value`, generalized over address width so it serves both the IPv6
+// `Describe` path and the `Split` path (IPv4 and IPv6 alike).
+struct SubnetBounds {
+    network: u128,
+    broadcast: u128,
+    first_host: u128,
+    last_host: u128,
+    total_hosts: u64,
+    netmask: u128,
+    wildcard: u128,
+}
+
+fn subnet_bounds(value: u128, bits: u8, prefix: u8) -> SubnetBounds {
+    let netmask = netmask_for(bits, prefix);
+    let wildcard = address_mask(bits) & !netmask;
+    let network = value & netmask;
+    let broadcast = network | wildcard;
+
+    let (first_host, last_host, total_hosts): (u128, u128, u128) = if prefix == bits {
+        (network, network, 1)
+    } else if prefix == bits - 1 {
+        (network, broadcast, 2)
+    } else {
+        (
+            network + 1,
+            broadcast - 1,
+            block_size(bits, prefix).saturating_sub(2),
+        )
+    };
+
+    SubnetBounds {
+        network,
+        broadcast,
+        first_host,
+        last_host,
+        total_hosts: total_hosts.min(u64::MAX as u128) as u64,
+        netmask,
+        wildcard,
+    }
+}
+
+fn cidr_info(notation: &str) -> Result<serde_json::Value> {
+    let (ip, prefix) = parse_notation(notation)?;
+
+    match ip {
+        IpAddr::V4(ip) => Ok(ipv4_describe(ip, prefix)),
+        IpAddr::V6(ip) => Ok(ipv6_describe(ip, prefix)),
     }
+}
 
+fn ipv4_describe(ip: Ipv4Addr, prefix: u8) -> serde_json::Value {
     let ip_u32: u32 = ip.into();
     let netmask: u32 = if prefix == 0 {
         0
@@ -82,7 +214,8 @@ fn cidr_info(notation: &str) -> Result<serde_json::Value> {
         _ => (network + 1, broadcast - 1, (1u64 << (32 - prefix)) - 2),
     };
 
-    Ok(json!({
+    json!({
+        "version": 4,
         "address": Ipv4Addr::from(ip_u32).to_string(),
         "address_decimal": ip_u32,
         "address_hex": ip_to_hex(ip_u32),
@@ -100,7 +233,24 @@ fn cidr_info(notation: &str) -> Result<serde_json::Value> {
         "netmask_hex": ip_to_hex(netmask),
         "wildcard": Ipv4Addr::from(wildcard).to_string(),
         "wildcard_hex": ip_to_hex(wildcard),
-    }))
+    })
+}
+
+fn ipv6_describe(ip: Ipv6Addr, prefix: u8) -> serde_json::Value {
+    let bounds = subnet_bounds(u128::from(ip), 128, prefix);
+
+    json!({
+        "version": 6,
+        "address": ip.to_string(),
+        "network": Ipv6Addr::from(bounds.network).to_string(),
+        "broadcast": Ipv6Addr::from(bounds.broadcast).to_string(),
+        "first_host": Ipv6Addr::from(bounds.first_host).to_string(),
+        "last_host": Ipv6Addr::from(bounds.last_host).to_string(),
+        "total_hosts": bounds.total_hosts,
+        "prefix": prefix,
+        "netmask": Ipv6Addr::from(bounds.netmask).to_string(),
+        "wildcard": Ipv6Addr::from(bounds.wildcard).to_string(),
+    })
 }
 
 fn ip_to_hex(ip: u32) -> String {
@@ -111,6 +261,64 @@ fn ip_to_hex(ip: u32) -> String {
     )
 }
 
+fn split_cidr(notation: &str, new_prefix: u8) -> Result<serde_json::Value> {
+    let (ip, prefix) = parse_notation(notation)?;
+    let bits = address_bits(&ip);
+
+    if new_prefix <= prefix {
+        bail!(
+            "new_prefix (/{}) must be longer than the parent prefix (/{})",
+            new_prefix,
+            prefix
+        );
+    }
+    if new_prefix > bits {
+        bail!(
+            "Prefix length must be between 0 and {}, got: {}",
+            bits,
+            new_prefix
+        );
+    }
+
+    let parent_network = ip_to_u128(ip) & netmask_for(bits, prefix);
+    let child_block_size = block_size(bits, new_prefix);
+    let subnet_count = block_size(bits, prefix) / child_block_size;
+
+    if subnet_count > MAX_SPLIT_SUBNETS {
+        bail!(
+            "Splitting /{} into /{} would produce {} subnets, which exceeds the limit of {}",
+            prefix,
+            new_prefix,
+            subnet_count,
+            MAX_SPLIT_SUBNETS
+        );
+    }
+
+    let is_v6 = matches!(ip, IpAddr::V6(_));
+    let subnets: Vec<serde_json::Value> = (0..subnet_count as u64)
+        .map(|i| {
+            let child_value = parent_network + (i as u128) * child_block_size;
+            let bounds = subnet_bounds(child_value, bits, new_prefix);
+
+            json!({
+                "network": u128_to_ip(bounds.network, is_v6).to_string(),
+                "broadcast": u128_to_ip(bounds.broadcast, is_v6).to_string(),
+                "first_host": u128_to_ip(bounds.first_host, is_v6).to_string(),
+                "last_host": u128_to_ip(bounds.last_host, is_v6).to_string(),
+                "total_hosts": bounds.total_hosts,
+            })
+        })
+        .collect();
+
+    Ok(json!({
+        "parent": notation,
+        "parent_prefix": prefix,
+        "new_prefix": new_prefix,
+        "count": subnets.len(),
+        "subnets": subnets,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -129,6 +337,21 @@ mod tests {
         }
     }
 
+    fn run_split(input: &str, new_prefix: u8) -> Result<serde_json::Value> {
+        let tool = IPTool {
+            command: IPCommand::CIDR {
+                command: CIDRCommand::Split {
+                    notation: StringInput(input.to_string()),
+                    new_prefix,
+                },
+            },
+        };
+        match tool.execute()?.unwrap() {
+            Output::JsonValue(v) => Ok(v),
+            _ => panic!("Expected JsonValue"),
+        }
+    }
+
     #[test]
     fn test_class_c_network() {
         let result = run_cidr("192.168.1.100/24");
@@ -224,4 +447,79 @@ mod tests {
         };
         assert!(tool.execute().is_err());
     }
+
+    #[test]
+    fn test_ipv6_network_bounds() {
+        let result = run_cidr("2001:db8::/32");
+        assert_eq!(result["version"], 6);
+        assert_eq!(result["network"], "2001:db8::");
+        assert_eq!(result["first_host"], "2001:db8::1");
+        assert_eq!(
+            result["last_host"],
+            "2001:db8:ffff:ffff:ffff:ffff:ffff:fffe"
+        );
+        assert_eq!(
+            result["broadcast"],
+            "2001:db8:ffff:ffff:ffff:ffff:ffff:ffff"
+        );
+        assert_eq!(result["prefix"], 32);
+    }
+
+    #[test]
+    fn test_ipv6_single_host() {
+        let result = run_cidr("2001:db8::1/128");
+        assert_eq!(result["network"], "2001:db8::1");
+        assert_eq!(result["first_host"], "2001:db8::1");
+        assert_eq!(result["last_host"], "2001:db8::1");
+        assert_eq!(result["total_hosts"], 1);
+    }
+
+    #[test]
+    fn test_ipv6_huge_block_saturates_total_hosts() {
+        let result = run_cidr("::/0");
+        assert_eq!(result["total_hosts"], u64::MAX);
+    }
+
+    #[test]
+    fn test_ipv6_rejects_prefix_over_128() {
+        let tool = IPTool {
+            command: IPCommand::CIDR {
+                command: CIDRCommand::Describe {
+                    notation: StringInput("2001:db8::/129".to_owned()),
+                },
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_split_v4_16_into_24s() {
+        let result = run_split("10.0.0.0/16", 24).unwrap();
+        assert_eq!(result["count"], 256);
+        let subnets = result["subnets"].as_array().unwrap();
+        assert_eq!(subnets[0]["network"], "10.0.0.0");
+        assert_eq!(subnets[0]["broadcast"], "10.0.0.255");
+        assert_eq!(subnets[1]["network"], "10.0.1.0");
+        assert_eq!(subnets[255]["network"], "10.0.255.0");
+    }
+
+    #[test]
+    fn test_split_v6() {
+        let result = run_split("2001:db8::/32", 34).unwrap();
+        assert_eq!(result["count"], 4);
+        let subnets = result["subnets"].as_array().unwrap();
+        assert_eq!(subnets[0]["network"], "2001:db8::");
+        assert_eq!(subnets[3]["network"], "2001:db8:c000::");
+    }
+
+    #[test]
+    fn test_split_rejects_shorter_or_equal_new_prefix() {
+        assert!(run_split("10.0.0.0/16", 16).is_err());
+        assert!(run_split("10.0.0.0/24", 16).is_err());
+    }
+
+    #[test]
+    fn test_split_rejects_exceeding_subnet_cap() {
+        assert!(run_split("10.0.0.0/8", 32).is_err());
+    }
 }
@@ -3,13 +3,15 @@ use nom::{
     IResult,
     branch::alt,
     bytes::complete::{tag, take_until, take_while1},
-    character::complete::{char, digit1},
+    character::complete::{char, digit1, multispace0},
     combinator::{map, opt, recognize},
-    multi::many0,
+    multi::{many0, separated_list0},
     sequence::{delimited, preceded, tuple},
 };
+use anyhow::Context;
 use serde_json::{Value, json};
 
+use crate::args::StringInput;
 use crate::tool::{Output, Tool};
 
 #[derive(Parser, Debug)]
@@ -26,6 +28,27 @@ enum JsonCommand {
         /// Key-value pairs in the format key=value (e.g., a.b.c=hello, "a.b[].c"=1 or "a.b[2].c"=false)
         #[arg(required = true)]
         inputs: Vec<String>,
+        /// Seed the document from an existing JSON document before applying
+        /// the key-value pairs ('-' reads from stdin)
+        #[arg(long)]
+        base: Option<StringInput>,
+        /// Remove the key or array element addressed by this path before
+        /// serializing (repeatable)
+        #[arg(long = "delete")]
+        delete: Vec<String>,
+    },
+    /// Flatten a JSON document into key=value lines the Builder can consume
+    Flatten {
+        /// JSON document to flatten ('-' reads from stdin)
+        input: StringInput,
+    },
+    /// Read a value out of a JSON document using the Builder's path notation
+    Get {
+        /// JSON document to query ('-' reads from stdin)
+        input: StringInput,
+        /// Path to look up (e.g., a.b[2].c). Use [] to project over every
+        /// element of an array, e.g. users[].name
+        path: String,
     },
 }
 
@@ -36,21 +59,253 @@ impl Tool for JsonTool {
 
     fn execute(&self) -> anyhow::Result<Option<Output>> {
         match &self.command {
-            JsonCommand::Builder { inputs } => {
-                let mut root = json!({});
+            JsonCommand::Builder {
+                inputs,
+                base,
+                delete,
+            } => {
+                let mut root = match base {
+                    Some(base) => serde_json::from_str(base.as_ref())
+                        .context("Could not parse --base as JSON")?,
+                    None => json!({}),
+                };
 
                 for input in inputs {
                     let (path_parts, value) = parse_input(input)?;
                     set_nested_value(&mut root, path_parts, value)?;
                 }
 
+                for path in delete {
+                    let (remaining, parts) = path_parser(path)
+                        .map_err(|e| anyhow::anyhow!("Failed to parse path '{}': {}", path, e))?;
+                    if !remaining.is_empty() {
+                        return Err(anyhow::anyhow!(
+                            "Failed to parse path completely, remaining: '{}'",
+                            remaining
+                        ));
+                    }
+
+                    delete_path(&mut root, &parts)?;
+                }
+
                 let serialized = serde_json::to_string_pretty(&root)?;
                 Ok(Some(Output::Text(serialized)))
             }
+            JsonCommand::Flatten { input } => {
+                let document: Value =
+                    serde_json::from_str(input.as_ref()).context("Could not parse input as JSON")?;
+
+                let mut lines = Vec::new();
+                flatten_into(None, &document, &mut lines);
+
+                Ok(Some(Output::Text(lines.join("\n"))))
+            }
+            JsonCommand::Get { input, path } => {
+                let document: Value =
+                    serde_json::from_str(input.as_ref()).context("Could not parse input as JSON")?;
+
+                let (remaining, parts) = path_parser(path)
+                    .map_err(|e| anyhow::anyhow!("Failed to parse path '{}': {}", path, e))?;
+                if !remaining.is_empty() {
+                    return Err(anyhow::anyhow!(
+                        "Failed to parse path completely, remaining: '{}'",
+                        remaining
+                    ));
+                }
+
+                let result = resolve_path(&document, &parts)?;
+                Ok(Some(Output::JsonValue(result)))
+            }
+        }
+    }
+}
+
+// Traverse `value` following `parts`, the same `PathPart` sequence the
+// Builder uses to write. `ArrayAppend` ([]) is reinterpreted on the way
+// out as "every element", so the rest of the path is resolved against
+// each item and the results are collected into an array - this is what
+// lets a path like `users[].name` act as a projection.
+fn resolve_path(value: &Value, parts: &[PathPart]) -> anyhow::Result<Value> {
+    match parts.split_first() {
+        None => Ok(value.clone()),
+        Some((PathPart::Key(key), rest)) => {
+            let object = value
+                .as_object()
+                .ok_or_else(|| anyhow::anyhow!("Cannot look up key '{}' on a non-object value", key))?;
+            let next = object
+                .get(key)
+                .ok_or_else(|| anyhow::anyhow!("Missing key '{}'", key))?;
+            resolve_path(next, rest)
+        }
+        Some((PathPart::ArrayIndex(index), rest)) => {
+            let array = value
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Cannot index with [{}] on a non-array value", index))?;
+            let next = array.get(*index).ok_or_else(|| {
+                anyhow::anyhow!(
+                    "Array index {} out of bounds (length {})",
+                    index,
+                    array.len()
+                )
+            })?;
+            resolve_path(next, rest)
+        }
+        Some((PathPart::ArrayAppend, rest)) => {
+            let array = value
+                .as_array()
+                .ok_or_else(|| anyhow::anyhow!("Cannot project [] over a non-array value"))?;
+
+            let matches = array
+                .iter()
+                .map(|item| resolve_path(item, rest))
+                .collect::<anyhow::Result<Vec<Value>>>()?;
+
+            Ok(Value::Array(matches))
+        }
+    }
+}
+
+// Remove the key or array element addressed by `parts` from `root`,
+// navigating the same way `resolve_path`/`set_nested_value` do. Erroring
+// on a missing key or out-of-bounds index, rather than treating it as a
+// no-op, matches `get`'s strictness about paths that don't exist.
+fn delete_path(root: &mut Value, parts: &[PathPart]) -> anyhow::Result<()> {
+    let Some((last, init)) = parts.split_last() else {
+        return Err(anyhow::anyhow!("Empty path"));
+    };
+
+    let mut current = root;
+    for part in init {
+        current = match part {
+            PathPart::Key(key) => current
+                .as_object_mut()
+                .and_then(|obj| obj.get_mut(key))
+                .ok_or_else(|| anyhow::anyhow!("Missing key '{}'", key))?,
+            PathPart::ArrayIndex(index) => current
+                .as_array_mut()
+                .and_then(|arr| arr.get_mut(*index))
+                .ok_or_else(|| anyhow::anyhow!("Array index {} out of bounds", index))?,
+            PathPart::ArrayAppend => {
+                return Err(anyhow::anyhow!(
+                    "Cannot traverse through [] when deleting; specify an index"
+                ));
+            }
+        };
+    }
+
+    match last {
+        PathPart::Key(key) => {
+            let object = current.as_object_mut().ok_or_else(|| {
+                anyhow::anyhow!("Cannot delete key '{}' from a non-object value", key)
+            })?;
+            object
+                .remove(key)
+                .ok_or_else(|| anyhow::anyhow!("Missing key '{}'", key))?;
+        }
+        PathPart::ArrayIndex(index) => {
+            let array = current.as_array_mut().ok_or_else(|| {
+                anyhow::anyhow!("Cannot delete index [{}] from a non-array value", index)
+            })?;
+            if *index >= array.len() {
+                return Err(anyhow::anyhow!(
+                    "Array index {} out of bounds (length {})",
+                    index,
+                    array.len()
+                ));
+            }
+            array.remove(*index);
+        }
+        PathPart::ArrayAppend => {
+            return Err(anyhow::anyhow!("Cannot delete via []; specify an index"));
+        }
+    }
+
+    Ok(())
+}
+
+// Walk a parsed JSON document and emit the Builder's key=value lines that
+// would reconstruct it: one line per leaf (scalar, or empty array/object,
+// since those have no children to recurse into). `prefix` is the path
+// built up so far - `None` means we're still at the document root.
+fn flatten_into(prefix: Option<&str>, value: &Value, lines: &mut Vec<String>) {
+    match value {
+        Value::Object(map) if !map.is_empty() => {
+            for (key, child) in map {
+                let segment = format_key_segment(key);
+                let next = match prefix {
+                    Some(p) => format!("{p}.{segment}"),
+                    None => segment,
+                };
+                flatten_into(Some(&next), child, lines);
+            }
+        }
+        Value::Array(items) if !items.is_empty() => {
+            for (index, child) in items.iter().enumerate() {
+                let next = format!("{}[{}]", prefix.unwrap_or(""), index);
+                flatten_into(Some(&next), child, lines);
+            }
+        }
+        _ => {
+            if let Some(path) = prefix {
+                lines.push(format!("{path}={}", format_leaf_value(value)));
+            }
         }
     }
 }
 
+// Quote a key segment if it contains characters that the path grammar
+// treats specially ('.', '[', '='), mirroring the quoting `quoted_key`
+// expects on the way back in.
+fn format_key_segment(key: &str) -> String {
+    if key.contains(['.', '[', '=', ' ']) {
+        format!("\"{key}\"")
+    } else {
+        key.to_string()
+    }
+}
+
+// Serialize a leaf JSON value the same way `json_value` would parse it
+// back, quoting strings that would otherwise be ambiguous with a bool,
+// null, a number, or that contain a delimiter json_value now stops at.
+fn format_leaf_value(value: &Value) -> String {
+    match value {
+        Value::String(s) => format_string_value(s),
+        Value::Null | Value::Bool(_) | Value::Number(_) => value.to_string(),
+        // Only reachable for an empty array/object leaf.
+        Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
+
+fn format_string_value(s: &str) -> String {
+    let ambiguous = s.is_empty()
+        || s == "true"
+        || s == "false"
+        || s == "null"
+        || s.parse::<f64>().is_ok()
+        || s.chars().any(|c| matches!(c, ',' | ']' | '}' | '"'));
+
+    if ambiguous {
+        format!("\"{}\"", escape_json_string(s))
+    } else {
+        s.to_string()
+    }
+}
+
+fn escape_json_string(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
+
 fn parse_input(input: &str) -> anyhow::Result<(Vec<PathPart>, Value)> {
     // Two-stage parsing:
     // 1. First, split input into key=value (input_parser)
@@ -60,7 +315,7 @@ fn parse_input(input: &str) -> anyhow::Result<(Vec<PathPart>, Value)> {
 
     match input_parser(input) {
         Ok((remaining, (path_str, value))) => {
-            if !remaining.is_empty() {
+            if !remaining.trim().is_empty() {
                 return Err(anyhow::anyhow!(
                     "Failed to parse input completely, remaining: '{}'",
                     remaining
@@ -85,26 +340,83 @@ fn parse_input(input: &str) -> anyhow::Result<(Vec<PathPart>, Value)> {
 }
 
 // Parse the input at the '=' separator level
-// Extracts the raw key string (before '=') and parses the value (after '=')
-// The key string is returned as-is for later path parsing
+// Extracts the raw key string (before '=', and before an optional ':type'
+// tag) and parses the value (after '='). The key string is returned as-is
+// for later path parsing. A type tag, if present, bypasses json_value's
+// auto-detection and coerces the value to exactly that type.
 fn input_parser(input: &str) -> IResult<&str, (String, Value)> {
-    let (input, key) = parse_key(input)?;
+    let (input, (key, type_tag)) = parse_key(input)?;
     let (input, _) = char('=')(input)?;
-    let (input, value) = json_value(input)?;
+    let (input, value) = match type_tag {
+        Some(type_tag) => typed_value(type_tag, input)?,
+        None => json_value(input)?,
+    };
 
     Ok((input, (key, value)))
 }
 
-// Parse a key before the '=' separator
-// This extracts the key string but doesn't interpret it as a path yet
-fn parse_key(input: &str) -> IResult<&str, String> {
-    alt((
+// Parse a key before the '=' separator, along with an optional ':type'
+// annotation (e.g. `port:int`). This extracts the key string but doesn't
+// interpret it as a path yet.
+fn parse_key(input: &str) -> IResult<&str, (String, Option<TypeTag>)> {
+    let (input, key) = alt((
         quoted_key,
-        // Unquoted key - everything before '='
-        map(take_while1(|c: char| c != '='), |s: &str| s.to_string()),
+        // Unquoted key - everything before '=' or a ':type' tag
+        map(take_while1(|c: char| c != '=' && c != ':'), |s: &str| {
+            s.to_string()
+        }),
+    ))(input)?;
+    let (input, type_tag) = opt(preceded(char(':'), type_tag))(input)?;
+
+    Ok((input, (key, type_tag)))
+}
+
+// An explicit type annotation on a key, e.g. `port:int`, `id:str`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TypeTag {
+    Int,
+    Str,
+    Float,
+    Bool,
+    Json,
+}
+
+// Parse one of the recognized ':type' tag names
+fn type_tag(input: &str) -> IResult<&str, TypeTag> {
+    alt((
+        map(tag("int"), |_| TypeTag::Int),
+        map(tag("str"), |_| TypeTag::Str),
+        map(tag("float"), |_| TypeTag::Float),
+        map(tag("bool"), |_| TypeTag::Bool),
+        map(tag("json"), |_| TypeTag::Json),
     ))(input)
 }
 
+// Parse a value that's been explicitly tagged with a type, coercing
+// straight to it instead of letting json_value guess. Unlike the
+// auto-detected path this never falls back to a bare string, so a
+// mismatch (e.g. `port:int=abc`) is a parse error.
+fn typed_value(type_tag: TypeTag, input: &str) -> IResult<&str, Value> {
+    let input = input.trim_start();
+
+    match type_tag {
+        TypeTag::Int => integer(input),
+        TypeTag::Float => alt((
+            float,
+            map(integer, |v: Value| json!(v.as_i64().unwrap() as f64)),
+        ))(input),
+        TypeTag::Bool => boolean(input),
+        TypeTag::Str => {
+            if input.starts_with('"') {
+                quoted_string(input)
+            } else {
+                map(nom::combinator::rest, |s: &str| json!(s))(input)
+            }
+        }
+        TypeTag::Json => json_value(input),
+    }
+}
+
 // Parse a quoted key (e.g., "hello world" in path)
 fn quoted_key(input: &str) -> IResult<&str, String> {
     map(
@@ -114,11 +426,15 @@ fn quoted_key(input: &str) -> IResult<&str, String> {
 }
 
 // Parse any JSON value with type detection
-// Tries parsers in order: quoted string, boolean, null, float, integer, unquoted string
+// Tries parsers in order: object, array, quoted string, boolean, null, float, integer, unquoted string
+// Objects and arrays recurse back into json_value for their elements, so
+// values can be arbitrarily nested (e.g. a.config={"x": [1, 2], "y": true}).
 fn json_value(input: &str) -> IResult<&str, Value> {
-    let input = input.trim();
+    let input = input.trim_start();
 
     alt((
+        json_object,
+        json_array,
         quoted_string,
         boolean,
         null,
@@ -128,14 +444,124 @@ fn json_value(input: &str) -> IResult<&str, Value> {
     ))(input)
 }
 
-// Parse a quoted string value (e.g., "hello world")
-fn quoted_string(input: &str) -> IResult<&str, Value> {
+// Parse a JSON object literal (e.g. {"a": 1, "b": [true, null]})
+fn json_object(input: &str) -> IResult<&str, Value> {
     map(
-        delimited(char('"'), take_until("\""), char('"')),
-        |s: &str| json!(s),
+        delimited(
+            char('{'),
+            separated_list0(preceded(multispace0, char(',')), json_member),
+            preceded(multispace0, char('}')),
+        ),
+        |members| {
+            let mut object = serde_json::Map::new();
+            for (key, value) in members {
+                object.insert(key, value);
+            }
+            Value::Object(object)
+        },
     )(input)
 }
 
+// Parse a single "key": value member of a JSON object
+fn json_member(input: &str) -> IResult<&str, (String, Value)> {
+    let (input, key) = preceded(multispace0, json_string_literal)(input)?;
+    let (input, _) = preceded(multispace0, char(':'))(input)?;
+    let (input, value) = preceded(multispace0, json_value)(input)?;
+
+    Ok((input, (key, value)))
+}
+
+// Parse a JSON array literal (e.g. [1, "two", [3]])
+fn json_array(input: &str) -> IResult<&str, Value> {
+    map(
+        delimited(
+            char('['),
+            separated_list0(
+                preceded(multispace0, char(',')),
+                preceded(multispace0, json_value),
+            ),
+            preceded(multispace0, char(']')),
+        ),
+        Value::Array,
+    )(input)
+}
+
+// Parse a quoted string value (e.g., "hello world")
+fn quoted_string(input: &str) -> IResult<&str, Value> {
+    map(json_string_literal, |s| json!(s))(input)
+}
+
+// Parse a double-quoted JSON string literal, decoding escape sequences
+// (\", \\, \/, \n, \t, \r, \b, \f, \uXXXX) into the string it represents.
+// Unlike `take_until`, this has to walk the content by hand so a `\"`
+// doesn't end the string early.
+fn json_string_literal(input: &str) -> IResult<&str, String> {
+    let (mut rest, _) = char('"')(input)?;
+    let mut out = String::new();
+
+    loop {
+        let mut chars = rest.chars();
+        match chars.next() {
+            None => {
+                return Err(nom::Err::Error(nom::error::Error::new(
+                    rest,
+                    nom::error::ErrorKind::Eof,
+                )));
+            }
+            Some('"') => {
+                rest = chars.as_str();
+                break;
+            }
+            Some('\\') => {
+                let escape = chars.next().ok_or_else(|| {
+                    nom::Err::Error(nom::error::Error::new(rest, nom::error::ErrorKind::Eof))
+                })?;
+
+                match escape {
+                    '"' => out.push('"'),
+                    '\\' => out.push('\\'),
+                    '/' => out.push('/'),
+                    'n' => out.push('\n'),
+                    't' => out.push('\t'),
+                    'r' => out.push('\r'),
+                    'b' => out.push('\u{8}'),
+                    'f' => out.push('\u{c}'),
+                    'u' => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        let code = u32::from_str_radix(&hex, 16).map_err(|_| {
+                            nom::Err::Error(nom::error::Error::new(
+                                rest,
+                                nom::error::ErrorKind::Digit,
+                            ))
+                        })?;
+                        let ch = char::from_u32(code).ok_or_else(|| {
+                            nom::Err::Error(nom::error::Error::new(
+                                rest,
+                                nom::error::ErrorKind::Digit,
+                            ))
+                        })?;
+                        out.push(ch);
+                    }
+                    _ => {
+                        return Err(nom::Err::Error(nom::error::Error::new(
+                            rest,
+                            nom::error::ErrorKind::Escaped,
+                        )));
+                    }
+                }
+
+                rest = chars.as_str();
+            }
+            Some(c) => {
+                rest = chars.as_str();
+                out.push(c);
+            }
+        }
+    }
+
+    Ok((rest, out))
+}
+
 // Parse a boolean value (true or false)
 fn boolean(input: &str) -> IResult<&str, Value> {
     alt((
@@ -174,9 +600,16 @@ fn integer(input: &str) -> IResult<&str, Value> {
     })(input)
 }
 
-// Parse an unquoted string (fallback - consumes rest of input)
+// Parse an unquoted string (fallback). Stops at a delimiter that would
+// otherwise belong to an enclosing array/object (`,`, `]`, `}`) so a bare
+// scalar nested inside one of those doesn't swallow the rest of it;
+// outside of a container there's normally nothing left to stop at, so this
+// still consumes the whole remaining input like it used to.
 fn unquoted_string(input: &str) -> IResult<&str, Value> {
-    map(nom::combinator::rest, |s: &str| json!(s))(input)
+    map(
+        take_while1(|c: char| c != ',' && c != ']' && c != '}'),
+        |s: &str| json!(s),
+    )(input)
 }
 
 // Parse a complete path into PathPart components
@@ -360,6 +793,8 @@ mod tests {
         let tool = JsonTool {
             command: JsonCommand::Builder {
                 inputs: vec!["a.b.c=hello".to_string()],
+                base: None,
+                delete: vec![],
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -376,6 +811,8 @@ mod tests {
         let tool = JsonTool {
             command: JsonCommand::Builder {
                 inputs: vec!["k.d.l=true".to_string()],
+                base: None,
+                delete: vec![],
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -392,6 +829,8 @@ mod tests {
         let tool = JsonTool {
             command: JsonCommand::Builder {
                 inputs: vec!["a.b[].c=1".to_string(), "a.b[].c=2".to_string()],
+                base: None,
+                delete: vec![],
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -409,6 +848,8 @@ mod tests {
         let tool = JsonTool {
             command: JsonCommand::Builder {
                 inputs: vec!["a.b[3].c=hello".to_string()],
+                base: None,
+                delete: vec![],
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -426,6 +867,8 @@ mod tests {
         let tool = JsonTool {
             command: JsonCommand::Builder {
                 inputs: vec![r#""hello world"=test"#.to_string()],
+                base: None,
+                delete: vec![],
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -436,4 +879,363 @@ mod tests {
             panic!("Expected Text output");
         }
     }
+
+    #[test]
+    fn test_inline_array_value() {
+        let (remaining, value) = json_value("[1, 2, 3]").unwrap();
+        assert_eq!(remaining, "");
+        assert_eq!(value, serde_json::json!([1, 2, 3]));
+    }
+
+    #[test]
+    fn test_inline_nested_object_value() {
+        let (remaining, value) = json_value(r#"{"x": 1, "y": [true, null]}"#).unwrap();
+        assert_eq!(remaining, "");
+        assert_eq!(value, serde_json::json!({"x": 1, "y": [true, null]}));
+    }
+
+    #[test]
+    fn test_builder_accepts_inline_array() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["a.b=[1, 2, 3]".to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(value["a"]["b"], serde_json::json!([1, 2, 3]));
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_builder_accepts_inline_object() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec![r#"a.config={"enabled": true, "retries": 3}"#.to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(
+                value["a"]["config"],
+                serde_json::json!({"enabled": true, "retries": 3})
+            );
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_quoted_string_escape_sequences() {
+        let (remaining, value) = json_value(r#""line\nbreak \"quoted\" A""#).unwrap();
+        assert_eq!(remaining, "");
+        assert_eq!(value, json!("line\nbreak \"quoted\" A"));
+    }
+
+    #[test]
+    fn test_flatten_round_trips_through_builder() {
+        let tool = JsonTool {
+            command: JsonCommand::Flatten {
+                input: StringInput(r#"{"a":{"b":[{"c":1}]}}"#.to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        assert_eq!(text, "a.b[0].c=1");
+
+        let rebuild = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: text.lines().map(|l| l.to_string()).collect(),
+                base: None,
+                delete: vec![],
+            },
+        };
+        let rebuilt = rebuild.execute().unwrap().unwrap();
+        let Output::Text(rebuilt_text) = rebuilt else {
+            panic!("Expected Text output");
+        };
+        let value: Value = serde_json::from_str(&rebuilt_text).unwrap();
+        assert_eq!(value, serde_json::json!({"a": {"b": [{"c": 1}]}}));
+    }
+
+    #[test]
+    fn test_flatten_quotes_ambiguous_and_special_keys() {
+        let tool = JsonTool {
+            command: JsonCommand::Flatten {
+                input: StringInput(r#"{"hello world": "true", "x.y": 2}"#.to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        let lines: Vec<&str> = text.lines().collect();
+        assert!(lines.contains(&r#""hello world"="true""#));
+        assert!(lines.contains(&r#""x.y"=2"#));
+    }
+
+    #[test]
+    fn test_type_tag_str_preserves_leading_zeros() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["id:str=007".to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(value["id"], "007");
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_type_tag_str_keeps_version_as_string() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["version:str=1.0".to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(value["version"], "1.0");
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_type_tag_float_coerces_integer_literal() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["ratio:float=3".to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(value["ratio"], 3.0);
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_type_tag_bool_and_json() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec![
+                base: None,
+                delete: vec![],
+                    "enabled:bool=true".to_string(),
+                    "data:json=[1,2,3]".to_string(),
+                ],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(value["enabled"], true);
+            assert_eq!(value["data"], serde_json::json!([1, 2, 3]));
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_type_tag_mismatch_is_an_error() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["port:int=abc".to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_untagged_key_keeps_auto_detection() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["version=1.0".to_string()],
+                base: None,
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        if let Output::Text(text) = result {
+            let value: Value = serde_json::from_str(&text).unwrap();
+            assert_eq!(value["version"], 1.0);
+        } else {
+            panic!("Expected Text output");
+        }
+    }
+
+    #[test]
+    fn test_get_nested_key() {
+        let tool = JsonTool {
+            command: JsonCommand::Get {
+                input: StringInput(r#"{"a":{"b":{"c":1}}}"#.to_string()),
+                path: "a.b.c".to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(value) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(value, serde_json::json!(1));
+    }
+
+    #[test]
+    fn test_get_array_index() {
+        let tool = JsonTool {
+            command: JsonCommand::Get {
+                input: StringInput(r#"{"a":[10,20,30]}"#.to_string()),
+                path: "a[1]".to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(value) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(value, serde_json::json!(20));
+    }
+
+    #[test]
+    fn test_get_array_projection() {
+        let tool = JsonTool {
+            command: JsonCommand::Get {
+                input: StringInput(
+                    r#"{"users":[{"name":"Ada"},{"name":"Grace"}]}"#.to_string(),
+                ),
+                path: "users[].name".to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::JsonValue(value) = result else {
+            panic!("Expected JsonValue output");
+        };
+        assert_eq!(value, serde_json::json!(["Ada", "Grace"]));
+    }
+
+    #[test]
+    fn test_get_missing_key_errors() {
+        let tool = JsonTool {
+            command: JsonCommand::Get {
+                input: StringInput(r#"{"a":1}"#.to_string()),
+                path: "b".to_string(),
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_get_out_of_bounds_errors() {
+        let tool = JsonTool {
+            command: JsonCommand::Get {
+                input: StringInput(r#"{"a":[1]}"#.to_string()),
+                path: "a[5]".to_string(),
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_flatten_empty_object_is_empty() {
+        let tool = JsonTool {
+            command: JsonCommand::Flatten {
+                input: StringInput("{}".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        assert_eq!(text, "");
+    }
+
+    #[test]
+    fn test_builder_base_seeds_document() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["server.port:int=9090".to_string()],
+                base: Some(StringInput(r#"{"server":{"host":"localhost"}}"#.to_string())),
+                delete: vec![],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["server"]["host"], "localhost");
+        assert_eq!(value["server"]["port"], 9090);
+    }
+
+    #[test]
+    fn test_builder_delete_removes_key() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec!["a.c=1".to_string()],
+                base: Some(StringInput(r#"{"a":{"b":"remove me"}}"#.to_string())),
+                delete: vec!["a.b".to_string()],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["a"]["b"], Value::Null);
+        assert_eq!(value["a"]["c"], 1);
+        assert!(!value["a"].as_object().unwrap().contains_key("b"));
+    }
+
+    #[test]
+    fn test_builder_delete_removes_array_element() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec![],
+                base: Some(StringInput(r#"{"a":[1,2,3]}"#.to_string())),
+                delete: vec!["a[1]".to_string()],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        let value: Value = serde_json::from_str(&text).unwrap();
+        assert_eq!(value["a"], serde_json::json!([1, 3]));
+    }
+
+    #[test]
+    fn test_builder_delete_missing_key_errors() {
+        let tool = JsonTool {
+            command: JsonCommand::Builder {
+                inputs: vec![],
+                base: Some(StringInput(r#"{"a":1}"#.to_string())),
+                delete: vec!["b".to_string()],
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
 }
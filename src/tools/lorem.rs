@@ -1,11 +1,14 @@
 use crate::tool::{Output, Tool};
-use clap::{Command, CommandFactory, Parser};
-use rand::{Rng, rngs::OsRng};
+use clap::{Command, CommandFactory, Parser, ValueEnum};
+use rand::{
+    Rng, SeedableRng,
+    rngs::{OsRng, StdRng},
+};
 
 #[derive(Parser, Debug)]
 #[command(name = "lorem")]
 pub struct Lorem {
-    /// Number of paragraphs to generate
+    /// Number of paragraphs to generate (ignored when --units is words or sentences)
     #[arg(short = 'p', long = "paragraphs", default_value = "3")]
     paragraphs: usize,
 
@@ -24,12 +27,46 @@ pub struct Lorem {
     /// Maximum number of words per sentence
     #[arg(long = "max-words", default_value = "15")]
     max_words: usize,
+
+    /// Seed the generator for reproducible output. Uses OsRng when omitted
+    #[arg(long)]
+    seed: Option<u64>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value = "text")]
+    format: Format,
+
+    /// Unit to count with --paragraphs/--count
+    #[arg(long, value_enum, default_value = "paragraphs")]
+    units: Units,
+
+    /// Number of units to generate when --units is words or sentences
+    #[arg(long)]
+    count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Html,
+    Markdown,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Units {
+    Paragraphs,
+    Sentences,
+    Words,
 }
 
 impl Lorem {
+    fn generate_word(&self, rng: &mut impl Rng) -> &'static str {
+        LOREM_WORDS[rng.gen_range(0..LOREM_WORDS.len())]
+    }
+
     fn generate_sentence(&self, rng: &mut impl Rng) -> String {
         let sentence = (0..rng.gen_range(self.min_words..=self.max_words))
-            .map(|_| LOREM_WORDS[rng.gen_range(0..LOREM_WORDS.len())])
+            .map(|_| self.generate_word(rng))
             .collect::<Vec<&str>>()
             .join(" ");
 
@@ -43,13 +80,48 @@ impl Lorem {
             .join(" ")
     }
 
+    fn generate_paragraphs(&self, rng: &mut impl Rng, count: usize) -> Vec<String> {
+        (0..count).map(|_| self.generate_paragraph(rng)).collect()
+    }
+
+    fn generate_sentences(&self, rng: &mut impl Rng, count: usize) -> Vec<String> {
+        (0..count).map(|_| self.generate_sentence(rng)).collect()
+    }
+
+    fn generate_words(&self, rng: &mut impl Rng, count: usize) -> Vec<String> {
+        (0..count)
+            .map(|_| self.generate_word(rng).to_string())
+            .collect()
+    }
+
+    // Generates the requested units using the given RNG, returning one
+    // chunk of text per unit so formatting can join/wrap them consistently.
+    fn generate(&self, rng: &mut impl Rng) -> Vec<String> {
+        match self.units {
+            Units::Paragraphs => self.generate_paragraphs(rng, self.paragraphs),
+            Units::Sentences => self.generate_sentences(rng, self.count.unwrap_or(self.paragraphs)),
+            Units::Words => self.generate_words(rng, self.count.unwrap_or(self.paragraphs)),
+        }
+    }
+
     fn generate_lorem(&self) -> String {
-        let mut rng = OsRng;
+        let chunks = match self.seed {
+            Some(seed) => self.generate(&mut StdRng::seed_from_u64(seed)),
+            None => self.generate(&mut OsRng),
+        };
 
-        (0..self.paragraphs)
-            .map(|_| self.generate_paragraph(&mut rng))
-            .collect::<Vec<String>>()
-            .join("\n\n")
+        match self.units {
+            Units::Words => chunks.join(" "),
+            Units::Sentences | Units::Paragraphs => match self.format {
+                Format::Text => chunks.join("\n\n"),
+                Format::Markdown => chunks.join("\n\n"),
+                Format::Html => chunks
+                    .iter()
+                    .map(|chunk| format!("<p>{chunk}</p>"))
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            },
+        }
     }
 }
 
@@ -249,3 +321,49 @@ const LOREM_WORDS: &[&str] = &[
     "scelerisque",
     "varius",
 ];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lorem(seed: Option<u64>, format: Format, units: Units, count: Option<usize>) -> Lorem {
+        Lorem {
+            paragraphs: 2,
+            min_sentences: 2,
+            max_sentences: 4,
+            min_words: 3,
+            max_words: 6,
+            seed,
+            format,
+            units,
+            count,
+        }
+    }
+
+    #[test]
+    fn test_same_seed_is_deterministic() {
+        let a = lorem(Some(42), Format::Text, Units::Paragraphs, None).generate_lorem();
+        let b = lorem(Some(42), Format::Text, Units::Paragraphs, None).generate_lorem();
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_different_seeds_differ() {
+        let a = lorem(Some(1), Format::Text, Units::Paragraphs, None).generate_lorem();
+        let b = lorem(Some(2), Format::Text, Units::Paragraphs, None).generate_lorem();
+        assert_ne!(a, b);
+    }
+
+    #[test]
+    fn test_html_format_wraps_paragraphs() {
+        let text = lorem(Some(1), Format::Html, Units::Paragraphs, None).generate_lorem();
+        assert!(text.starts_with("<p>"));
+        assert!(text.ends_with("</p>"));
+    }
+
+    #[test]
+    fn test_words_units_ignores_sentence_structure() {
+        let text = lorem(Some(1), Format::Text, Units::Words, Some(5)).generate_lorem();
+        assert_eq!(text.split(' ').count(), 5);
+    }
+}
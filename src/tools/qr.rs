@@ -1,7 +1,9 @@
 use crate::tool::{Output, Tool};
 use anyhow::{Context, Result};
-use clap::{Command, CommandFactory, Parser};
-use qrcode::QrCode;
+use clap::{Command, CommandFactory, Parser, ValueEnum};
+use qrcode::{EcLevel, QrCode, render::svg};
+use std::fs;
+use std::io::Cursor;
 use std::path::PathBuf;
 
 #[derive(Parser, Debug)]
@@ -10,9 +12,48 @@ pub struct QRTool {
     /// The text or URL to encode as QR code
     text: String,
 
-    /// Save QR code to file (PNG format)
+    /// Save QR code to file
     #[arg(short, long)]
     output: Option<PathBuf>,
+
+    /// Output format. Defaults to png when --output is set, ascii otherwise
+    #[arg(long, value_enum)]
+    format: Option<Format>,
+
+    /// Error-correction level, trading capacity for damage resistance
+    #[arg(long, value_enum, default_value = "m")]
+    ecc: ErrorCorrection,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Ascii,
+    Png,
+    Svg,
+}
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum ErrorCorrection {
+    L,
+    M,
+    Q,
+    H,
+}
+
+impl ErrorCorrection {
+    fn to_level(self) -> EcLevel {
+        match self {
+            ErrorCorrection::L => EcLevel::L,
+            ErrorCorrection::M => EcLevel::M,
+            ErrorCorrection::Q => EcLevel::Q,
+            ErrorCorrection::H => EcLevel::H,
+        }
+    }
+}
+
+enum Rendered {
+    Text(String),
+    Bytes(Vec<u8>),
 }
 
 impl Tool for QRTool {
@@ -21,29 +62,133 @@ impl Tool for QRTool {
     }
 
     fn execute(&self) -> Result<Option<Output>> {
-        let code = QrCode::new(&self.text).context("Failed to generate QR code")?;
-
-        if let Some(output_path) = &self.output {
-            // Save to file
-            let image = code
-                .render::<image::Luma<u8>>()
-                .max_dimensions(512, 512)
-                .build();
-
-            image
-                .save(output_path)
-                .context("Failed to save QR code image")?;
-
-            Ok(None)
-        } else {
-            // Display in terminal
-            let string = code
-                .render::<char>()
-                .quiet_zone(false)
-                .module_dimensions(2, 1)
-                .build();
-
-            Ok(Some(Output::Text(string)))
+        let code = QrCode::with_error_correction_level(&self.text, self.ecc.to_level())
+            .context("Failed to generate QR code")?;
+
+        let format = self
+            .format
+            .unwrap_or(if self.output.is_some() {
+                Format::Png
+            } else {
+                Format::Ascii
+            });
+
+        let rendered = match format {
+            Format::Ascii => Rendered::Text(
+                code.render::<char>()
+                    .quiet_zone(false)
+                    .module_dimensions(2, 1)
+                    .build(),
+            ),
+            Format::Png => {
+                let image = code
+                    .render::<image::Luma<u8>>()
+                    .max_dimensions(512, 512)
+                    .build();
+
+                let mut bytes = Vec::new();
+                image
+                    .write_to(&mut Cursor::new(&mut bytes), image::ImageFormat::Png)
+                    .context("Failed to encode QR code as PNG")?;
+
+                Rendered::Bytes(bytes)
+            }
+            Format::Svg => Rendered::Text(
+                code.render()
+                    .min_dimensions(512, 512)
+                    .dark_color(svg::Color("#000000"))
+                    .light_color(svg::Color("#ffffff"))
+                    .build(),
+            ),
+        };
+
+        match (rendered, &self.output) {
+            (Rendered::Text(text), Some(path)) => {
+                fs::write(path, &text).context("Failed to save QR code")?;
+                Ok(None)
+            }
+            (Rendered::Text(text), None) => Ok(Some(Output::Text(text))),
+            (Rendered::Bytes(bytes), Some(path)) => {
+                fs::write(path, &bytes).context("Failed to save QR code image")?;
+                Ok(None)
+            }
+            (Rendered::Bytes(bytes), None) => Ok(Some(Output::Bytes(bytes))),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_ascii_output() {
+        let tool = QRTool {
+            text: "hello".to_string(),
+            output: None,
+            format: None,
+            ecc: ErrorCorrection::M,
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        assert!(!text.is_empty());
+    }
+
+    #[test]
+    fn test_svg_output_contains_svg_tag() {
+        let tool = QRTool {
+            text: "hello".to_string(),
+            output: None,
+            format: Some(Format::Svg),
+            ecc: ErrorCorrection::M,
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Text(text) = result else {
+            panic!("Expected Text output");
+        };
+        assert!(text.contains("<svg"));
+    }
+
+    #[test]
+    fn test_png_output_is_valid_png_bytes() {
+        let tool = QRTool {
+            text: "hello".to_string(),
+            output: None,
+            format: Some(Format::Png),
+            ecc: ErrorCorrection::M,
+        };
+        let result = tool.execute().unwrap().unwrap();
+        let Output::Bytes(bytes) = result else {
+            panic!("Expected Bytes output");
+        };
+        assert_eq!(&bytes[..8], &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]);
+    }
+
+    #[test]
+    fn test_output_path_defaults_to_png_format() {
+        let dir = std::env::temp_dir();
+        let path = dir.join("ut_qr_test_default_format.png");
+
+        let tool = QRTool {
+            text: "hello".to_string(),
+            output: Some(path.clone()),
+            format: None,
+            ecc: ErrorCorrection::M,
+        };
+        let result = tool.execute().unwrap();
+        assert!(result.is_none());
+
+        let bytes = fs::read(&path).unwrap();
+        assert_eq!(&bytes[..8], &[0x89, 0x50, 0x4E, 0x47, 0x0D, 0x0A, 0x1A, 0x0A]);
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_higher_ecc_level_increases_code_size() {
+        let low = QrCode::with_error_correction_level("some payload text", EcLevel::L).unwrap();
+        let high = QrCode::with_error_correction_level("some payload text", EcLevel::H).unwrap();
+        assert!(high.width() >= low.width());
+    }
+}
@@ -8,7 +8,7 @@ use ratatui::{
     Terminal,
     backend::CrosstermBackend,
     layout::{Constraint, Direction, Layout, Rect},
-    style::{Color, Style, Stylize},
+    style::{Color, Modifier, Style, Stylize},
     text::{Line, Span, Text},
     widgets::{Block, BorderType, Borders, Padding, Paragraph},
 };
@@ -59,6 +59,7 @@ impl Tool for RegexTool {
 enum InputFocus {
     Regex,
     Sample,
+    Replace,
 }
 
 struct App<'a> {
@@ -66,9 +67,15 @@ struct App<'a> {
 
     regex_textarea: TextArea<'a>,
     sample_textarea: TextArea<'a>,
+    replace_textarea: TextArea<'a>,
 
     compiled_regex: Option<Regex>,
     regex_error: Option<String>,
+
+    replaced_text: String,
+
+    show_match_details: bool,
+    match_details_scroll: u16,
 }
 
 impl<'a> Default for App<'a> {
@@ -79,14 +86,23 @@ impl<'a> Default for App<'a> {
         let mut sample_textarea = TextArea::default();
         sample_textarea.set_cursor_line_style(Style::new());
 
+        let mut replace_textarea = TextArea::default();
+        replace_textarea.set_cursor_line_style(Style::new());
+
         App {
             input_focus: InputFocus::Sample,
 
             regex_textarea,
             sample_textarea,
+            replace_textarea,
 
             compiled_regex: None,
             regex_error: None,
+
+            replaced_text: String::new(),
+
+            show_match_details: false,
+            match_details_scroll: 0,
         }
     }
 }
@@ -120,23 +136,27 @@ impl<'a> App<'a> {
         }
     }
 
+    // Recomputes the substitution preview. Supports `$1`/`${name}` group
+    // references via `Regex::replace_all`'s own template syntax. Falls back
+    // to the raw sample text, unchanged, so the pane is never blank while
+    // the pattern doesn't compile.
+    fn recompute_replacement(&mut self) {
+        let sample_text = self.get_sample_text();
+        let template = self.replace_textarea.lines().join("\n");
+
+        self.replaced_text = match &self.compiled_regex {
+            Some(regex) => regex.replace_all(&sample_text, template.as_str()).into_owned(),
+            None => sample_text,
+        };
+    }
+
     fn get_highlighted_text(&self) -> Text<'static> {
         let sample_text = self.get_sample_text();
         let Some(regex) = &self.compiled_regex else {
             return Text::from(sample_text);
         };
 
-        let highlight_styles = &[
-            Style::new().bg(Color::LightBlue).fg(Color::Black),
-            Style::new().bg(Color::LightGreen).fg(Color::Black),
-            Style::new().bg(Color::LightRed).fg(Color::Black),
-            Style::new().bg(Color::LightYellow).fg(Color::Black),
-            Style::new().bg(Color::Blue).fg(Color::Black),
-            Style::new().bg(Color::Green).fg(Color::Black),
-            Style::new().bg(Color::Red).fg(Color::White),
-            Style::new().bg(Color::Yellow).fg(Color::Black),
-            Style::new().bg(Color::Magenta).fg(Color::White),
-        ];
+        let highlight_styles = highlight_styles();
 
         let mut highlights: Vec<(usize, usize, Style)> = vec![];
         for capture in regex.captures_iter(&sample_text) {
@@ -202,6 +222,81 @@ impl<'a> App<'a> {
         lines.push(current_line);
         Text::from(lines)
     }
+
+    // Every match, with the byte span and text of the full match and each
+    // of its capture groups, for the match-detail inspector panel.
+    fn get_match_details(&self) -> Vec<MatchDetail> {
+        let sample_text = self.get_sample_text();
+        let Some(regex) = &self.compiled_regex else {
+            return vec![];
+        };
+
+        let group_names: Vec<Option<String>> = regex
+            .capture_names()
+            .map(|name| name.map(|name| name.to_string()))
+            .collect();
+
+        regex
+            .captures_iter(&sample_text)
+            .map(|capture| {
+                let full_match = capture
+                    .get(0)
+                    .expect("group 0 is always present for a match");
+
+                let captures = capture
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(group_index, submatch)| {
+                        let submatch = submatch?;
+                        Some(CaptureDetail {
+                            group_index,
+                            group_name: group_names.get(group_index).cloned().flatten(),
+                            text: submatch.as_str().to_string(),
+                            start: submatch.start(),
+                            end: submatch.end(),
+                        })
+                    })
+                    .collect();
+
+                MatchDetail {
+                    start: full_match.start(),
+                    end: full_match.end(),
+                    captures,
+                }
+            })
+            .collect()
+    }
+}
+
+// The colors used to highlight each capture group, shared between the
+// sample-text highlighting and the match-detail panel so a group's color
+// means the same thing in both places.
+fn highlight_styles() -> [Style; 9] {
+    [
+        Style::new().bg(Color::LightBlue).fg(Color::Black),
+        Style::new().bg(Color::LightGreen).fg(Color::Black),
+        Style::new().bg(Color::LightRed).fg(Color::Black),
+        Style::new().bg(Color::LightYellow).fg(Color::Black),
+        Style::new().bg(Color::Blue).fg(Color::Black),
+        Style::new().bg(Color::Green).fg(Color::Black),
+        Style::new().bg(Color::Red).fg(Color::White),
+        Style::new().bg(Color::Yellow).fg(Color::Black),
+        Style::new().bg(Color::Magenta).fg(Color::White),
+    ]
+}
+
+struct CaptureDetail {
+    group_index: usize,
+    group_name: Option<String>,
+    text: String,
+    start: usize,
+    end: usize,
+}
+
+struct MatchDetail {
+    start: usize,
+    end: usize,
+    captures: Vec<CaptureDetail>,
 }
 
 fn run_app_loop(
@@ -222,11 +317,37 @@ fn run_app_loop(
                     return Ok(());
                 }
 
-                // Handle Tab to switch focus.
-                if matches!(key.code, KeyCode::Tab | KeyCode::BackTab) {
+                // Toggle the match-detail inspector panel.
+                if matches!(key.code, KeyCode::F(2)) {
+                    app.show_match_details = !app.show_match_details;
+                    continue;
+                }
+
+                // Scroll the match-detail panel, regardless of which field
+                // has focus, since it isn't part of the Tab cycle.
+                if matches!(key.code, KeyCode::PageUp) {
+                    app.match_details_scroll = app.match_details_scroll.saturating_sub(1);
+                    continue;
+                }
+                if matches!(key.code, KeyCode::PageDown) {
+                    app.match_details_scroll = app.match_details_scroll.saturating_add(1);
+                    continue;
+                }
+
+                // Handle Tab/Shift+Tab to cycle focus between the three fields.
+                if matches!(key.code, KeyCode::Tab) {
                     app.input_focus = match app.input_focus {
                         InputFocus::Regex => InputFocus::Sample,
+                        InputFocus::Sample => InputFocus::Replace,
+                        InputFocus::Replace => InputFocus::Regex,
+                    };
+                    continue;
+                }
+                if matches!(key.code, KeyCode::BackTab) {
+                    app.input_focus = match app.input_focus {
+                        InputFocus::Regex => InputFocus::Replace,
                         InputFocus::Sample => InputFocus::Regex,
+                        InputFocus::Replace => InputFocus::Sample,
                     };
                     continue;
                 }
@@ -245,9 +366,15 @@ fn run_app_loop(
                     InputFocus::Regex => {
                         app.regex_textarea.input(input);
                         app.compile_regex(); // TODO: Do this in a worker thread.
+                        app.recompute_replacement();
                     }
                     InputFocus::Sample => {
                         app.sample_textarea.input(input);
+                        app.recompute_replacement();
+                    }
+                    InputFocus::Replace => {
+                        app.replace_textarea.input(input);
+                        app.recompute_replacement();
                     }
                 }
             }
@@ -255,8 +382,30 @@ fn run_app_loop(
     }
 }
 
+// Areas for the four focusable/renderable regions of the body.
+struct BodyAreas {
+    regex_label: Rect,
+    regex: Rect,
+    sample_label: Rect,
+    sample: Rect,
+    replace_label: Rect,
+    replace: Rect,
+    replaced_label: Rect,
+    replaced: Rect,
+}
+
 // Draw the UI.
 fn draw_ui(f: &mut ratatui::Frame, app: &mut App) {
+    let (body_area, details_area) = if app.show_match_details {
+        let split = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(65), Constraint::Percentage(35)])
+            .split(f.area());
+        (split[0], Some(split[1]))
+    } else {
+        (f.area(), None)
+    };
+
     let chunks = Layout::default()
         .direction(Direction::Vertical)
         .constraints([
@@ -265,16 +414,39 @@ fn draw_ui(f: &mut ratatui::Frame, app: &mut App) {
             Constraint::Length(1), // Regex
             Constraint::Length(1), // Spacer
             Constraint::Length(1), // Label
-            Constraint::Min(8),    // Sample
+            Constraint::Min(6),    // Sample
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Label
+            Constraint::Length(1), // Replace
+            Constraint::Length(1), // Spacer
+            Constraint::Length(1), // Label
+            Constraint::Min(6),    // Replaced
             Constraint::Length(1), // Spacer
             Constraint::Length(1), // Help
         ])
         .horizontal_margin(2)
         .vertical_margin(1)
-        .split(f.area());
+        .split(body_area);
+
+    draw_body(
+        f,
+        app,
+        BodyAreas {
+            regex_label: chunks[1],
+            regex: chunks[2],
+            sample_label: chunks[4],
+            sample: chunks[5],
+            replace_label: chunks[7],
+            replace: chunks[8],
+            replaced_label: chunks[10],
+            replaced: chunks[11],
+        },
+    );
+    draw_help(f, chunks[13]);
 
-    draw_body(f, app, (chunks[1], chunks[2], chunks[4], chunks[5]));
-    draw_help(f, chunks[7]);
+    if let Some(details_area) = details_area {
+        draw_match_details(f, app, details_area);
+    }
 }
 
 // Add a line for help text below.
@@ -286,6 +458,9 @@ fn draw_help(f: &mut ratatui::Frame, area: Rect) {
             "Cycle Focus ".into(),
             Span::styled("Tab", muted),
             " ".repeat(3).into(),
+            "Matches ".into(),
+            Span::styled("F2", muted),
+            " ".repeat(3).into(),
             "Exit ".into(),
             Span::styled("Ctrl + q", muted),
         ])),
@@ -293,8 +468,56 @@ fn draw_help(f: &mut ratatui::Frame, area: Rect) {
     );
 }
 
+// Draw the match-detail inspector panel: every match's byte span, plus
+// each of its capture groups, color-coordinated with the same
+// `highlight_styles` index used to highlight the sample text.
+fn draw_match_details(f: &mut ratatui::Frame, app: &App, area: Rect) {
+    let styles = highlight_styles();
+    let details = app.get_match_details();
+
+    let mut lines: Vec<Line> = vec![];
+    for (match_index, detail) in details.iter().enumerate() {
+        lines.push(Line::from(Span::styled(
+            format!("Match {} [{}..{}]", match_index, detail.start, detail.end),
+            Style::new().add_modifier(Modifier::BOLD),
+        )));
+
+        for capture in &detail.captures {
+            let label = match &capture.group_name {
+                Some(name) => format!("  {} ({name})", capture.group_index),
+                None => format!("  {}", capture.group_index),
+            };
+
+            lines.push(Line::from(vec![
+                Span::styled(label, styles[capture.group_index % styles.len()]),
+                Span::raw(format!(
+                    " [{}..{}] {:?}",
+                    capture.start, capture.end, capture.text
+                )),
+            ]));
+        }
+    }
+
+    if details.is_empty() {
+        lines.push(Line::styled("No matches", Style::new().fg(Color::DarkGray)));
+    }
+
+    let block = Block::default()
+        .title("Matches")
+        .borders(Borders::LEFT)
+        .border_type(BorderType::Thick)
+        .border_style(Style::new().fg(Color::DarkGray))
+        .padding(Padding::horizontal(1));
+
+    let paragraph = Paragraph::new(lines)
+        .block(block)
+        .scroll((app.match_details_scroll, 0));
+
+    f.render_widget(paragraph, area);
+}
+
 // Draw the application contents.
-fn draw_body(f: &mut ratatui::Frame, app: &mut App, areas: (Rect, Rect, Rect, Rect)) {
+fn draw_body(f: &mut ratatui::Frame, app: &mut App, areas: BodyAreas) {
     let textarea_base = Block::default()
         .borders(Borders::LEFT)
         .border_type(BorderType::Thick)
@@ -331,7 +554,7 @@ fn draw_body(f: &mut ratatui::Frame, app: &mut App, areas: (Rect, Rect, Rect, Re
 
     let mut sample_label = Paragraph::new("Test String");
     if matches!(app.input_focus, InputFocus::Sample) {
-        app.sample_textarea.set_block(textarea_active);
+        app.sample_textarea.set_block(textarea_active.clone());
         app.sample_textarea.set_cursor_style(cursor_active);
     } else {
         sample_label = sample_label.fg(Color::DarkGray);
@@ -339,19 +562,40 @@ fn draw_body(f: &mut ratatui::Frame, app: &mut App, areas: (Rect, Rect, Rect, Re
         app.sample_textarea.set_cursor_style(Style::new().hidden());
     }
 
+    let mut replace_label = Paragraph::new("Replace");
+    if matches!(app.input_focus, InputFocus::Replace) {
+        app.replace_textarea.set_block(textarea_active);
+        app.replace_textarea.set_cursor_style(cursor_active);
+    } else {
+        replace_label = replace_label.fg(Color::DarkGray);
+        app.replace_textarea.set_block(textarea_base.clone());
+        app.replace_textarea.set_cursor_style(Style::new().hidden());
+    }
+
     // Render the regex.
-    f.render_widget(regex_label, areas.0);
-    f.render_widget(&app.regex_textarea, areas.1);
+    f.render_widget(regex_label, areas.regex_label);
+    f.render_widget(&app.regex_textarea, areas.regex);
 
     // Render the test string.
-    f.render_widget(sample_label, areas.2);
+    f.render_widget(sample_label, areas.sample_label);
     if matches!(app.input_focus, InputFocus::Sample) {
         // When focused, render the textarea for proper cursor handling.
-        f.render_widget(&app.sample_textarea, areas.3);
+        f.render_widget(&app.sample_textarea, areas.sample);
     } else {
         // When not focused, render highlighted text.
         let highlighted_text = app.get_highlighted_text();
-        let text_paragraph = Paragraph::new(highlighted_text).block(textarea_base);
-        f.render_widget(text_paragraph, areas.3);
+        let text_paragraph = Paragraph::new(highlighted_text).block(textarea_base.clone());
+        f.render_widget(text_paragraph, areas.sample);
     }
+
+    // Render the replacement template.
+    f.render_widget(replace_label, areas.replace_label);
+    f.render_widget(&app.replace_textarea, areas.replace);
+
+    // Render the substitution preview. It's read-only, so it's always shown
+    // as plain text rather than switching to an editable textarea on focus.
+    let replaced_label = Paragraph::new("Replaced").fg(Color::DarkGray);
+    f.render_widget(replaced_label, areas.replaced_label);
+    let replaced_paragraph = Paragraph::new(app.replaced_text.clone()).block(textarea_base);
+    f.render_widget(replaced_paragraph, areas.replaced);
 }
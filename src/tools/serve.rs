@@ -35,6 +35,10 @@ pub struct ServeTool {
     /// Authentication credentials (username:password)
     #[arg(long)]
     auth: Option<Auth>,
+
+    /// Allow clients to create, overwrite, and delete files via PUT/DELETE
+    #[arg(long)]
+    writable: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -93,6 +97,15 @@ impl ServeTool {
             .fallback_service(serve_dir)
             .layer(tower_http::trace::TraceLayer::new_for_http());
 
+        if self.writable {
+            tracing::debug!("writable mode is enabled");
+            let write_routes = axum::Router::new()
+                .route("/{*path}", axum::routing::put(put_file).delete(delete_file))
+                .route("/", axum::routing::put(put_file).delete(delete_file))
+                .with_state(root.clone());
+            app = app.merge(write_routes);
+        }
+
         if let Some(auth) = &self.auth {
             tracing::debug!("auth is enabled");
             app = app.layer(middleware::from_fn_with_state(
@@ -141,6 +154,65 @@ fn build_and_validate_path(base_path: &Path, requested_path: &str) -> Option<Pat
     Some(abs_path)
 }
 
+// Builds a sibling path in the same directory as `target`, so writing to it
+// and then renaming over `target` is an atomic same-filesystem move.
+fn sibling_temp_path(target: &Path) -> PathBuf {
+    let mut temp_name = target.file_name().unwrap_or_default().to_os_string();
+    temp_name.push(format!(".ut-upload-{}.tmp", std::process::id()));
+    target.with_file_name(temp_name)
+}
+
+async fn put_file(
+    State(ref root): State<PathBuf>,
+    OriginalUri(uri): OriginalUri,
+    body: axum::body::Bytes,
+) -> Result<impl IntoResponse, impl IntoResponse> {
+    let Some(absolute_path) = build_and_validate_path(root, uri.path()) else {
+        return Err((StatusCode::BAD_REQUEST,));
+    };
+    if absolute_path.is_dir() {
+        return Err((StatusCode::BAD_REQUEST,));
+    }
+
+    let existed = absolute_path.exists();
+
+    if let Some(parent) = absolute_path.parent() {
+        fs::create_dir_all(parent).map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR,))?;
+    }
+
+    let temp_path = sibling_temp_path(&absolute_path);
+    fs::write(&temp_path, &body).map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR,))?;
+    fs::rename(&temp_path, &absolute_path).map_err(|_| {
+        let _ = fs::remove_file(&temp_path);
+        (StatusCode::INTERNAL_SERVER_ERROR,)
+    })?;
+
+    Ok(if existed {
+        StatusCode::NO_CONTENT
+    } else {
+        StatusCode::CREATED
+    })
+}
+
+async fn delete_file(
+    State(ref root): State<PathBuf>,
+    OriginalUri(uri): OriginalUri,
+) -> Result<impl IntoResponse, impl IntoResponse> {
+    let Some(absolute_path) = build_and_validate_path(root, uri.path()) else {
+        return Err((StatusCode::BAD_REQUEST,));
+    };
+    if !absolute_path.exists() {
+        return Err((StatusCode::NOT_FOUND,));
+    }
+    if absolute_path.is_dir() {
+        return Err((StatusCode::BAD_REQUEST,));
+    }
+
+    fs::remove_file(&absolute_path).map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR,))?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
 async fn list_dir(
     State(ref root): State<PathBuf>,
     OriginalUri(uri): OriginalUri,
@@ -1,6 +1,7 @@
 use crate::tool::{Output, Tool};
-use anyhow::Context;
+use anyhow::{Context, Result, bail};
 use clap::{Command, CommandFactory, Parser, Subcommand};
+use rand::{RngCore, rngs::OsRng};
 use ulid::Ulid;
 
 #[derive(Parser, Debug)]
@@ -21,6 +22,17 @@ enum ULIDCommand {
         /// Number of ULIDs to generate
         #[arg(short = 'c', long = "count", default_value = "1")]
         quantity: usize,
+
+        /// Guarantee strictly increasing output within this batch, even
+        /// when multiple ULIDs land in the same millisecond
+        #[arg(short, long)]
+        monotonic: bool,
+
+        /// Mint the ULID(s) at a fixed time instead of now, given as
+        /// milliseconds since the epoch or an RFC 3339 datetime. Useful for
+        /// reproducible test fixtures
+        #[arg(short, long)]
+        timestamp: Option<String>,
     },
 
     /// Parse and inspect a ULID
@@ -54,49 +66,55 @@ impl Tool for ULIDTool {
     }
 
     fn execute(&self) -> anyhow::Result<Option<Output>> {
-        let result = match &self.command {
-            ULIDCommand::Generate { quantity } => {
-                let ulids: Vec<String> = (0..*quantity)
-                    .map(|_| Ulid::new().to_string())
-                    .collect();
-                serde_json::json!(ulids)
+        match &self.command {
+            ULIDCommand::Generate {
+                quantity,
+                monotonic,
+                timestamp,
+            } => {
+                let timestamp_ms = timestamp.as_deref().map(resolve_timestamp_ms).transpose()?;
+
+                let ulids = if *monotonic {
+                    generate_monotonic(*quantity, timestamp_ms)?
+                } else {
+                    (0..*quantity)
+                        .map(|_| match timestamp_ms {
+                            Some(ms) => Ulid::from_parts(ms, random_ulid_bits()),
+                            None => Ulid::new(),
+                        })
+                        .collect()
+                };
+
+                let results: Vec<_> = ulids.into_iter().map(ulid_to_json).collect();
+                Ok(Some(Output::JsonValue(serde_json::json!(results))))
             }
 
             ULIDCommand::Parse { ulid } => {
                 let parsed = Ulid::from_string(ulid).context("Invalid ULID format")?;
 
-                let timestamp_ms = parsed.timestamp_ms();
-                let datetime_secs = timestamp_ms / 1000;
+                let mut value = ulid_to_json(parsed);
+                value["bytes"] = serde_json::json!(parsed.to_bytes());
+                value["random_hex"] = serde_json::json!(hex_encode(&parsed.to_bytes()[6..]));
 
-                // Convert to ISO 8601 format using jiff
-                let datetime_str = jiff::Timestamp::from_second(datetime_secs as i64)
-                    .map(|ts| ts.to_string())
-                    .unwrap_or_else(|_| "Invalid timestamp".to_string());
-
-                serde_json::json!({
-                    "ulid": ulid,
-                    "datetime": datetime_str,
-                    "timestamp_ms": timestamp_ms,
-                    "bytes": parsed.to_bytes(),
-                })
+                Ok(Some(Output::JsonValue(value)))
             }
 
             ULIDCommand::Validate { ulid } => {
-                // TODO: Also use proper exit code.
-                serde_json::json!(if Ulid::from_string(ulid).is_ok() {
-                    "valid"
-                } else {
-                    "invalid"
-                })
+                let is_valid = Ulid::from_string(ulid).is_ok();
+
+                Ok(Some(Output::Status {
+                    value: serde_json::json!(if is_valid { "valid" } else { "invalid" }),
+                    exit_code: if is_valid { 0 } else { 1 },
+                }))
             }
 
             ULIDCommand::ToUUID { ulid } => {
                 let parsed = Ulid::from_string(ulid).context("Invalid ULID format")?;
                 let uuid: uuid::Uuid = parsed.into();
-                serde_json::json!({
+                Ok(Some(Output::JsonValue(serde_json::json!({
                     "ulid": ulid,
                     "uuid": uuid.to_string(),
-                })
+                }))))
             }
 
             ULIDCommand::FromUUID { uuid } => {
@@ -106,15 +124,91 @@ impl Tool for ULIDTool {
                 let uuid_bytes = parsed_uuid.as_bytes();
                 let ulid = Ulid::from_bytes(*uuid_bytes);
 
-                serde_json::json!({
+                Ok(Some(Output::JsonValue(serde_json::json!({
                     "uuid": uuid,
                     "ulid": ulid.to_string(),
-                })
+                }))))
+            }
+        }
+    }
+}
+
+// ULID's random component is 80 bits, not the full width of the u128 that
+// `Ulid::random()` returns it in.
+const MAX_ULID_RANDOM: u128 = (1u128 << 80) - 1;
+
+// Generates `quantity` ULIDs that are strictly increasing even when several
+// land in the same millisecond: if the clock (wall or fixed) hasn't advanced
+// since the previous ULID, the random component is incremented instead of
+// redrawn, so the batch stays sortable by generation order.
+fn generate_monotonic(quantity: usize, timestamp_ms: Option<u64>) -> Result<Vec<Ulid>> {
+    let mut ulids = Vec::with_capacity(quantity);
+    let mut prev: Option<Ulid> = None;
+
+    for _ in 0..quantity {
+        let candidate = match timestamp_ms {
+            Some(ms) => Ulid::from_parts(ms, random_ulid_bits()),
+            None => Ulid::new(),
+        };
+
+        let next = match prev {
+            Some(prev_ulid) if candidate.timestamp_ms() == prev_ulid.timestamp_ms() => {
+                if prev_ulid.random() >= MAX_ULID_RANDOM {
+                    bail!("ULID random component overflowed within the same millisecond");
+                }
+                Ulid::from_parts(prev_ulid.timestamp_ms(), prev_ulid.random() + 1)
             }
+            _ => candidate,
         };
 
-        Ok(Some(Output::JsonValue(result)))
+        prev = Some(next);
+        ulids.push(next);
     }
+
+    Ok(ulids)
+}
+
+// Draws a fresh 80-bit random component for a ULID minted at a fixed
+// timestamp, where `Ulid::new()` isn't an option because it always uses the
+// wall clock.
+fn random_ulid_bits() -> u128 {
+    let mut bytes = [0u8; 16];
+    OsRng.fill_bytes(&mut bytes[6..]);
+    u128::from_be_bytes(bytes) & MAX_ULID_RANDOM
+}
+
+// Parses --timestamp as either milliseconds since the epoch or an RFC 3339
+// datetime, for minting reproducible ULIDs.
+fn resolve_timestamp_ms(raw: &str) -> Result<u64> {
+    if let Ok(ms) = raw.parse::<u64>() {
+        return Ok(ms);
+    }
+
+    let timestamp: jiff::Timestamp = raw
+        .replace('Z', "+00:00")
+        .parse()
+        .context("--timestamp must be milliseconds since the epoch or an RFC 3339 datetime")?;
+    Ok(timestamp.as_millisecond() as u64)
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+// Renders a ULID's string form alongside its decoded timestamp, the shape
+// shared by `generate` and `parse` so downstream tooling can sort/inspect
+// either output uniformly.
+fn ulid_to_json(ulid: Ulid) -> serde_json::Value {
+    let timestamp_ms = ulid.timestamp_ms();
+    let datetime_str = jiff::Timestamp::from_millisecond(timestamp_ms as i64)
+        .map(|ts| ts.to_string())
+        .unwrap_or_else(|_| "Invalid timestamp".to_string());
+
+    serde_json::json!({
+        "ulid": ulid.to_string(),
+        "timestamp_ms": timestamp_ms,
+        "datetime": datetime_str,
+    })
 }
 
 #[cfg(test)]
@@ -124,7 +218,11 @@ mod tests {
     #[test]
     fn test_generate_single() {
         let tool = ULIDTool {
-            command: ULIDCommand::Generate { quantity: 1 },
+            command: ULIDCommand::Generate {
+                quantity: 1,
+                monotonic: false,
+                timestamp: None,
+            },
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -136,15 +234,21 @@ mod tests {
         assert_eq!(ulids.len(), 1);
 
         // Verify it's a valid ULID format (26 characters)
-        let ulid_str = ulids[0].as_str().unwrap();
+        let ulid_str = ulids[0]["ulid"].as_str().unwrap();
         assert_eq!(ulid_str.len(), 26);
         assert!(Ulid::from_string(ulid_str).is_ok());
+        assert!(ulids[0]["timestamp_ms"].as_u64().is_some());
+        assert!(ulids[0]["datetime"].as_str().is_some());
     }
 
     #[test]
     fn test_generate_multiple() {
         let tool = ULIDTool {
-            command: ULIDCommand::Generate { quantity: 5 },
+            command: ULIDCommand::Generate {
+                quantity: 5,
+                monotonic: false,
+                timestamp: None,
+            },
         };
         let result = tool.execute().unwrap().unwrap();
 
@@ -157,11 +261,74 @@ mod tests {
 
         // Verify all are valid ULIDs
         for ulid in ulids {
-            let ulid_str = ulid.as_str().unwrap();
+            let ulid_str = ulid["ulid"].as_str().unwrap();
             assert!(Ulid::from_string(ulid_str).is_ok());
         }
     }
 
+    #[test]
+    fn test_generate_with_fixed_timestamp_embeds_that_timestamp() {
+        let tool = ULIDTool {
+            command: ULIDCommand::Generate {
+                quantity: 3,
+                monotonic: false,
+                timestamp: Some("1700000000000".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let ulids = val.as_array().unwrap();
+        assert_eq!(ulids.len(), 3);
+        for entry in ulids {
+            assert_eq!(entry["timestamp_ms"].as_u64().unwrap(), 1700000000000);
+        }
+    }
+
+    #[test]
+    fn test_generate_with_rfc3339_timestamp() {
+        let tool = ULIDTool {
+            command: ULIDCommand::Generate {
+                quantity: 1,
+                monotonic: false,
+                timestamp: Some("2023-11-14T22:13:20Z".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val[0]["timestamp_ms"].as_u64().unwrap(), 1700000000000);
+    }
+
+    #[test]
+    fn test_monotonic_with_fixed_timestamp_is_strictly_increasing() {
+        let tool = ULIDTool {
+            command: ULIDCommand::Generate {
+                quantity: 50,
+                monotonic: true,
+                timestamp: Some("1700000000000".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let ulids = val.as_array().unwrap();
+        let strings: Vec<&str> = ulids.iter().map(|v| v["ulid"].as_str().unwrap()).collect();
+        let mut sorted = strings.clone();
+        sorted.sort();
+        assert_eq!(strings, sorted);
+        // All share the same embedded timestamp.
+        for entry in ulids {
+            assert_eq!(entry["timestamp_ms"].as_u64().unwrap(), 1700000000000);
+        }
+    }
+
     #[test]
     fn test_validate_valid() {
         let valid_ulid = Ulid::new().to_string();
@@ -170,11 +337,12 @@ mod tests {
         };
         let result = tool.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val) = result else {
+        let Output::Status { value, exit_code } = result else {
             unreachable!()
         };
 
-        assert_eq!(val.as_str().unwrap(), "valid");
+        assert_eq!(value.as_str().unwrap(), "valid");
+        assert_eq!(exit_code, 0);
     }
 
     #[test]
@@ -186,11 +354,12 @@ mod tests {
         };
         let result = tool.execute().unwrap().unwrap();
 
-        let Output::JsonValue(val) = result else {
+        let Output::Status { value, exit_code } = result else {
             unreachable!()
         };
 
-        assert_eq!(val.as_str().unwrap(), "invalid");
+        assert_eq!(value.as_str().unwrap(), "invalid");
+        assert_eq!(exit_code, 1);
     }
 
     #[test]
@@ -212,6 +381,8 @@ mod tests {
         assert_eq!(val["ulid"].as_str().unwrap(), ulid_str);
         assert!(val["timestamp_ms"].as_u64().is_some());
         assert!(val["datetime"].as_str().is_some());
+        assert!(val["bytes"].is_array());
+        assert_eq!(val["random_hex"].as_str().unwrap().len(), 20); // 10 bytes, hex-encoded
     }
 
     #[test]
@@ -286,4 +457,47 @@ mod tests {
         // Should match original
         assert_eq!(final_ulid_str, original_ulid_str);
     }
+
+    #[test]
+    fn test_monotonic_generation_is_sorted() {
+        let tool = ULIDTool {
+            command: ULIDCommand::Generate {
+                quantity: 200,
+                monotonic: true,
+                timestamp: None,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let ulids = val.as_array().unwrap();
+        assert_eq!(ulids.len(), 200);
+
+        let strings: Vec<&str> = ulids.iter().map(|v| v["ulid"].as_str().unwrap()).collect();
+        let mut sorted = strings.clone();
+        sorted.sort();
+        assert_eq!(strings, sorted);
+    }
+
+    #[test]
+    fn test_incrementing_random_component_preserves_timestamp_and_sorts_after() {
+        // generate_monotonic draws fresh ULIDs from the wall clock, so this
+        // exercises the same arithmetic it relies on directly: bumping the
+        // random component by one keeps the timestamp fixed and produces a
+        // strictly greater ULID.
+        let a = Ulid::from_parts(1_000, 5);
+        let b = Ulid::from_parts(a.timestamp_ms(), a.random() + 1);
+
+        assert_eq!(a.timestamp_ms(), b.timestamp_ms());
+        assert_eq!(b.random(), a.random() + 1);
+        assert!(b > a);
+    }
+
+    #[test]
+    fn test_max_ulid_random_is_not_incrementable() {
+        let saturated = Ulid::from_parts(9_999_999, MAX_ULID_RANDOM);
+        assert!(saturated.random() >= MAX_ULID_RANDOM);
+    }
 }
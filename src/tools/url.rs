@@ -17,17 +17,60 @@ enum UrlCommand {
     Encode {
         /// Text to URL encode (use "-" for stdin)
         text: StringInput,
+        /// Use application/x-www-form-urlencoded rules (spaces become '+')
+        #[arg(long)]
+        form: bool,
     },
     /// URL decode text
     Decode {
         /// Text to URL decode (use "-" for stdin)
         text: StringInput,
+        /// Use application/x-www-form-urlencoded rules ('+' becomes a space)
+        #[arg(long)]
+        form: bool,
     },
     /// Parse URL into its components
     Parse {
         /// URL to parse (use "-" for stdin)
         url: StringInput,
     },
+    /// Resolve a relative reference against a base URL
+    Join {
+        /// Base URL (use "-" for stdin)
+        base: StringInput,
+        /// Reference to resolve against the base (e.g. /path, ../other, ?query)
+        reference: StringInput,
+    },
+    /// Parse an application/x-www-form-urlencoded query string into a JSON object
+    FormParse {
+        /// Query string to parse (e.g. key1=value1&key2=value2, use "-" for stdin)
+        query: StringInput,
+    },
+    /// Build an application/x-www-form-urlencoded query string from key=value pairs
+    FormBuild {
+        /// Key-value pairs in the format key=value
+        #[arg(required = true)]
+        pairs: Vec<String>,
+    },
+    /// Convert between Unicode and Punycode (IDNA ASCII-compatible) domain forms
+    Idna {
+        #[command(subcommand)]
+        command: IdnaCommand,
+    },
+}
+
+#[derive(Parser, Debug)]
+pub enum IdnaCommand {
+    /// Convert a Unicode domain to its ASCII-compatible punycode form
+    Encode {
+        /// Domain to encode (e.g. münchen.de)
+        domain: StringInput,
+    },
+    /// Convert a punycode (ASCII-compatible) domain back to Unicode
+    Decode {
+        /// Domain to decode (e.g. xn--mnchen-3ya.de)
+        domain: StringInput,
+    },
 }
 
 impl Tool for UrlTool {
@@ -37,14 +80,22 @@ impl Tool for UrlTool {
 
     fn execute(&self) -> anyhow::Result<Option<Output>> {
         match &self.command {
-            UrlCommand::Encode { text } => {
-                let result = urlencoding::encode(text.as_ref()).into_owned();
+            UrlCommand::Encode { text, form } => {
+                let result = if *form {
+                    url::form_urlencoded::byte_serialize(text.as_ref().as_bytes()).collect()
+                } else {
+                    urlencoding::encode(text.as_ref()).into_owned()
+                };
                 Ok(Some(Output::JsonValue(serde_json::json!(result))))
             }
-            UrlCommand::Decode { text } => {
-                let result = urlencoding::decode(text.as_ref())
-                    .context("Could not decode")?
-                    .into_owned();
+            UrlCommand::Decode { text, form } => {
+                let result = if *form {
+                    form_decode_value(text.as_ref())
+                } else {
+                    urlencoding::decode(text.as_ref())
+                        .context("Could not decode")?
+                        .into_owned()
+                };
                 Ok(Some(Output::JsonValue(serde_json::json!(result))))
             }
             UrlCommand::Parse { url } => {
@@ -56,11 +107,29 @@ impl Tool for UrlTool {
                     .map(|(k, v)| (k.into_owned(), serde_json::json!(v)))
                     .collect();
 
+                let host = match parsed.host() {
+                    Some(url::Host::Domain(domain)) => {
+                        serde_json::json!({"type": "domain", "value": domain})
+                    }
+                    Some(url::Host::Ipv4(ip)) => {
+                        serde_json::json!({"type": "ipv4", "value": ip.to_string()})
+                    }
+                    Some(url::Host::Ipv6(ip)) => {
+                        serde_json::json!({"type": "ipv6", "value": ip.to_string()})
+                    }
+                    None => serde_json::Value::Null,
+                };
+
+                let path_segments: Option<Vec<&str>> =
+                    parsed.path_segments().map(|segments| segments.collect());
+
                 let result = serde_json::json!({
                     "scheme": parsed.scheme(),
-                    "host": parsed.host_str(),
+                    "host": host,
                     "port": parsed.port_or_known_default(),
                     "path": parsed.path(),
+                    "path_segments": path_segments,
+                    "origin": parsed.origin().ascii_serialization(),
                     "query": parsed.query(),
                     "query_params": query_params,
                     "fragment": parsed.fragment(),
@@ -70,8 +139,206 @@ impl Tool for UrlTool {
 
                 Ok(Some(Output::JsonValue(result)))
             }
+            UrlCommand::Join { base, reference } => {
+                let base = Url::parse(base.as_ref()).context("Could not parse base URL")?;
+                let joined = base
+                    .join(reference.as_ref())
+                    .context("Could not resolve reference against base URL")?;
+
+                Ok(Some(Output::JsonValue(serde_json::json!(
+                    joined.to_string()
+                ))))
+            }
+            UrlCommand::FormParse { query } => {
+                let object: serde_json::Map<String, serde_json::Value> =
+                    url::form_urlencoded::parse(query.as_ref().as_bytes())
+                        .map(|(k, v)| (k.into_owned(), serde_json::json!(v)))
+                        .collect();
+
+                Ok(Some(Output::JsonValue(serde_json::Value::Object(object))))
+            }
+            UrlCommand::FormBuild { pairs } => {
+                let mut serializer = url::form_urlencoded::Serializer::new(String::new());
+
+                for pair in pairs {
+                    let (key, value) = pair
+                        .split_once('=')
+                        .with_context(|| format!("Expected key=value, got '{pair}'"))?;
+                    serializer.append_pair(key, value);
+                }
+
+                Ok(Some(Output::JsonValue(serde_json::json!(
+                    serializer.finish()
+                ))))
+            }
+            UrlCommand::Idna { command } => match command {
+                IdnaCommand::Encode { domain } => {
+                    // `Url` already performs IDNA encoding while parsing a
+                    // host, so routing a throwaway URL through it is enough
+                    // to read the ASCII-compatible form back out.
+                    let url = Url::parse(&format!("https://{}/", domain.as_ref()))
+                        .context("Could not encode domain (invalid domain syntax)")?;
+                    let encoded = url.host_str().context("URL has no host")?.to_string();
+
+                    let labels: Vec<serde_json::Value> = domain
+                        .as_ref()
+                        .split('.')
+                        .zip(encoded.split('.'))
+                        .map(|(label, result)| {
+                            serde_json::json!({"label": label, "result": result, "ok": true})
+                        })
+                        .collect();
+
+                    Ok(Some(Output::JsonValue(serde_json::json!({
+                        "domain": encoded,
+                        "labels": labels,
+                    }))))
+                }
+                IdnaCommand::Decode { domain } => {
+                    let labels: Vec<serde_json::Value> = domain
+                        .as_ref()
+                        .split('.')
+                        .map(|label| match label.strip_prefix("xn--") {
+                            Some(rest) => match decode_punycode_label(rest) {
+                                Ok(decoded) => {
+                                    serde_json::json!({"label": label, "result": decoded, "ok": true})
+                                }
+                                Err(_) => {
+                                    serde_json::json!({"label": label, "result": null, "ok": false})
+                                }
+                            },
+                            // Labels without the ACE prefix aren't punycode; pass them through.
+                            None => serde_json::json!({"label": label, "result": label, "ok": true}),
+                        })
+                        .collect();
+
+                    let domain_result = labels
+                        .iter()
+                        .all(|entry| entry["ok"] == true)
+                        .then(|| {
+                            labels
+                                .iter()
+                                .map(|entry| entry["result"].as_str().unwrap())
+                                .collect::<Vec<_>>()
+                                .join(".")
+                        });
+
+                    Ok(Some(Output::JsonValue(serde_json::json!({
+                        "domain": domain_result,
+                        "labels": labels,
+                    }))))
+                }
+            },
+        }
+    }
+}
+
+// Decode a single Punycode label (the part after the "xn--" ACE prefix) per
+// RFC 3492's bootstring algorithm, the same scheme IDNA uses for each
+// dot-separated label of a domain. There's no public API in the `url`
+// crate for the ASCII -> Unicode direction (only the reverse happens
+// automatically while parsing a host), so this is implemented by hand.
+fn decode_punycode_label(input: &str) -> Result<String, ()> {
+    const BASE: u32 = 36;
+    const TMIN: u32 = 1;
+    const TMAX: u32 = 26;
+    const SKEW: u32 = 38;
+    const DAMP: u32 = 700;
+    const INITIAL_BIAS: u32 = 72;
+    const INITIAL_N: u32 = 128;
+
+    fn adapt(delta: u32, num_points: u32, first_time: bool) -> u32 {
+        let mut delta = if first_time { delta / DAMP } else { delta / 2 };
+        delta += delta / num_points;
+
+        let mut k = 0;
+        while delta > ((BASE - TMIN) * TMAX) / 2 {
+            delta /= BASE - TMIN;
+            k += BASE;
+        }
+
+        k + (((BASE - TMIN + 1) * delta) / (delta + SKEW))
+    }
+
+    fn decode_digit(c: char) -> Result<u32, ()> {
+        match c {
+            '0'..='9' => Ok(c as u32 - '0' as u32 + 26),
+            'a'..='z' => Ok(c as u32 - 'a' as u32),
+            'A'..='Z' => Ok(c as u32 - 'A' as u32),
+            _ => Err(()),
+        }
+    }
+
+    // Basic code points are everything before the last '-'; the rest is
+    // the encoded extension. No '-' means there were no basic code points.
+    let (basic, extended) = match input.rfind('-') {
+        Some(pos) => (&input[..pos], &input[pos + 1..]),
+        None => ("", input),
+    };
+
+    let mut output: Vec<char> = Vec::new();
+    for c in basic.chars() {
+        if !c.is_ascii() {
+            return Err(());
+        }
+        output.push(c);
+    }
+
+    let mut n: u32 = INITIAL_N;
+    let mut i: u32 = 0;
+    let mut bias: u32 = INITIAL_BIAS;
+    let mut chars = extended.chars();
+
+    while chars.clone().next().is_some() {
+        let old_i = i;
+        let mut w = 1u32;
+        let mut k = BASE;
+
+        loop {
+            let c = chars.next().ok_or(())?;
+            let digit = decode_digit(c)?;
+            i = i
+                .checked_add(digit.checked_mul(w).ok_or(())?)
+                .ok_or(())?;
+
+            let t = if k <= bias {
+                TMIN
+            } else if k >= bias + TMAX {
+                TMAX
+            } else {
+                k - bias
+            };
+
+            if digit < t {
+                break;
+            }
+
+            w = w.checked_mul(BASE - t).ok_or(())?;
+            k += BASE;
         }
+
+        let out_len = output.len() as u32 + 1;
+        bias = adapt(i - old_i, out_len, old_i == 0);
+        n = n.checked_add(i / out_len).ok_or(())?;
+        i %= out_len;
+
+        let ch = char::from_u32(n).ok_or(())?;
+        output.insert(i as usize, ch);
+        i += 1;
     }
+
+    Ok(output.into_iter().collect())
+}
+
+// Decode a single application/x-www-form-urlencoded value ('+' as space,
+// percent-escapes decoded). `form_urlencoded::parse` is built around whole
+// query strings, so a bare value is handled by treating it as a query
+// string with one key and an empty value.
+fn form_decode_value(input: &str) -> String {
+    url::form_urlencoded::parse(input.as_bytes())
+        .next()
+        .map(|(key, _)| key.into_owned())
+        .unwrap_or_default()
 }
 
 #[cfg(test)]
@@ -84,6 +351,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Encode {
                 text: StringInput("hello world".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -99,6 +367,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Encode {
                 text: StringInput("hello@world.com?key=value&foo=bar".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -117,6 +386,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Encode {
                 text: StringInput("Hello 世界".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -132,6 +402,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Encode {
                 text: StringInput("".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -147,6 +418,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Encode {
                 text: StringInput("hello%20world".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -162,6 +434,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput("hello%20world".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -177,6 +450,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput("hello%40world.com%3Fkey%3Dvalue%26foo%3Dbar".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -192,6 +466,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput("Hello%20%E4%B8%96%E7%95%8C".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -207,6 +482,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput("".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -222,6 +498,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput("hello+world".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -237,6 +514,7 @@ mod tests {
         let tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput("hello%ZZworld".to_string()),
+                form: false,
             },
         };
         let result = tool.execute().unwrap().unwrap();
@@ -255,6 +533,7 @@ mod tests {
         let encode_tool = UrlTool {
             command: UrlCommand::Encode {
                 text: StringInput(original.to_string()),
+                form: false,
             },
         };
         let encoded = encode_tool.execute().unwrap().unwrap();
@@ -267,6 +546,7 @@ mod tests {
         let decode_tool = UrlTool {
             command: UrlCommand::Decode {
                 text: StringInput(encoded_str),
+                form: false,
             },
         };
         let decoded = decode_tool.execute().unwrap().unwrap();
@@ -290,7 +570,8 @@ mod tests {
             unreachable!()
         };
         assert_eq!(val["scheme"], "https");
-        assert_eq!(val["host"], "example.com");
+        assert_eq!(val["host"]["type"], "domain");
+        assert_eq!(val["host"]["value"], "example.com");
         assert_eq!(val["port"], 443);
         assert_eq!(val["path"], "/path");
         assert!(val["query"].is_null());
@@ -357,7 +638,7 @@ mod tests {
         let Output::JsonValue(val) = result else {
             unreachable!()
         };
-        assert_eq!(val["host"], "localhost");
+        assert_eq!(val["host"]["value"], "localhost");
         assert_eq!(val["port"], 8080);
         assert_eq!(val["scheme"], "http");
     }
@@ -372,4 +653,328 @@ mod tests {
         let result = tool.execute();
         assert!(result.is_err());
     }
+
+    #[test]
+    fn test_join_resolves_absolute_path() {
+        let tool = UrlTool {
+            command: UrlCommand::Join {
+                base: StringInput("sc://xn--ida.example/".to_string()),
+                reference: StringInput("/resources/testharness.js".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(
+            val.as_str().unwrap(),
+            "sc://xn--ida.example/resources/testharness.js"
+        );
+    }
+
+    #[test]
+    fn test_join_relative_reference() {
+        let tool = UrlTool {
+            command: UrlCommand::Join {
+                base: StringInput("https://example.com/a/b/c".to_string()),
+                reference: StringInput("../d".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "https://example.com/a/d");
+    }
+
+    #[test]
+    fn test_join_empty_reference_leaves_base_unchanged() {
+        let tool = UrlTool {
+            command: UrlCommand::Join {
+                base: StringInput("https://example.com/a/b".to_string()),
+                reference: StringInput("".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "https://example.com/a/b");
+    }
+
+    #[test]
+    fn test_join_invalid_base_errors() {
+        let tool = UrlTool {
+            command: UrlCommand::Join {
+                base: StringInput("not-a-valid-url".to_string()),
+                reference: StringInput("/path".to_string()),
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_form_encode_uses_plus_for_space() {
+        let tool = UrlTool {
+            command: UrlCommand::Encode {
+                text: StringInput("hello world".to_string()),
+                form: true,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "hello+world");
+    }
+
+    #[test]
+    fn test_form_decode_plus_becomes_space() {
+        let tool = UrlTool {
+            command: UrlCommand::Decode {
+                text: StringInput("hello+world".to_string()),
+                form: true,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "hello world");
+    }
+
+    #[test]
+    fn test_form_parse_query_string() {
+        let tool = UrlTool {
+            command: UrlCommand::FormParse {
+                query: StringInput("key1=value1&key2=hello+world".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["key1"], "value1");
+        assert_eq!(val["key2"], "hello world");
+    }
+
+    #[test]
+    fn test_form_build_query_string() {
+        let tool = UrlTool {
+            command: UrlCommand::FormBuild {
+                pairs: vec!["key1=value1".to_string(), "key2=hello world".to_string()],
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val.as_str().unwrap(), "key1=value1&key2=hello+world");
+    }
+
+    #[test]
+    fn test_form_round_trip() {
+        let build = UrlTool {
+            command: UrlCommand::FormBuild {
+                pairs: vec!["a=1".to_string(), "b=two words".to_string()],
+            },
+        };
+        let built = build.execute().unwrap().unwrap();
+        let Output::JsonValue(query) = built else {
+            unreachable!()
+        };
+
+        let parse = UrlTool {
+            command: UrlCommand::FormParse {
+                query: StringInput(query.as_str().unwrap().to_string()),
+            },
+        };
+        let result = parse.execute().unwrap().unwrap();
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["a"], "1");
+        assert_eq!(val["b"], "two words");
+    }
+
+    #[test]
+    fn test_parse_path_segments() {
+        let tool = UrlTool {
+            command: UrlCommand::Parse {
+                url: StringInput("https://example.com/a/b/c".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["path_segments"], serde_json::json!(["a", "b", "c"]));
+    }
+
+    #[test]
+    fn test_parse_origin() {
+        let tool = UrlTool {
+            command: UrlCommand::Parse {
+                url: StringInput("https://example.com:8443/path".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["origin"], "https://example.com:8443");
+    }
+
+    #[test]
+    fn test_parse_opaque_origin() {
+        let tool = UrlTool {
+            command: UrlCommand::Parse {
+                url: StringInput("data:text/plain,hello".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["origin"], "null");
+    }
+
+    #[test]
+    fn test_parse_ipv6_host() {
+        let tool = UrlTool {
+            command: UrlCommand::Parse {
+                url: StringInput("http://[::1]:8080/".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["host"]["type"], "ipv6");
+        assert_eq!(val["host"]["value"], "::1");
+        assert_eq!(val["port"], 8080);
+    }
+
+    #[test]
+    fn test_parse_ipv4_host() {
+        let tool = UrlTool {
+            command: UrlCommand::Parse {
+                url: StringInput("http://127.0.0.1/".to_string()),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["host"]["type"], "ipv4");
+        assert_eq!(val["host"]["value"], "127.0.0.1");
+    }
+
+    #[test]
+    fn test_idna_encode_unicode_domain() {
+        let tool = UrlTool {
+            command: UrlCommand::Idna {
+                command: IdnaCommand::Encode {
+                    domain: StringInput("münchen.de".to_string()),
+                },
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["domain"], "xn--mnchen-3ya.de");
+    }
+
+    #[test]
+    fn test_idna_decode_punycode_domain() {
+        let tool = UrlTool {
+            command: UrlCommand::Idna {
+                command: IdnaCommand::Decode {
+                    domain: StringInput("xn--mnchen-3ya.de".to_string()),
+                },
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["domain"], "münchen.de");
+        assert_eq!(val["labels"][0]["label"], "xn--mnchen-3ya");
+        assert_eq!(val["labels"][0]["result"], "münchen");
+        assert_eq!(val["labels"][0]["ok"], true);
+        assert_eq!(val["labels"][1]["label"], "de");
+        assert_eq!(val["labels"][1]["result"], "de");
+    }
+
+    #[test]
+    fn test_idna_decode_flags_invalid_label() {
+        let tool = UrlTool {
+            command: UrlCommand::Idna {
+                command: IdnaCommand::Decode {
+                    domain: StringInput("xn--???.de".to_string()),
+                },
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["labels"][0]["ok"], false);
+        assert!(val["domain"].is_null());
+    }
+
+    #[test]
+    fn test_idna_round_trip() {
+        let encode = UrlTool {
+            command: UrlCommand::Idna {
+                command: IdnaCommand::Encode {
+                    domain: StringInput("café.fr".to_string()),
+                },
+            },
+        };
+        let encoded = encode.execute().unwrap().unwrap();
+        let Output::JsonValue(encoded) = encoded else {
+            unreachable!()
+        };
+        let ascii_domain = encoded["domain"].as_str().unwrap().to_string();
+
+        let decode = UrlTool {
+            command: UrlCommand::Idna {
+                command: IdnaCommand::Decode {
+                    domain: StringInput(ascii_domain),
+                },
+            },
+        };
+        let decoded = decode.execute().unwrap().unwrap();
+        let Output::JsonValue(decoded) = decoded else {
+            unreachable!()
+        };
+        assert_eq!(decoded["domain"], "café.fr");
+    }
+
+    #[test]
+    fn test_form_build_rejects_missing_equals() {
+        let tool = UrlTool {
+            command: UrlCommand::FormBuild {
+                pairs: vec!["not-a-pair".to_string()],
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
 }
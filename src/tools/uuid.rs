@@ -1,50 +1,37 @@
 use crate::tool::{Output, Tool};
-use clap::{Command, CommandFactory, Parser, Subcommand, ValueEnum};
+use anyhow::{Context, Result, bail};
+use clap::{Command, CommandFactory, Parser, Subcommand};
 use uuid::Uuid;
 
 #[derive(Parser, Debug)]
-#[command(name = "uuid")]
+#[command(name = "uuid", about = "Generate and inspect UUIDs")]
 pub struct UUIDTool {
     #[command(subcommand)]
     command: UUIDCommand,
 }
 
-#[derive(ValueEnum, Clone, Debug)]
-enum Namespace {
-    /// DNS namespace
-    DNS,
-    /// URL namespace
-    URL,
-    /// ISO OID namespace
-    OID,
-    /// X.500 DN namespace
-    X500,
-}
-
-impl Namespace {
-    fn to_uuid(&self) -> Uuid {
-        match self {
-            Namespace::DNS => Uuid::NAMESPACE_DNS,
-            Namespace::URL => Uuid::NAMESPACE_URL,
-            Namespace::OID => Uuid::NAMESPACE_OID,
-            Namespace::X500 => Uuid::NAMESPACE_X500,
-        }
-    }
-}
-
 #[derive(Subcommand, Debug)]
 enum UUIDCommand {
-    /// Generate UUID v1 (timestamp-based)
+    /// Generate UUID v1 (timestamp + node, not sortable)
     V1 {
         /// Number of UUIDs to generate
         #[arg(short = 'c', long = "count", default_value = "1")]
         quantity: usize,
+
+        /// Custom 6-byte node id as hex, e.g. 010203040506 or
+        /// 01:02:03:04:05:06 (default: 000102030405)
+        #[arg(long)]
+        node: Option<String>,
+        /// Fixed Unix timestamp in seconds to embed, for reproducible output
+        #[arg(long)]
+        timestamp: Option<u64>,
     },
     /// Generate UUID v3 (namespace + MD5 hash)
     V3 {
-        /// Namespace to use
+        /// Namespace to use: one of dns, url, oid, x500, or an explicit
+        /// namespace UUID
         #[arg(short, long)]
-        namespace: Namespace,
+        namespace: String,
         /// Name to hash
         #[arg(short = 'N', long)]
         name: String,
@@ -60,9 +47,10 @@ enum UUIDCommand {
     },
     /// Generate UUID v5 (namespace + SHA-1 hash)
     V5 {
-        /// Namespace to use
+        /// Namespace to use: one of dns, url, oid, x500, or an explicit
+        /// namespace UUID
         #[arg(short, long)]
-        namespace: Namespace,
+        namespace: String,
         /// Name to hash
         #[arg(short = 'N', long)]
         name: String,
@@ -70,6 +58,77 @@ enum UUIDCommand {
         #[arg(short = 'c', long = "count", default_value = "1")]
         quantity: usize,
     },
+    /// Generate UUID v6 (timestamp + node, field-reordered to be sortable)
+    V6 {
+        /// Number of UUIDs to generate
+        #[arg(short = 'c', long = "count", default_value = "1")]
+        quantity: usize,
+    },
+    /// Generate UUID v7 (Unix-millisecond timestamp + random, sortable)
+    V7 {
+        /// Number of UUIDs to generate
+        #[arg(short = 'c', long = "count", default_value = "1")]
+        quantity: usize,
+        /// Fixed Unix timestamp in seconds to embed, for reproducible output
+        #[arg(long)]
+        timestamp: Option<u64>,
+    },
+    /// Parse and inspect a UUID
+    Parse {
+        /// UUID string to parse
+        uuid: String,
+    },
+    /// Validate a UUID string
+    Validate {
+        /// UUID string to validate
+        uuid: String,
+    },
+}
+
+// Resolves a `--namespace` argument that is either one of the well-known
+// namespace names (matched case-insensitively) or an explicit namespace
+// UUID, so callers that need a namespace bcrypt/openssl didn't standardize
+// aren't stuck with only the four RFC 4122 ones.
+fn resolve_namespace(namespace: &str) -> Result<Uuid> {
+    match namespace.to_ascii_lowercase().as_str() {
+        "dns" => Ok(Uuid::NAMESPACE_DNS),
+        "url" => Ok(Uuid::NAMESPACE_URL),
+        "oid" => Ok(Uuid::NAMESPACE_OID),
+        "x500" => Ok(Uuid::NAMESPACE_X500),
+        _ => Uuid::parse_str(namespace)
+            .context("Namespace must be one of dns, url, oid, x500, or a UUID"),
+    }
+}
+
+const NODE_ID: [u8; 6] = [0, 1, 2, 3, 4, 5];
+
+// Parses a 6-byte node id given as hex, optionally separated by `:` or `-`
+// (e.g. "010203040506" or "01:02:03:04:05:06"), mirroring cipher's decode_hex
+// helper for the same kind of hex-bytes CLI argument.
+fn parse_node_id(value: &str) -> Result<[u8; 6]> {
+    let cleaned: String = value.chars().filter(|c| *c != ':' && *c != '-').collect();
+
+    if cleaned.len() != 12 || !cleaned.chars().all(|c| c.is_ascii_hexdigit()) {
+        bail!("Node id must be 6 bytes as hex, e.g. 010203040506 or 01:02:03:04:05:06");
+    }
+
+    let mut node = [0u8; 6];
+    for (i, byte) in node.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&cleaned[i * 2..i * 2 + 2], 16).context("Invalid node id hex")?;
+    }
+
+    Ok(node)
+}
+
+// Collapses a batch of generated UUIDs down to a bare string when only one
+// was requested, and to a JSON array otherwise, so single generations stay
+// easy to pipe while `--count` keeps producing a parseable batch.
+fn quantity_output(quantity: usize, values: Vec<String>) -> serde_json::Value {
+    if quantity == 1 && values.len() == 1 {
+        serde_json::json!(values[0])
+    } else {
+        serde_json::json!(values)
+    }
 }
 
 impl Tool for UUIDTool {
@@ -78,37 +137,405 @@ impl Tool for UUIDTool {
     }
 
     fn execute(&self) -> anyhow::Result<Option<Output>> {
-        let uuids: Vec<String> = match &self.command {
-            UUIDCommand::V1 { quantity } => (0..*quantity)
-                .map(|_| Uuid::now_v1(&[0, 1, 2, 3, 4, 5]).to_string())
-                .collect(),
+        match &self.command {
+            UUIDCommand::V1 {
+                quantity,
+                node,
+                timestamp,
+            } => {
+                let node_id = match node {
+                    Some(node) => parse_node_id(node)?,
+                    None => NODE_ID,
+                };
+
+                let values = (0..*quantity)
+                    .map(|_| match timestamp {
+                        Some(secs) => {
+                            let ts = uuid::Timestamp::from_unix(uuid::NoContext, *secs, 0);
+                            Uuid::new_v1(ts, &node_id).to_string()
+                        }
+                        None => Uuid::now_v1(&node_id).to_string(),
+                    })
+                    .collect::<Vec<String>>();
+
+                Ok(Some(Output::JsonValue(quantity_output(*quantity, values))))
+            }
             UUIDCommand::V3 {
                 namespace,
                 name,
                 quantity,
             } => {
-                let ns_uuid = namespace.to_uuid();
+                let ns_uuid = resolve_namespace(namespace)?;
 
-                (0..*quantity)
+                let values = (0..*quantity)
                     .map(|_| Uuid::new_v3(&ns_uuid, name.as_bytes()).to_string())
-                    .collect()
+                    .collect::<Vec<String>>();
+
+                Ok(Some(Output::JsonValue(quantity_output(*quantity, values))))
             }
             UUIDCommand::V4 { quantity } => {
-                (0..*quantity).map(|_| Uuid::new_v4().to_string()).collect()
+                let values = (0..*quantity)
+                    .map(|_| Uuid::new_v4().to_string())
+                    .collect::<Vec<String>>();
+
+                Ok(Some(Output::JsonValue(quantity_output(*quantity, values))))
             }
             UUIDCommand::V5 {
                 namespace,
                 name,
                 quantity,
             } => {
-                let ns_uuid = namespace.to_uuid();
+                let ns_uuid = resolve_namespace(namespace)?;
 
-                (0..*quantity)
+                let values = (0..*quantity)
                     .map(|_| Uuid::new_v5(&ns_uuid, name.as_bytes()).to_string())
-                    .collect()
+                    .collect::<Vec<String>>();
+
+                Ok(Some(Output::JsonValue(quantity_output(*quantity, values))))
+            }
+            UUIDCommand::V6 { quantity } => {
+                let values = (0..*quantity)
+                    .map(|_| Uuid::now_v6(&NODE_ID).to_string())
+                    .collect::<Vec<String>>();
+
+                Ok(Some(Output::JsonValue(quantity_output(*quantity, values))))
+            }
+            UUIDCommand::V7 {
+                quantity,
+                timestamp,
+            } => {
+                let values = (0..*quantity)
+                    .map(|_| match timestamp {
+                        Some(secs) => {
+                            let ts = uuid::Timestamp::from_unix(uuid::NoContext, *secs, 0);
+                            Uuid::new_v7(ts).to_string()
+                        }
+                        None => Uuid::now_v7().to_string(),
+                    })
+                    .collect::<Vec<String>>();
+
+                Ok(Some(Output::JsonValue(quantity_output(*quantity, values))))
+            }
+            UUIDCommand::Parse { uuid } => Ok(Some(Output::JsonValue(parse_uuid(uuid)?))),
+            UUIDCommand::Validate { uuid } => {
+                let is_valid = Uuid::parse_str(uuid).is_ok();
+
+                Ok(Some(Output::Status {
+                    value: serde_json::json!(if is_valid { "valid" } else { "invalid" }),
+                    exit_code: if is_valid { 0 } else { 1 },
+                }))
             }
+        }
+    }
+}
+
+// Emits the version, variant, and (for timestamp-carrying versions 1, 6,
+// and 7) the embedded timestamp as ISO-8601, mirroring ulid's Parse output
+// shape.
+fn parse_uuid(uuid: &str) -> Result<serde_json::Value> {
+    let parsed = Uuid::parse_str(uuid).context("Invalid UUID format")?;
+
+    let mut result = serde_json::json!({
+        "uuid": uuid,
+        "version": parsed.get_version_num(),
+        "variant": format!("{:?}", parsed.get_variant()),
+    });
+
+    if let Some(timestamp) = parsed.get_timestamp() {
+        let (secs, nanos) = timestamp.to_unix();
+        let datetime_str = jiff::Timestamp::new(secs as i64, nanos as i32)
+            .map(|ts| ts.to_string())
+            .unwrap_or_else(|_| "Invalid timestamp".to_string());
+        result["datetime"] = serde_json::json!(datetime_str);
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_v1_defaults_to_zero_node_id() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V1 {
+                quantity: 1,
+                node: None,
+                timestamp: None,
+            },
         };
+        let result = tool.execute().unwrap().unwrap();
 
-        Ok(Some(Output::JsonValue(serde_json::json!(uuids))))
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let uuid_str = val.as_str().unwrap();
+        let parsed = Uuid::parse_str(uuid_str).unwrap();
+        assert_eq!(&parsed.as_bytes()[10..16], &NODE_ID);
+    }
+
+    #[test]
+    fn test_v1_with_custom_node_id() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V1 {
+                quantity: 1,
+                node: Some("01:02:03:04:05:06".to_string()),
+                timestamp: None,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let uuid_str = val.as_str().unwrap();
+        let parsed = Uuid::parse_str(uuid_str).unwrap();
+        assert_eq!(&parsed.as_bytes()[10..16], &[1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_v1_rejects_malformed_node_id() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V1 {
+                quantity: 1,
+                node: Some("not-hex".to_string()),
+                timestamp: None,
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_v1_with_fixed_timestamp_is_reproducible() {
+        let tool = |timestamp| UUIDTool {
+            command: UUIDCommand::V1 {
+                quantity: 1,
+                node: None,
+                timestamp: Some(timestamp),
+            },
+        };
+
+        let first = tool(1_700_000_000).execute().unwrap().unwrap();
+        let second = tool(1_700_000_000).execute().unwrap().unwrap();
+
+        let Output::JsonValue(first_val) = first else {
+            unreachable!()
+        };
+        let Output::JsonValue(second_val) = second else {
+            unreachable!()
+        };
+        assert_eq!(first_val, second_val);
+    }
+
+    #[test]
+    fn test_v7_with_fixed_timestamp_is_reproducible() {
+        let tool = |timestamp| UUIDTool {
+            command: UUIDCommand::V7 {
+                quantity: 1,
+                timestamp: Some(timestamp),
+            },
+        };
+
+        let first = tool(1_700_000_000).execute().unwrap().unwrap();
+        let second = tool(1_700_000_000).execute().unwrap().unwrap();
+
+        let Output::JsonValue(first_val) = first else {
+            unreachable!()
+        };
+        let Output::JsonValue(second_val) = second else {
+            unreachable!()
+        };
+        assert_eq!(first_val, second_val);
+    }
+
+    #[test]
+    fn test_single_quantity_outputs_bare_string() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V4 { quantity: 1 },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert!(val.is_string());
+    }
+
+    #[test]
+    fn test_v4_generates_requested_count() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V4 { quantity: 3 },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let uuids = val.as_array().unwrap();
+        assert_eq!(uuids.len(), 3);
+        for uuid in uuids {
+            assert!(Uuid::parse_str(uuid.as_str().unwrap()).is_ok());
+        }
+    }
+
+    #[test]
+    fn test_v7_is_time_sortable() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V7 {
+                quantity: 50,
+                timestamp: None,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let uuids: Vec<&str> = val
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|v| v.as_str().unwrap())
+            .collect();
+
+        let mut sorted = uuids.clone();
+        sorted.sort();
+        assert_eq!(uuids, sorted);
+    }
+
+    #[test]
+    fn test_v5_with_well_known_namespace_is_deterministic() {
+        let tool = |quantity| UUIDTool {
+            command: UUIDCommand::V5 {
+                namespace: "dns".to_string(),
+                name: "example.com".to_string(),
+                quantity,
+            },
+        };
+
+        let first = tool(1).execute().unwrap().unwrap();
+        let second = tool(1).execute().unwrap().unwrap();
+
+        let Output::JsonValue(first_val) = first else {
+            unreachable!()
+        };
+        let Output::JsonValue(second_val) = second else {
+            unreachable!()
+        };
+        assert_eq!(first_val, second_val);
+    }
+
+    #[test]
+    fn test_v3_with_explicit_namespace_uuid() {
+        let explicit_namespace = Uuid::new_v4().to_string();
+
+        let tool = UUIDTool {
+            command: UUIDCommand::V3 {
+                namespace: explicit_namespace.clone(),
+                name: "widget".to_string(),
+                quantity: 1,
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        let uuid_str = val.as_str().unwrap();
+
+        let expected = Uuid::new_v3(
+            &Uuid::parse_str(&explicit_namespace).unwrap(),
+            "widget".as_bytes(),
+        );
+        assert_eq!(uuid_str, expected.to_string());
+    }
+
+    #[test]
+    fn test_v5_rejects_unknown_namespace() {
+        let tool = UUIDTool {
+            command: UUIDCommand::V5 {
+                namespace: "not-a-namespace".to_string(),
+                name: "example.com".to_string(),
+                quantity: 1,
+            },
+        };
+        assert!(tool.execute().is_err());
+    }
+
+    #[test]
+    fn test_validate_valid() {
+        let tool = UUIDTool {
+            command: UUIDCommand::Validate {
+                uuid: Uuid::new_v4().to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::Status { value, exit_code } = result else {
+            unreachable!()
+        };
+        assert_eq!(value.as_str().unwrap(), "valid");
+        assert_eq!(exit_code, 0);
+    }
+
+    #[test]
+    fn test_validate_invalid() {
+        let tool = UUIDTool {
+            command: UUIDCommand::Validate {
+                uuid: "not-a-uuid".to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::Status { value, exit_code } = result else {
+            unreachable!()
+        };
+        assert_eq!(value.as_str().unwrap(), "invalid");
+        assert_eq!(exit_code, 1);
+    }
+
+    #[test]
+    fn test_parse_v4_has_no_timestamp() {
+        let uuid = Uuid::new_v4();
+        let tool = UUIDTool {
+            command: UUIDCommand::Parse {
+                uuid: uuid.to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["version"], 4);
+        assert!(val.get("datetime").is_none());
+    }
+
+    #[test]
+    fn test_parse_v7_includes_timestamp() {
+        let uuid = Uuid::now_v7();
+        let tool = UUIDTool {
+            command: UUIDCommand::Parse {
+                uuid: uuid.to_string(),
+            },
+        };
+        let result = tool.execute().unwrap().unwrap();
+
+        let Output::JsonValue(val) = result else {
+            unreachable!()
+        };
+        assert_eq!(val["version"], 7);
+        assert!(val["datetime"].as_str().is_some());
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_uuid() {
+        let tool = UUIDTool {
+            command: UUIDCommand::Parse {
+                uuid: "not-a-uuid".to_string(),
+            },
+        };
+        assert!(tool.execute().is_err());
     }
 }